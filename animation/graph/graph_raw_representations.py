@@ -6,7 +6,8 @@ from utils.anim_utils import get_darker_hex
 
 
 class TensorRepr:
-    def __init__(self, data=[], shape=(), offset=0, id=""):
+    def __init__(self, data=[], shape=(), offset=0, id="", name=None):
+        self.name = name
         self.shape = shape
         self.offset = offset
         self.data = TensorRepr.create_array_from_raw(data, shape)
@@ -33,13 +34,17 @@ class TensorRepr:
     def get_id(self) -> str:
         return self.id
 
+    def get_name(self):
+        return self.name
+
     def set_id(self, id: str):
         self.id = id
 
 
 class BackwardNode:
-    def __init__(self, name: str, origin, gradient, children, preset: bool = False):
+    def __init__(self, name: str, origin, gradient, children, location: str = "", preset: bool = False):
         self.name = name
+        self.location = location
 
         if preset:
             self.origin = origin
@@ -62,6 +67,9 @@ class BackwardNode:
     def get_gradient(self) -> TensorRepr:
         return self.gradient
 
+    def get_location(self) -> str:
+        return self.location
+
     def get_children(self):
         return self.children
 
@@ -74,6 +82,7 @@ class BackwardNode:
             node.get_origin(),
             node.get_gradient(),
             node.get_children(),
+            node.get_location(),
             preset=True
         )
 
@@ -117,12 +126,14 @@ class ForwardNode:
 
 
 class Node:
-    def __init__(self, name="", origin="", gradient="", id=""):
+    def __init__(self, name="", origin="", gradient="", id="", location="", origin_name=None):
         self.name = name
+        self.origin_name = origin_name
         self.ops_name = re.findall('[A-Z][a-z]*', name)[0]
         self.origin = origin
         self.gradient = gradient
         self.id = id
+        self.location = location
 
     def __repr__(self):
         return f"Node(name={self.get_name()}, origin={self.origin}, gradient={self.gradient})"
@@ -145,6 +156,12 @@ class Node:
     def get_id(self) -> str:
         return self.id
 
+    def get_location(self) -> str:
+        return self.location
+
+    def get_origin_name(self):
+        return self.origin_name
+
     def get_ops_name(self) -> str:
         return self.ops_name
 
@@ -0,0 +1,305 @@
+use ndarray::ScalarOperand;
+use num_traits::Float;
+use std::fmt::Debug;
+
+use crate::tensor_core::dtypes::DTComp;
+use crate::tensor_core::tensor::Tensor;
+
+// Gradients of half precision tensors underflow to zero long before f32 ones do. Multiplying the
+// loss by a large scale before the backward pass lifts them into range, and dividing the
+// gradients by the same scale afterwards recovers their true values. The scaler keeps the scale as
+// large as possible: it shrinks whenever a gradient overflows, and grows back after a run of
+// steps without any overflow.
+
+/// Scale applied to the loss of the first step
+pub const DEFAULT_INIT_SCALE: f32 = 65536.0;
+
+#[derive(Debug, Clone)]
+pub struct DynamicLossScaler {
+    scale: f32,
+    growth_factor: f32,
+    backoff_factor: f32,
+    growth_interval: usize,
+    steps_since_overflow: usize,
+    skipped_steps: usize,
+}
+
+impl Default for DynamicLossScaler {
+    fn default() -> Self {
+        return DynamicLossScaler {
+            scale: DEFAULT_INIT_SCALE,
+            growth_factor: 2.0,
+            backoff_factor: 0.5,
+            growth_interval: 2000,
+            steps_since_overflow: 0,
+            skipped_steps: 0,
+        };
+    }
+}
+
+impl DynamicLossScaler {
+    pub fn new() -> Self {
+        return DynamicLossScaler::default();
+    }
+
+    pub fn with_init_scale(mut self, scale: f32) -> Self {
+        if !(scale.is_finite() && scale > 0.0) {
+            panic!(
+                "Error: Loss scale must be a positive finite number, got {}",
+                scale
+            );
+        }
+
+        self.scale = scale;
+        return self;
+    }
+
+    pub fn with_growth_factor(mut self, growth_factor: f32) -> Self {
+        if growth_factor <= 1.0 {
+            panic!(
+                "Error: Growth factor of a loss scaler must be greater than 1, got {}",
+                growth_factor
+            );
+        }
+
+        self.growth_factor = growth_factor;
+        return self;
+    }
+
+    pub fn with_backoff_factor(mut self, backoff_factor: f32) -> Self {
+        if !(backoff_factor > 0.0 && backoff_factor < 1.0) {
+            panic!(
+                "Error: Backoff factor of a loss scaler must be between 0 and 1, got {}",
+                backoff_factor
+            );
+        }
+
+        self.backoff_factor = backoff_factor;
+        return self;
+    }
+
+    /// Number of steps in a row without overflow after which the scale grows
+    pub fn with_growth_interval(mut self, growth_interval: usize) -> Self {
+        if growth_interval == 0 {
+            panic!("Error: Growth interval of a loss scaler must be at least 1");
+        }
+
+        self.growth_interval = growth_interval;
+        return self;
+    }
+
+    pub fn get_scale(&self) -> f32 {
+        return self.scale;
+    }
+
+    /// Number of steps skipped so far because of overflowing gradients
+    pub fn get_skipped_steps(&self) -> usize {
+        return self.skipped_steps;
+    }
+
+    /// Multiplies the loss by the current scale. Call `backward` on the result instead of the loss.
+    /// A scale past the largest finite value of `T`, like the default one for `F16`, is first
+    /// lowered to the largest power of two `T` can hold.
+    #[track_caller]
+    pub fn scale<T>(&mut self, loss: &Tensor<T>) -> Tensor<T>
+    where
+        T: DTComp + Debug + Float + ScalarOperand + 'static,
+    {
+        let is_finite = T::from(self.scale).is_some_and(|scale| scale.is_finite());
+        if !is_finite {
+            let max_value = T::max_value().to_f32().unwrap_or(f32::MAX);
+            self.scale = 2.0_f32.powi(max_value.log2().floor() as i32);
+        }
+
+        let scale = T::from(self.scale).unwrap_or_else(|| {
+            panic!(
+                "Error: Loss scale {} cannot be represented as {}",
+                self.scale,
+                T::dtype()
+            )
+        });
+
+        return loss * scale;
+    }
+
+    /// Divides the gradient of every parameter by the current scale, and returns whether all of
+    /// them are finite. Parameters without a gradient are skipped.
+    pub fn unscale<T>(&self, params: &[&Tensor<T>]) -> bool
+    where
+        T: DTComp + Debug + Float + 'static,
+    {
+        let mut finite = true;
+
+        for param in params.iter() {
+            if let Some(grad) = param.grad() {
+                // the division runs in f64, which holds every supported float dtype, so a gradient
+                // that overflowed stays infinite and no precision is lost on the way
+                let unscaled = grad.get_raw_data().mapv(|x| {
+                    let x = x.to_f64().unwrap_or(f64::NAN) / self.scale as f64;
+                    return T::from(x).unwrap_or_else(T::nan);
+                });
+
+                finite = finite && unscaled.iter().all(|x| x.is_finite());
+                param.set_grad(Some(Tensor::from_raw_array(unscaled, false)));
+            }
+        }
+
+        return finite;
+    }
+
+    /// Adjusts the scale after a step. An overflow shrinks the scale right away, while
+    /// `growth_interval` clean steps in a row grow it.
+    pub fn update(&mut self, found_overflow: bool) {
+        if found_overflow {
+            self.scale *= self.backoff_factor;
+            self.steps_since_overflow = 0;
+            self.skipped_steps += 1;
+            return;
+        }
+
+        self.steps_since_overflow += 1;
+
+        if self.steps_since_overflow == self.growth_interval {
+            let grown = self.scale * self.growth_factor;
+
+            // never grow into a scale that is itself infinite
+            if grown.is_finite() {
+                self.scale = grown;
+            }
+
+            self.steps_since_overflow = 0;
+        }
+    }
+
+    /// Unscales the gradients and runs `optimizer_step` if they are all finite. On overflow the
+    /// step is skipped and the gradients are cleared, so the next step starts from scratch.
+    /// Returns whether the step ran.
+    pub fn step<T, F>(&mut self, params: &[&Tensor<T>], optimizer_step: F) -> bool
+    where
+        T: DTComp + Debug + Float + 'static,
+        F: FnOnce(),
+    {
+        let found_overflow = !self.unscale(params);
+
+        if found_overflow {
+            for param in params.iter() {
+                param.zero_grad();
+            }
+        } else {
+            optimizer_step();
+        }
+
+        self.update(found_overflow);
+        return !found_overflow;
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    #[allow(unused)]
+    use super::*;
+    use crate::tensor_core::half::F16;
+
+    #[test]
+    fn loss_scaler_skips_overflowing_steps() {
+        let mut scaler = DynamicLossScaler::new()
+            .with_init_scale(1024.0)
+            .with_growth_interval(2);
+
+        let w = Tensor::new(vec![F16::from_f32(0.5), F16::from_f32(2.0)], vec![2], true);
+
+        // a clean step unscales the gradient back to its true value
+        w.set_grad(Some(Tensor::new(
+            vec![F16::from_f32(512.0), F16::from_f32(1024.0)],
+            vec![2],
+            false,
+        )));
+
+        let mut ran = false;
+        assert!(scaler.step(&[&w], || ran = true));
+        assert!(ran);
+        assert_eq!(
+            w.grad()
+                .unwrap()
+                .get_raw_data()
+                .iter()
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![F16::from_f32(0.5), F16::ONE]
+        );
+
+        // a gradient that overflowed half precision skips the step and halves the scale
+        w.set_grad(Some(Tensor::new(
+            vec![F16::INFINITY, F16::ONE],
+            vec![2],
+            false,
+        )));
+
+        let mut ran = false;
+        assert!(!scaler.step(&[&w], || ran = true));
+        assert!(!ran);
+        assert!(w.grad().is_none());
+        assert_eq!(scaler.get_scale(), 512.0);
+        assert_eq!(scaler.get_skipped_steps(), 1);
+
+        // two clean steps in a row grow the scale again
+        scaler.update(false);
+        scaler.update(false);
+        assert_eq!(scaler.get_scale(), 1024.0);
+
+        let loss = Tensor::new(vec![F16::from_f32(0.25)], vec![1], false);
+        assert_eq!(
+            scaler
+                .scale(&loss)
+                .get_raw_data()
+                .iter()
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![F16::from_f32(256.0)]
+        );
+    }
+
+    #[test]
+    fn default_scaler_steps_in_half_precision() {
+        use ndarray::Axis;
+
+        // the default scale of 65536 overflows F16, so it is lowered to 32768
+        let mut scaler = DynamicLossScaler::new();
+        let w = Tensor::new(
+            vec![F16::from_f32(0.01), F16::from_f32(0.02)],
+            vec![2],
+            true,
+        );
+
+        let loss = (&w * &w).sum(Axis(0));
+        let scaled = scaler.scale(&loss);
+        assert!(scaled.item().is_finite());
+        assert_eq!(scaler.get_scale(), 32768.0);
+
+        scaled.backward_scalar(false);
+        assert!(scaler.step(&[&w], || {}));
+        assert_eq!(scaler.get_skipped_steps(), 0);
+        assert_eq!(
+            w.grad()
+                .unwrap()
+                .get_raw_data()
+                .iter()
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![
+                F16::from_f32(0.01) + F16::from_f32(0.01),
+                F16::from_f32(0.02) + F16::from_f32(0.02)
+            ]
+        );
+    }
+
+    #[test]
+    fn unscale_keeps_f64_precision() {
+        let scaler = DynamicLossScaler::new().with_init_scale(3.0);
+        let w = Tensor::new(vec![1.0_f64], vec![1], true);
+        w.set_grad(Some(Tensor::new(vec![0.1_f64 * 3.0], vec![1], false)));
+
+        assert!(scaler.unscale(&[&w]));
+        assert_eq!(w.grad().unwrap().item(), 0.1 * 3.0 / 3.0);
+    }
+}
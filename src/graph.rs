@@ -1,3 +1,4 @@
 pub mod backward;
 pub mod edge;
+pub mod planner;
 pub mod visualize;
@@ -6,6 +6,7 @@ use crate::tensor_core::tensor_impl::TensorImpl;
 
 use std::cell::RefCell;
 use std::fmt::{Debug, Display};
+use std::panic::Location;
 use std::rc::Rc;
 
 // Backward node types
@@ -36,15 +37,43 @@ pub mod sum_backward;
 // Broadcast & Reshape operations
 pub mod broadcast_backward;
 pub mod concat_backward;
+pub mod contiguous_backward;
 pub mod reshape_backward;
 pub mod squeeze_backward;
 pub mod stack_backward;
 pub mod transpose_backward;
 pub mod unsqueeze_backward;
 
+// Indexing operations
+pub mod gather_backward;
+pub mod index_select_backward;
+pub mod masked_select_backward;
+pub mod slice_backward;
+
+// Scatter & Segment operations
+pub mod index_add_backward;
+pub mod scatter_add_backward;
+pub mod segment_max_backward;
+pub mod segment_mean_backward;
+pub mod segment_sum_backward;
+
+// Selection by mask
+pub mod masked_fill_backward;
+pub mod where_backward;
+
+// Type conversions
+pub mod cast_backward;
+
+// Complex operations
+pub mod complex_part_backward;
+pub mod conj_backward;
+
 // Advance operations
 pub mod matmul_backward;
 
+// Fused element wise kernels
+pub mod fused_backward;
+
 // Utils
 pub mod backward_utils;
 
@@ -65,6 +94,19 @@ where
         edge: Option<&Edge<T>>,
     ) -> Rc<Tensor<T>>;
 
+    /// Gradients for every edge in edge list order. Nodes that can produce all of them in a single
+    /// pass should override this
+    fn calculate_gradients_for_all_edges(
+        &self,
+        upstream_gradient: &Rc<Tensor<T>>,
+    ) -> Vec<Rc<Tensor<T>>> {
+        return self
+            .get_edge_list()
+            .iter()
+            .map(|edge| self.calculate_gradient_for_next_node(upstream_gradient, Some(edge)))
+            .collect();
+    }
+
     /// Get edge list
     fn get_edge_list(&self) -> &[Edge<T>];
 
@@ -74,9 +116,11 @@ where
     /// Loop through input list and link inputs with each tensor's TensorImpl
     fn save_input_refs(&mut self, input_refs: Vec<Rc<RefCell<TensorImpl<T>>>>);
 
-    fn clear_input_refs(&mut self) {
-        self.save_input_refs(vec![]);
-    }
+    /// Tensors saved by this node for its gradient calculation
+    fn get_input_refs(&self) -> &[Rc<RefCell<TensorImpl<T>>>];
+
+    /// Drop every saved tensor. The node can no longer calculate gradients after this is called
+    fn clear_input_refs(&mut self);
 
     // MISC functions
     fn get_id(&self) -> usize;
@@ -84,6 +128,9 @@ where
     fn get_name(&self) -> String;
 
     fn get_origin(&self) -> Option<Rc<RefCell<TensorImpl<T>>>>;
+
+    /// Location in user code of the op that created this node
+    fn get_location(&self) -> &'static Location<'static>;
 }
 
 impl<T> Display for dyn Backward<T>
@@ -94,3 +141,82 @@ where
         write!(f, "{}", self.get_name().as_str())
     }
 }
+
+#[cfg(test)]
+pub mod test {
+    #[allow(unused)]
+    use super::*;
+    use crate::ops::public_ops::concat::concat;
+    use crate::ops::public_ops::matmul::matmul;
+    use ndarray::Axis;
+
+    fn saved_count(tensor: &Tensor<f32>) -> usize {
+        return tensor.get_grad_fn().borrow().get_input_refs().len();
+    }
+
+    #[test]
+    fn nodes_save_only_needed_tensors() {
+        let a = Tensor::new(vec![1.0, 2.0, 3.0, 4.0], vec![2, 2], true);
+        let b = Tensor::new(vec![5.0, 6.0, 7.0, 8.0], vec![2, 2], true);
+        let c = Tensor::new(vec![5.0, 6.0, 7.0, 8.0], vec![2, 2], false);
+
+        // shape-only gradients
+        assert_eq!(saved_count(&(&a + &b)), 0);
+        assert_eq!(saved_count(&(&a - &b)), 0);
+        assert_eq!(saved_count(&a.sum(Axis(0))), 0);
+        assert_eq!(saved_count(&a.mean(Axis(0))), 0);
+        assert_eq!(saved_count(&a.max(Axis(0))), 0);
+        assert_eq!(saved_count(&a.broadcast(vec![3, 2, 2])), 0);
+        assert_eq!(saved_count(&concat(&[&a, &b], Axis(0))), 0);
+
+        // only the operands needed by an existing edge
+        assert_eq!(saved_count(&(&a * &b)), 2);
+        assert_eq!(saved_count(&(&a * &c)), 1);
+        assert_eq!(saved_count(&(&a * 2.0)), 0);
+        assert_eq!(saved_count(&(&a / &c)), 1);
+        assert_eq!(saved_count(&(&c / &a)), 2);
+        assert_eq!(saved_count(&matmul(&a, &c)), 1);
+        assert_eq!(saved_count(&matmul(&a, &b)), 2);
+
+        // the output instead of the input
+        let exp = a.exp();
+        assert_eq!(saved_count(&exp), 1);
+        assert_eq!(
+            exp.get_grad_fn().borrow().get_input_refs()[0]
+                .borrow()
+                .get_storage_()
+                .get_allocation_id(),
+            exp.get_storage().get_allocation_id()
+        );
+
+        let tanh = a.tanh();
+        assert_eq!(saved_count(&tanh), 1);
+        assert_eq!(
+            tanh.get_grad_fn().borrow().get_input_refs()[0]
+                .borrow()
+                .get_storage_()
+                .get_allocation_id(),
+            tanh.get_storage().get_allocation_id()
+        );
+    }
+
+    #[test]
+    fn nodes_record_creation_location() {
+        let a = Tensor::new(vec![1.0_f32, 2.0], vec![2], true);
+        let b = Tensor::new(vec![3.0_f32, 4.0], vec![2], true);
+
+        let line = line!() + 1;
+        let c = &a * &b;
+        let location = c.get_grad_fn().borrow().get_location();
+        assert_eq!((location.file(), location.line()), (file!(), line));
+
+        let line = line!() + 1;
+        let d = concat(&[&c.exp(), &b], Axis(0)).sum(Axis(0));
+        let location = d.get_grad_fn().borrow().get_location();
+        assert_eq!((location.file(), location.line()), (file!(), line));
+
+        // leaf tensors record where gradient tracking was turned on
+        let location = a.get_grad_accum().borrow().get_location();
+        assert_eq!(location.file(), file!());
+    }
+}
@@ -3,14 +3,14 @@ use super::Tensor;
 
 use crate::graph::backward::Backward;
 use crate::graph::backward::backward_types::BackwardType;
-use crate::graph::backward::backward_utils::gradient_from_broadcast;
+use crate::graph::backward::backward_utils::{apply_node, gradient_from_broadcast};
 use crate::graph::edge::Edge;
-use crate::ops::compute::add_compute::add_compute_tensor_tensor;
 use crate::tensor_core::tensor_impl::TensorImpl;
 use std::cell::RefCell;
 use std::fmt::Debug;
 use std::ops::Add;
 use std::ops::Deref;
+use std::panic::Location;
 use std::rc::{Rc, Weak};
 
 #[derive(Debug)]
@@ -19,11 +19,13 @@ where
     T: DTComp + Clone + Debug,
 {
     input_refs: Vec<Rc<RefCell<TensorImpl<T>>>>,
+    input_shapes: Vec<Vec<usize>>,
     name: BackwardType,
     id: usize,
     edge_list: Vec<Edge<T>>,
     #[allow(unused)]
     origin: Option<Weak<RefCell<TensorImpl<T>>>>,
+    location: &'static Location<'static>,
 }
 
 impl<T> Backward<T> for AddBackward<T>
@@ -35,21 +37,15 @@ where
             if let Some(origin_as_strong_rc) = origin_as_option_ref.upgrade() {
                 if let Some(origin_ref) = origin_as_strong_rc.borrow().get_autograd_ref_().as_ref()
                 {
-                    if origin_ref.grad_is_set() {
-                        let old_grad = origin_ref.get_grad_as_tensor();
-                        let new_grad = add_compute_tensor_tensor(old_grad.deref(), grad.deref());
-
-                        origin_ref.set_grad(Rc::new(new_grad));
-                    } else {
-                        origin_ref.set_grad(Rc::clone(grad));
-                    }
+                    origin_ref.accumulate_grad(grad);
                 }
             }
         } else {
             panic!(
-                "Dangling graph node, no origin tensor found at node: {} with id: {}",
+                "Dangling graph node, no origin tensor found at node: {} with id: {}, created at {}",
                 self.get_name(),
                 self.get_id(),
+                self.get_location(),
             );
         }
     }
@@ -63,7 +59,7 @@ where
             let next_grad = self.calculate_gradient_for_next_node(&upstream_gradient, Some(&edge));
 
             let next_node = edge.get_next_grad_fn();
-            next_node.borrow().apply(next_grad, retain_graph);
+            apply_node(&next_node, next_grad, retain_graph);
         }
     }
 
@@ -75,17 +71,9 @@ where
         if let Some(edge) = edge {
             let edge_nr = edge.input_nr;
 
-            let input_tensor;
-
-            if edge_nr == 0 {
-                input_tensor = Rc::clone(&self.input_refs[0]);
-            } else {
-                input_tensor = Rc::clone(&self.input_refs[1]);
-            }
-
             return Rc::new(gradient_from_broadcast(
                 upstream_gradient.deref(),
-                &input_tensor.borrow().get_raw_shape(),
+                &self.input_shapes[edge_nr],
             ));
         } else {
             panic!("Cannot calculate gradient for add operation because of missing inputs");
@@ -104,6 +92,14 @@ where
         self.input_refs.extend(input_refs);
     }
 
+    fn get_input_refs(&self) -> &[Rc<RefCell<TensorImpl<T>>>] {
+        return &self.input_refs;
+    }
+
+    fn clear_input_refs(&mut self) {
+        self.input_refs.clear();
+    }
+
     fn get_id(&self) -> usize {
         return self.id;
     }
@@ -112,6 +108,10 @@ where
         return self.name.to_string();
     }
 
+    fn get_location(&self) -> &'static Location<'static> {
+        return self.location;
+    }
+
     fn get_origin(&self) -> Option<Rc<RefCell<TensorImpl<T>>>> {
         match &self.origin {
             Some(origin_weak) => {
@@ -129,17 +129,25 @@ impl<T> AddBackward<T>
 where
     T: Clone + DTComp + Debug,
 {
+    #[track_caller]
     pub fn new(id: usize, edge_list: Vec<Edge<T>>, origin: &Rc<RefCell<TensorImpl<T>>>) -> Self {
         let node = AddBackward {
             input_refs: vec![],
+            input_shapes: vec![],
             name: BackwardType::AddBackward,
             id,
             edge_list,
             origin: Some(Rc::downgrade(origin)),
+            location: Location::caller(),
         };
 
         return node;
     }
+
+    /// Saves the input shapes, the gradient is summed back down to them over broadcast dims
+    pub fn save_input_shapes(&mut self, input_shapes: Vec<Vec<usize>>) {
+        self.input_shapes.extend(input_shapes);
+    }
 }
 
 #[cfg(test)]
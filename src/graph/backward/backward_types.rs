@@ -23,8 +23,24 @@ pub enum BackwardType {
     SqueezeBackward,
     TransposeBackward,
     ReshapeBackward,
+    ContiguousBackward,
     StackBackward,
     ConcatBackward,
+    SliceBackward,
+    IndexSelectBackward,
+    GatherBackward,
+    MaskedSelectBackward,
+    ScatterAddBackward,
+    IndexAddBackward,
+    SegmentSumBackward,
+    SegmentMeanBackward,
+    SegmentMaxBackward,
+    CastBackward,
+    ComplexPartBackward,
+    ConjBackward,
+    WhereBackward,
+    MaskedFillBackward,
+    FusedBackward,
 }
 
 impl fmt::Display for BackwardType {
@@ -51,8 +67,24 @@ impl fmt::Display for BackwardType {
             BackwardType::SqueezeBackward => write!(f, "SqueezeBackward"),
             BackwardType::TransposeBackward => write!(f, "TransposeBackward"),
             BackwardType::ReshapeBackward => write!(f, "ReshapeBackward"),
+            BackwardType::ContiguousBackward => write!(f, "ContiguousBackward"),
             BackwardType::StackBackward => write!(f, "StackBackward"),
             BackwardType::ConcatBackward => write!(f, "ConcatBackward"),
+            BackwardType::SliceBackward => write!(f, "SliceBackward"),
+            BackwardType::IndexSelectBackward => write!(f, "IndexSelectBackward"),
+            BackwardType::GatherBackward => write!(f, "GatherBackward"),
+            BackwardType::MaskedSelectBackward => write!(f, "MaskedSelectBackward"),
+            BackwardType::ScatterAddBackward => write!(f, "ScatterAddBackward"),
+            BackwardType::IndexAddBackward => write!(f, "IndexAddBackward"),
+            BackwardType::SegmentSumBackward => write!(f, "SegmentSumBackward"),
+            BackwardType::SegmentMeanBackward => write!(f, "SegmentMeanBackward"),
+            BackwardType::SegmentMaxBackward => write!(f, "SegmentMaxBackward"),
+            BackwardType::CastBackward => write!(f, "CastBackward"),
+            BackwardType::ComplexPartBackward => write!(f, "ComplexPartBackward"),
+            BackwardType::ConjBackward => write!(f, "ConjBackward"),
+            BackwardType::WhereBackward => write!(f, "WhereBackward"),
+            BackwardType::MaskedFillBackward => write!(f, "MaskedFillBackward"),
+            BackwardType::FusedBackward => write!(f, "FusedBackward"),
         }
     }
 }
@@ -1,8 +1,105 @@
 use crate::{
+    graph::backward::Backward,
     ops::compute::sum_mean_compute::sum_to_size_compute_tensor,
+    profiler::memory_profiler,
+    profiler::timing_profiler::{self, SpanCategory},
     tensor_core::{dtypes::DTComp, tensor::Tensor},
 };
-use std::{fmt::Debug, ops::Add};
+use std::{
+    cell::{Cell, RefCell},
+    fmt::Debug,
+    ops::Add,
+    panic::{self, AssertUnwindSafe},
+    rc::Rc,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+static NODE_ID_COUNTER: AtomicUsize = AtomicUsize::new(1);
+
+/// Unique id for a newly created graph node
+pub fn next_node_id() -> usize {
+    return NODE_ID_COUNTER.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Run the apply of a node. Every traversal of the graph goes through here so the profilers can
+/// attribute the work done inside a node to it.
+pub fn apply_node<T>(
+    node: &Rc<RefCell<dyn Backward<T>>>,
+    upstream_gradient: Rc<Tensor<T>>,
+    retain_graph: bool,
+) where
+    T: DTComp + Debug,
+{
+    let _span = timing_profiler::span(
+        SpanCategory::Backward,
+        &node.borrow().get_name(),
+        &[upstream_gradient.as_ref()],
+    );
+
+    with_node_panic_context(&*node.borrow(), || {
+        memory_profiler::enter_node(&*node.borrow());
+        node.borrow().apply(upstream_gradient, retain_graph);
+        memory_profiler::exit_node();
+    });
+}
+
+thread_local! {
+    static NODE_PANIC_DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Run `run` for `node`, adding the node and where in user code it was created to the message of
+/// any panic raised inside. Nested nodes each add a line while the panic travels up, so the message
+/// reads as a trace from the failing node back to the root. The outermost node raises the panic
+/// again, which lets the panic hook print the whole trace.
+pub fn with_node_panic_context<T, R, F>(node: &dyn Backward<T>, run: F) -> R
+where
+    T: DTComp + Debug,
+    F: FnOnce() -> R,
+{
+    let name = node.get_name();
+    let id = node.get_id();
+    let location = node.get_location();
+    let tensor_name = match node.get_origin() {
+        Some(origin) => origin.borrow().get_name_(),
+        None => None,
+    };
+
+    NODE_PANIC_DEPTH.with(|depth| depth.set(depth.get() + 1));
+    let result = panic::catch_unwind(AssertUnwindSafe(run));
+    let depth = NODE_PANIC_DEPTH.with(|depth| {
+        depth.set(depth.get() - 1);
+        depth.get()
+    });
+
+    let payload = match result {
+        Ok(value) => return value,
+        Err(payload) => payload,
+    };
+
+    let message = if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else if let Some(message) = payload.downcast_ref::<&str>() {
+        String::from(*message)
+    } else {
+        String::from("Error: backward panicked")
+    };
+
+    let tensor_name = match tensor_name {
+        Some(name) => format!(" for tensor '{}'", name),
+        None => String::new(),
+    };
+
+    let message = format!(
+        "{}\n    in node {} with id: {}{}, created at {}",
+        message, name, id, tensor_name, location
+    );
+
+    if depth == 0 {
+        panic!("{}", message);
+    }
+
+    panic::resume_unwind(Box::new(message));
+}
 
 pub fn gradient_from_broadcast<T>(tensor: &Tensor<T>, intended_shape: &[usize]) -> Tensor<T>
 where
@@ -11,6 +108,33 @@ where
     return sum_to_size_compute_tensor(tensor, intended_shape);
 }
 
+#[cfg(test)]
+pub mod test {
+    #[allow(unused)]
+    use super::*;
+
+    #[test]
+    fn backward_panics_name_the_node_and_where_it_was_created() {
+        let x = Tensor::new(vec![1.0_f32, 2.0], vec![2], true);
+        let w = Tensor::new(vec![3.0_f32, 4.0], vec![2], false);
+        let z = (&x * &w).with_name("z");
+
+        let payload = panic::catch_unwind(AssertUnwindSafe(|| {
+            z.backward(Tensor::new(vec![1.0_f32, 1.0, 1.0], vec![3], false), false);
+        }))
+        .unwrap_err();
+
+        let message = payload.downcast_ref::<String>().unwrap();
+        assert!(message.contains("in node MulBackward"), "{}", message);
+        assert!(message.contains("for tensor 'z'"), "{}", message);
+        assert!(
+            message.contains(&format!("created at {}:", file!())),
+            "{}",
+            message
+        );
+    }
+}
+
 // #[cfg(test)}]
 // pub mod test {
 //     #[allow(unused)]
@@ -0,0 +1,192 @@
+use num_traits::AsPrimitive;
+
+use super::DTComp;
+use super::Tensor;
+
+use crate::graph::backward::Backward;
+use crate::graph::backward::backward_types::BackwardType;
+use crate::graph::backward::backward_utils::apply_node;
+use crate::graph::edge::Edge;
+use crate::ops::compute::cast_compute::compute_cast;
+use crate::tensor_core::tensor_impl::TensorImpl;
+
+use std::cell::RefCell;
+use std::fmt::Debug;
+use std::ops::Add;
+use std::panic::Location;
+use std::rc::{Rc, Weak};
+
+/// Node of a cast from a tensor of `T` into a tensor of `U`. Its input lives in a graph of a
+/// different element type, so the edge to it is kept apart from the edge list, which stays empty,
+/// and the gradient is cast back to `T` when the node is applied. Graph walks over `U` such as the
+/// planner and the visualizer stop at this node.
+#[derive(Debug)]
+pub struct CastBackward<U, T>
+where
+    U: DTComp + Clone + Debug,
+    T: DTComp + Clone + Debug,
+{
+    input_refs: Vec<Rc<RefCell<TensorImpl<U>>>>,
+    name: BackwardType,
+    id: usize,
+    edge_list: Vec<Edge<U>>,
+    input_edge: Option<Edge<T>>,
+    origin: Option<Weak<RefCell<TensorImpl<U>>>>,
+    location: &'static Location<'static>,
+}
+
+impl<U, T> Backward<U> for CastBackward<U, T>
+where
+    U: Copy + DTComp + Debug + Add<Output = U> + AsPrimitive<T>,
+    T: Copy + DTComp + Debug + Add<Output = T>,
+{
+    fn save_grad_to_origin_tensor(&self, grad: &Rc<Tensor<U>>) {
+        if let Some(origin_as_option_ref) = self.origin.as_ref() {
+            if let Some(origin_as_strong_rc) = origin_as_option_ref.upgrade() {
+                if let Some(origin_ref) = origin_as_strong_rc.borrow().get_autograd_ref_().as_ref()
+                {
+                    origin_ref.accumulate_grad(grad);
+                }
+            }
+        } else {
+            panic!(
+                "Dangling graph node, no origin tensor found at node: {} with id: {}, created at {}",
+                self.get_name(),
+                self.get_id(),
+                self.get_location(),
+            );
+        }
+    }
+
+    fn apply(&self, upstream_gradient: Rc<Tensor<U>>, retain_graph: bool) {
+        if retain_graph {
+            self.save_grad_to_origin_tensor(&upstream_gradient);
+        }
+
+        if let Some(edge) = self.input_edge.as_ref() {
+            let next_grad = Rc::new(compute_cast::<U, T>(&upstream_gradient));
+
+            let next_node = edge.get_next_grad_fn();
+            apply_node(&next_node, next_grad, retain_graph);
+        }
+    }
+
+    fn calculate_gradient_for_next_node(
+        &self,
+        upstream_gradient: &Rc<Tensor<U>>,
+        _edge: Option<&Edge<U>>,
+    ) -> Rc<Tensor<U>> {
+        // the gradient of the input only differs from the upstream gradient by its element type,
+        // the cast back to it happens in `apply`
+        return Rc::clone(upstream_gradient);
+    }
+
+    fn get_edge_list(&self) -> &[Edge<U>] {
+        return &self.edge_list;
+    }
+
+    fn add_to_edge_list(&mut self, edge: Edge<U>) {
+        self.edge_list.push(edge);
+    }
+
+    fn save_input_refs(&mut self, input_refs: Vec<Rc<RefCell<TensorImpl<U>>>>) {
+        self.input_refs.extend(input_refs);
+    }
+
+    fn get_input_refs(&self) -> &[Rc<RefCell<TensorImpl<U>>>] {
+        return &self.input_refs;
+    }
+
+    fn clear_input_refs(&mut self) {
+        self.input_refs.clear();
+    }
+
+    fn get_id(&self) -> usize {
+        return self.id;
+    }
+
+    fn get_name(&self) -> String {
+        return self.name.to_string();
+    }
+
+    fn get_location(&self) -> &'static Location<'static> {
+        return self.location;
+    }
+
+    fn get_origin(&self) -> Option<Rc<RefCell<TensorImpl<U>>>> {
+        match &self.origin {
+            Some(origin_weak) => {
+                let origin_rc = origin_weak.upgrade();
+                return origin_rc;
+            }
+            None => {
+                panic!("no origin found on this tensor");
+            }
+        }
+    }
+}
+
+impl<U, T> CastBackward<U, T>
+where
+    U: Clone + DTComp + Debug,
+    T: Clone + DTComp + Debug,
+{
+    #[track_caller]
+    pub fn new(id: usize, edge_list: Vec<Edge<U>>, origin: &Rc<RefCell<TensorImpl<U>>>) -> Self {
+        let node = CastBackward {
+            name: BackwardType::CastBackward,
+            input_refs: vec![],
+            id,
+            edge_list,
+            input_edge: None,
+            origin: Some(Rc::downgrade(origin)),
+            location: Location::caller(),
+        };
+
+        return node;
+    }
+
+    /// Connects the node to the input of the cast, which has the element type `T`
+    pub fn set_input_edge(&mut self, edge: Edge<T>) {
+        self.input_edge = Some(edge);
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    #[allow(unused)]
+    use super::*;
+
+    #[test]
+    fn cast_backward_operation() {
+        let x1 = Tensor::new(vec![1.0_f32, 2.0, 3.0], vec![3], true);
+        let x2 = Tensor::new(vec![0.5_f64, 0.25, 0.125], vec![3], true);
+
+        let z = &x1.to::<f64>() * &x2;
+        assert_eq!(
+            z.get_raw_data().iter().copied().collect::<Vec<_>>(),
+            vec![0.5, 0.5, 0.375]
+        );
+
+        z.backward(Tensor::new(vec![1.0, 1.0, 1.0], vec![3], false), false);
+
+        assert_eq!(
+            x1.grad()
+                .unwrap()
+                .get_raw_data()
+                .iter()
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![0.5, 0.25, 0.125]
+        );
+        assert_eq!(
+            x2.grad()
+                .unwrap()
+                .get_raw_data()
+                .iter()
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![1.0, 2.0, 3.0]
+        );
+    }
+}
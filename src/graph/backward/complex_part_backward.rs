@@ -0,0 +1,211 @@
+use num_complex::Complex;
+use num_traits::Float;
+
+use super::DTComp;
+use super::Tensor;
+
+use crate::graph::backward::Backward;
+use crate::graph::backward::backward_types::BackwardType;
+use crate::graph::backward::backward_utils::apply_node;
+use crate::graph::edge::Edge;
+use crate::ops::compute::complex_compute::compute_scale_by_real;
+use crate::tensor_core::tensor_impl::TensorImpl;
+
+use std::cell::RefCell;
+use std::fmt::Debug;
+use std::panic::Location;
+use std::rc::{Rc, Weak};
+
+/// Node of a real valued function of a complex tensor, such as its real part or its absolute
+/// value. The gradient of the input is a saved complex factor scaled by the real upstream
+/// gradient. Like `CastBackward`, the input lives in a graph of a different element type, so the
+/// edge to it is kept apart from the edge list, which stays empty.
+#[derive(Debug)]
+pub struct ComplexPartBackward<F>
+where
+    F: DTComp + Clone + Debug,
+    Complex<F>: DTComp,
+{
+    input_refs: Vec<Rc<RefCell<TensorImpl<F>>>>,
+    name: BackwardType,
+    id: usize,
+    edge_list: Vec<Edge<F>>,
+    input_edge: Option<Edge<Complex<F>>>,
+    grad_factor: Option<Rc<Tensor<Complex<F>>>>,
+    origin: Option<Weak<RefCell<TensorImpl<F>>>>,
+    location: &'static Location<'static>,
+}
+
+impl<F> Backward<F> for ComplexPartBackward<F>
+where
+    F: DTComp + Debug + Float,
+    Complex<F>: DTComp,
+{
+    fn save_grad_to_origin_tensor(&self, grad: &Rc<Tensor<F>>) {
+        if let Some(origin_as_option_ref) = self.origin.as_ref() {
+            if let Some(origin_as_strong_rc) = origin_as_option_ref.upgrade() {
+                if let Some(origin_ref) = origin_as_strong_rc.borrow().get_autograd_ref_().as_ref()
+                {
+                    origin_ref.accumulate_grad(grad);
+                }
+            }
+        } else {
+            panic!(
+                "Dangling graph node, no origin tensor found at node: {} with id: {}, created at {}",
+                self.get_name(),
+                self.get_id(),
+                self.get_location(),
+            );
+        }
+    }
+
+    fn apply(&self, upstream_gradient: Rc<Tensor<F>>, retain_graph: bool) {
+        if retain_graph {
+            self.save_grad_to_origin_tensor(&upstream_gradient);
+        }
+
+        if let Some(edge) = self.input_edge.as_ref() {
+            if let Some(grad_factor) = self.grad_factor.as_ref() {
+                let next_grad = Rc::new(compute_scale_by_real(grad_factor, &upstream_gradient));
+
+                let next_node = edge.get_next_grad_fn();
+                apply_node(&next_node, next_grad, retain_graph);
+            } else {
+                panic!(
+                    "Error, trying to calculate gradient of a complex part without any gradient factor set"
+                );
+            }
+        }
+    }
+
+    fn calculate_gradient_for_next_node(
+        &self,
+        upstream_gradient: &Rc<Tensor<F>>,
+        _edge: Option<&Edge<F>>,
+    ) -> Rc<Tensor<F>> {
+        // the gradient of the input is complex, it is computed in `apply`
+        return Rc::clone(upstream_gradient);
+    }
+
+    fn get_edge_list(&self) -> &[Edge<F>] {
+        return &self.edge_list;
+    }
+
+    fn add_to_edge_list(&mut self, edge: Edge<F>) {
+        self.edge_list.push(edge);
+    }
+
+    fn save_input_refs(&mut self, input_refs: Vec<Rc<RefCell<TensorImpl<F>>>>) {
+        self.input_refs.extend(input_refs);
+    }
+
+    fn get_input_refs(&self) -> &[Rc<RefCell<TensorImpl<F>>>] {
+        return &self.input_refs;
+    }
+
+    fn clear_input_refs(&mut self) {
+        self.input_refs.clear();
+    }
+
+    fn get_id(&self) -> usize {
+        return self.id;
+    }
+
+    fn get_name(&self) -> String {
+        return self.name.to_string();
+    }
+
+    fn get_location(&self) -> &'static Location<'static> {
+        return self.location;
+    }
+
+    fn get_origin(&self) -> Option<Rc<RefCell<TensorImpl<F>>>> {
+        match &self.origin {
+            Some(origin_weak) => {
+                let origin_rc = origin_weak.upgrade();
+                return origin_rc;
+            }
+            None => {
+                panic!("no origin found on this tensor");
+            }
+        }
+    }
+}
+
+impl<F> ComplexPartBackward<F>
+where
+    F: DTComp + Clone + Debug,
+    Complex<F>: DTComp,
+{
+    #[track_caller]
+    pub fn new(id: usize, edge_list: Vec<Edge<F>>, origin: &Rc<RefCell<TensorImpl<F>>>) -> Self {
+        let node = ComplexPartBackward {
+            name: BackwardType::ComplexPartBackward,
+            input_refs: vec![],
+            id,
+            edge_list,
+            input_edge: None,
+            grad_factor: None,
+            origin: Some(Rc::downgrade(origin)),
+            location: Location::caller(),
+        };
+
+        return node;
+    }
+
+    /// Connects the node to the complex input
+    pub fn set_input_edge(&mut self, edge: Edge<Complex<F>>) {
+        self.input_edge = Some(edge);
+    }
+
+    pub fn save_grad_factor(&mut self, grad_factor: Rc<Tensor<Complex<F>>>) {
+        self.grad_factor = Some(grad_factor);
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    #[allow(unused)]
+    use super::*;
+
+    use num_complex::Complex;
+
+    #[test]
+    fn complex_part_backward_operation() {
+        let x1 = Tensor::new(
+            vec![Complex::new(3.0_f64, 4.0), Complex::new(0.0, 2.0)],
+            vec![2],
+            true,
+        );
+
+        // |z| has gradient z / |z|, and the angle has gradient i z / |z|^2
+        let z = &x1.abs() + &x1.angle();
+        z.backward(Tensor::new(vec![1.0, 1.0], vec![2], false), false);
+
+        let grad = x1.grad().unwrap();
+        let grad = grad.get_raw_data().iter().copied().collect::<Vec<_>>();
+
+        let expected = vec![
+            Complex::new(0.6 - 0.16, 0.8 + 0.12),
+            Complex::new(-0.5, 1.0),
+        ];
+        for (g, e) in grad.iter().zip(expected.iter()) {
+            assert!((g - e).norm() < 1e-12);
+        }
+
+        // the real and imaginary parts pass the gradient to the matching part of the input
+        let x2 = Tensor::new(vec![Complex::new(1.0_f32, -1.0)], vec![1], true);
+        let z = &(&x2.real() * 2.0_f32) + &(&x2.imag() * 3.0_f32);
+        z.backward(Tensor::new(vec![1.0], vec![1], false), false);
+
+        assert_eq!(
+            x2.grad()
+                .unwrap()
+                .get_raw_data()
+                .iter()
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![Complex::new(2.0, 3.0)]
+        );
+    }
+}
@@ -5,14 +5,14 @@ use ndarray::Slice;
 
 use crate::graph::backward::Backward;
 use crate::graph::backward::backward_types::BackwardType;
+use crate::graph::backward::backward_utils::apply_node;
 use crate::graph::edge::Edge;
-use crate::ops::compute::add_compute::add_compute_tensor_tensor;
 use crate::tensor_core::tensor_impl::TensorImpl;
 
 use std::cell::RefCell;
 use std::fmt::Debug;
 use std::ops::Add;
-use std::ops::Deref;
+use std::panic::Location;
 use std::rc::{Rc, Weak};
 
 #[derive(Debug)]
@@ -27,6 +27,7 @@ where
     origin: Option<Weak<RefCell<TensorImpl<T>>>>,
     dim: Axis,
     ranges: Vec<(usize, usize)>,
+    location: &'static Location<'static>,
 }
 
 impl<T> Backward<T> for ConcatBackward<T>
@@ -38,21 +39,15 @@ where
             if let Some(origin_as_strong_rc) = origin_as_option_ref.upgrade() {
                 if let Some(origin_ref) = origin_as_strong_rc.borrow().get_autograd_ref_().as_ref()
                 {
-                    if origin_ref.grad_is_set() {
-                        let old_grad = origin_ref.get_grad_as_tensor();
-                        let new_grad = add_compute_tensor_tensor(old_grad.deref(), grad.deref());
-
-                        origin_ref.set_grad(Rc::new(new_grad));
-                    } else {
-                        origin_ref.set_grad(Rc::clone(grad));
-                    }
+                    origin_ref.accumulate_grad(grad);
                 }
             }
         } else {
             panic!(
-                "Dangling graph node, no origin tensor found at node: {} with id: {}",
+                "Dangling graph node, no origin tensor found at node: {} with id: {}, created at {}",
                 self.get_name(),
                 self.get_id(),
+                self.get_location(),
             );
         }
     }
@@ -66,7 +61,7 @@ where
             let next_grad = self.calculate_gradient_for_next_node(&upstream_gradient, Some(&edge));
 
             let next_node = edge.get_next_grad_fn();
-            next_node.borrow().apply(next_grad, retain_graph);
+            apply_node(&next_node, next_grad, retain_graph);
         }
     }
 
@@ -106,6 +101,14 @@ where
         self.input_refs.extend(input_refs);
     }
 
+    fn get_input_refs(&self) -> &[Rc<RefCell<TensorImpl<T>>>] {
+        return &self.input_refs;
+    }
+
+    fn clear_input_refs(&mut self) {
+        self.input_refs.clear();
+    }
+
     fn get_id(&self) -> usize {
         return self.id;
     }
@@ -114,6 +117,10 @@ where
         return self.name.to_string();
     }
 
+    fn get_location(&self) -> &'static Location<'static> {
+        return self.location;
+    }
+
     fn get_origin(&self) -> Option<Rc<RefCell<TensorImpl<T>>>> {
         match &self.origin {
             Some(origin_weak) => {
@@ -131,6 +138,7 @@ impl<T> ConcatBackward<T>
 where
     T: Clone + DTComp + Debug,
 {
+    #[track_caller]
     pub fn new(id: usize, edge_list: Vec<Edge<T>>, origin: &Rc<RefCell<TensorImpl<T>>>) -> Self {
         let node = ConcatBackward {
             input_refs: vec![],
@@ -138,6 +146,7 @@ where
             id,
             edge_list,
             origin: Some(Rc::downgrade(origin)),
+            location: Location::caller(),
             dim: Axis(0),
             ranges: vec![],
         };
@@ -0,0 +1,188 @@
+use super::DTComp;
+use super::Tensor;
+
+use crate::graph::backward::Backward;
+use crate::graph::backward::backward_types::BackwardType;
+use crate::graph::backward::backward_utils::apply_node;
+use crate::graph::edge::Edge;
+use crate::ops::compute::complex_compute::compute_conj_if_complex;
+use crate::tensor_core::tensor_impl::TensorImpl;
+
+use std::cell::RefCell;
+use std::fmt::Debug;
+use std::ops::Add;
+use std::panic::Location;
+use std::rc::{Rc, Weak};
+
+#[derive(Debug)]
+pub struct ConjBackward<T>
+where
+    T: DTComp + Clone + Debug,
+{
+    input_refs: Vec<Rc<RefCell<TensorImpl<T>>>>,
+    input_shapes: Vec<Vec<usize>>,
+    name: BackwardType,
+    id: usize,
+    edge_list: Vec<Edge<T>>,
+    origin: Option<Weak<RefCell<TensorImpl<T>>>>,
+    location: &'static Location<'static>,
+}
+
+impl<T> Backward<T> for ConjBackward<T>
+where
+    T: Clone + DTComp + Debug + 'static + Add<Output = T>,
+{
+    fn save_grad_to_origin_tensor(&self, grad: &Rc<Tensor<T>>) {
+        if let Some(origin_as_option_ref) = self.origin.as_ref() {
+            if let Some(origin_as_strong_rc) = origin_as_option_ref.upgrade() {
+                if let Some(origin_ref) = origin_as_strong_rc.borrow().get_autograd_ref_().as_ref()
+                {
+                    origin_ref.accumulate_grad(grad);
+                }
+            }
+        } else {
+            panic!(
+                "Dangling graph node, no origin tensor found at node: {} with id: {}, created at {}",
+                self.get_name(),
+                self.get_id(),
+                self.get_location(),
+            );
+        }
+    }
+
+    fn apply(&self, upstream_gradient: Rc<Tensor<T>>, retain_graph: bool) {
+        if retain_graph {
+            self.save_grad_to_origin_tensor(&upstream_gradient);
+        }
+
+        for edge in self.get_edge_list().iter() {
+            let next_grad = self.calculate_gradient_for_next_node(&upstream_gradient, Some(&edge));
+
+            let next_node = edge.get_next_grad_fn();
+            apply_node(&next_node, next_grad, retain_graph);
+        }
+    }
+
+    fn calculate_gradient_for_next_node(
+        &self,
+        upstream_gradient: &Rc<Tensor<T>>,
+        edge: Option<&Edge<T>>,
+    ) -> Rc<Tensor<T>> {
+        if let Some(_) = edge {
+            // conjugation is its own inverse, under the conjugate Wirtinger convention the
+            // gradient is conjugated the same way as the input
+            let result_tensor = compute_conj_if_complex(upstream_gradient);
+            return Rc::new(result_tensor);
+        } else {
+            panic!(
+                "Error, no edge found to connect to and calculate gradient because conj requires a tensor to operate"
+            );
+        }
+    }
+
+    fn get_edge_list(&self) -> &[Edge<T>] {
+        return &self.edge_list;
+    }
+
+    fn add_to_edge_list(&mut self, edge: Edge<T>) {
+        self.edge_list.push(edge);
+    }
+
+    fn save_input_refs(&mut self, input_refs: Vec<Rc<RefCell<TensorImpl<T>>>>) {
+        self.input_refs.extend(input_refs);
+    }
+
+    fn get_input_refs(&self) -> &[Rc<RefCell<TensorImpl<T>>>] {
+        return &self.input_refs;
+    }
+
+    fn clear_input_refs(&mut self) {
+        self.input_refs.clear();
+    }
+
+    fn get_id(&self) -> usize {
+        return self.id;
+    }
+
+    fn get_name(&self) -> String {
+        return self.name.to_string();
+    }
+
+    fn get_location(&self) -> &'static Location<'static> {
+        return self.location;
+    }
+
+    fn get_origin(&self) -> Option<Rc<RefCell<TensorImpl<T>>>> {
+        match &self.origin {
+            Some(origin_weak) => {
+                let origin_rc = origin_weak.upgrade();
+                return origin_rc;
+            }
+            None => {
+                panic!("no origin found on this tensor");
+            }
+        }
+    }
+}
+
+impl<T> ConjBackward<T>
+where
+    T: Clone + DTComp + Debug,
+{
+    #[track_caller]
+    pub fn new(id: usize, edge_list: Vec<Edge<T>>, origin: &Rc<RefCell<TensorImpl<T>>>) -> Self {
+        let node = ConjBackward {
+            input_refs: vec![],
+            input_shapes: vec![],
+            name: BackwardType::ConjBackward,
+            id,
+            edge_list,
+            origin: Some(Rc::downgrade(origin)),
+            location: Location::caller(),
+        };
+
+        return node;
+    }
+
+    pub fn save_input_shapes(&mut self, input_shapes: Vec<Vec<usize>>) {
+        self.input_shapes.extend(input_shapes);
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    #[allow(unused)]
+    use super::*;
+    use num_complex::Complex;
+
+    #[test]
+    fn conj_backward_operation() {
+        let x1 = Tensor::new(
+            vec![Complex::new(1.0_f64, 2.0), Complex::new(-3.0, 0.5)],
+            vec![2],
+            true,
+        );
+        let x2 = Tensor::new(
+            vec![Complex::new(0.0_f64, 1.0), Complex::new(2.0, 0.0)],
+            vec![2],
+            false,
+        );
+
+        // the real part of conj(x1) * x2 is x1.re * x2.re + x1.im * x2.im, so the gradient of x1
+        // is x2
+        let z = (&x1.conj() * &x2).real();
+        assert_eq!(z.get_grad_fn().borrow().get_name(), "ComplexPartBackward");
+
+        z.backward(Tensor::new(vec![1.0, 1.0], vec![2], false), false);
+
+        assert_eq!(
+            x1.grad()
+                .unwrap()
+                .get_raw_data()
+                .iter()
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![Complex::new(0.0, 1.0), Complex::new(2.0, 0.0)]
+        );
+    }
+}
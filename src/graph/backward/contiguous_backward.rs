@@ -0,0 +1,162 @@
+use super::DTComp;
+use super::Tensor;
+
+use crate::graph::backward::Backward;
+use crate::graph::backward::backward_types::BackwardType;
+use crate::graph::backward::backward_utils::apply_node;
+use crate::graph::edge::Edge;
+use crate::tensor_core::tensor_impl::TensorImpl;
+
+use std::cell::RefCell;
+use std::fmt::Debug;
+use std::ops::Add;
+use std::panic::Location;
+use std::rc::{Rc, Weak};
+
+#[derive(Debug)]
+pub struct ContiguousBackward<T>
+where
+    T: DTComp + Clone + Debug,
+{
+    input_refs: Vec<Rc<RefCell<TensorImpl<T>>>>,
+    name: BackwardType,
+    id: usize,
+    edge_list: Vec<Edge<T>>,
+    origin: Option<Weak<RefCell<TensorImpl<T>>>>,
+    location: &'static Location<'static>,
+}
+
+impl<T> Backward<T> for ContiguousBackward<T>
+where
+    T: Clone + DTComp + Debug + 'static + Add<Output = T>,
+{
+    fn save_grad_to_origin_tensor(&self, grad: &Rc<Tensor<T>>) {
+        if let Some(origin_as_option_ref) = self.origin.as_ref() {
+            if let Some(origin_as_strong_rc) = origin_as_option_ref.upgrade() {
+                if let Some(origin_ref) = origin_as_strong_rc.borrow().get_autograd_ref_().as_ref()
+                {
+                    origin_ref.accumulate_grad(grad);
+                }
+            }
+        } else {
+            panic!(
+                "Dangling graph node, no origin tensor found at node: {} with id: {}, created at {}",
+                self.get_name(),
+                self.get_id(),
+                self.get_location(),
+            );
+        }
+    }
+
+    fn apply(&self, upstream_gradient: Rc<Tensor<T>>, retain_graph: bool) {
+        if retain_graph {
+            self.save_grad_to_origin_tensor(&upstream_gradient);
+        }
+
+        for edge in self.get_edge_list().iter() {
+            let next_grad = self.calculate_gradient_for_next_node(&upstream_gradient, Some(&edge));
+
+            let next_node = edge.get_next_grad_fn();
+            apply_node(&next_node, next_grad, retain_graph);
+        }
+    }
+
+    fn calculate_gradient_for_next_node(
+        &self,
+        upstream_gradient: &Rc<Tensor<T>>,
+        edge: Option<&Edge<T>>,
+    ) -> Rc<Tensor<T>> {
+        if let Some(_) = edge {
+            // a contiguous copy has the same shape and elements as its input
+            return Rc::clone(upstream_gradient);
+        } else {
+            panic!(
+                "Error: No edge found to connect to and calculate gradient because contiguous is an operation requiring one tensor"
+            );
+        }
+    }
+
+    fn get_edge_list(&self) -> &[Edge<T>] {
+        return &self.edge_list;
+    }
+
+    fn add_to_edge_list(&mut self, edge: Edge<T>) {
+        self.edge_list.push(edge);
+    }
+
+    fn save_input_refs(&mut self, input_refs: Vec<Rc<RefCell<TensorImpl<T>>>>) {
+        self.input_refs.extend(input_refs);
+    }
+
+    fn get_input_refs(&self) -> &[Rc<RefCell<TensorImpl<T>>>] {
+        return &self.input_refs;
+    }
+
+    fn clear_input_refs(&mut self) {
+        self.input_refs.clear();
+    }
+
+    fn get_id(&self) -> usize {
+        return self.id;
+    }
+
+    fn get_name(&self) -> String {
+        return self.name.to_string();
+    }
+
+    fn get_location(&self) -> &'static Location<'static> {
+        return self.location;
+    }
+
+    fn get_origin(&self) -> Option<Rc<RefCell<TensorImpl<T>>>> {
+        match &self.origin {
+            Some(origin_weak) => {
+                let origin_rc = origin_weak.upgrade();
+                return origin_rc;
+            }
+            None => {
+                panic!("no origin found on this tensor");
+            }
+        }
+    }
+}
+
+impl<T> ContiguousBackward<T>
+where
+    T: Clone + DTComp + Debug,
+{
+    #[track_caller]
+    pub fn new(id: usize, edge_list: Vec<Edge<T>>, origin: &Rc<RefCell<TensorImpl<T>>>) -> Self {
+        let node = ContiguousBackward {
+            name: BackwardType::ContiguousBackward,
+            input_refs: vec![],
+            id,
+            edge_list,
+            origin: Some(Rc::downgrade(origin)),
+            location: Location::caller(),
+        };
+
+        return node;
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    #[allow(unused)]
+    use super::*;
+    use crate::utils::testing_utils::total_test_for_backward_operation;
+
+    #[test]
+    fn contiguous_backward_operation() {
+        let x1 = Tensor::new(vec![1, 2, 3, 4, 5, 6, 7, 8], vec![4, 2], true).as_float_32();
+        let z = x1.transpose(None).contiguous();
+
+        total_test_for_backward_operation(
+            vec![&x1],
+            vec![Tensor::new(vec![1, 1, 1, 1, 1, 1, 1, 1], vec![4, 2], false).as_float_32()],
+            &z,
+            "ContiguousBackward",
+            Tensor::new(vec![1, 3, 5, 7, 2, 4, 6, 8], vec![2, 4], false).as_float_32(),
+        );
+    }
+}
@@ -1,14 +1,13 @@
 use ndarray::ScalarOperand;
-use num_traits::Signed;
 
 use super::DTComp;
 use super::Tensor;
 
 use crate::graph::backward::Backward;
 use crate::graph::backward::backward_types::BackwardType;
-use crate::graph::backward::backward_utils::gradient_from_broadcast;
+use crate::graph::backward::backward_utils::{apply_node, gradient_from_broadcast};
 use crate::graph::edge::Edge;
-use crate::ops::compute::add_compute::add_compute_tensor_tensor;
+use crate::ops::compute::complex_compute::compute_conj_if_complex;
 use crate::ops::compute::div_compute::div_compute_tensor_scalar;
 use crate::ops::compute::div_compute::div_compute_tensor_tensor;
 use crate::ops::compute::div_compute::div_compute_tensorimpl_tensorimpl;
@@ -21,6 +20,9 @@ use std::fmt::Debug;
 use std::ops::Add;
 use std::ops::Deref;
 use std::ops::Div;
+use std::ops::Mul;
+use std::ops::Neg;
+use std::panic::Location;
 use std::rc::{Rc, Weak};
 
 #[derive(Debug)]
@@ -29,11 +31,14 @@ where
     T: DTComp + Clone + Debug,
 {
     input_refs: Vec<Rc<RefCell<TensorImpl<T>>>>,
+    saved_input_nrs: Vec<usize>,
+    input_shapes: Vec<Vec<usize>>,
     name: BackwardType,
     id: usize,
     edge_list: Vec<Edge<T>>,
     scalar: Option<S>,
     origin: Option<Weak<RefCell<TensorImpl<T>>>>,
+    location: &'static Location<'static>,
 }
 
 impl<T, S> Backward<T> for DivBackward<T, S>
@@ -45,7 +50,8 @@ where
         + Div<Output = T>
         + Div<S, Output = T>
         + Add<Output = T>
-        + Signed,
+        + Mul<Output = T>
+        + Neg<Output = T>,
     S: ScalarOperand + Clone + Debug,
 {
     fn save_grad_to_origin_tensor(&self, grad: &Rc<Tensor<T>>) {
@@ -53,21 +59,15 @@ where
             if let Some(origin_as_strong_rc) = origin_as_option_ref.upgrade() {
                 if let Some(origin_ref) = origin_as_strong_rc.borrow().get_autograd_ref_().as_ref()
                 {
-                    if origin_ref.grad_is_set() {
-                        let old_grad = origin_ref.get_grad_as_tensor();
-                        let new_grad = add_compute_tensor_tensor(old_grad.deref(), grad.deref());
-
-                        origin_ref.set_grad(Rc::new(new_grad));
-                    } else {
-                        origin_ref.set_grad(Rc::clone(grad));
-                    }
+                    origin_ref.accumulate_grad(grad);
                 }
             }
         } else {
             panic!(
-                "Dangling graph node, no origin tensor found at node: {} with id: {}",
+                "Dangling graph node, no origin tensor found at node: {} with id: {}, created at {}",
                 self.get_name(),
                 self.get_id(),
+                self.get_location(),
             );
         }
     }
@@ -81,7 +81,7 @@ where
             let next_grad = self.calculate_gradient_for_next_node(&upstream_gradient, Some(&edge));
 
             let next_node = edge.get_next_grad_fn();
-            next_node.borrow().apply(next_grad, retain_graph);
+            apply_node(&next_node, next_grad, retain_graph);
         }
     }
 
@@ -90,27 +90,32 @@ where
         upstream_gradient: &Rc<Tensor<T>>,
         edge: Option<&Edge<T>>,
     ) -> Rc<Tensor<T>> {
-        if self.input_refs.len() == 2 {
+        let upstream_gradient = compute_conj_if_complex(upstream_gradient.deref());
+
+        if let Some(scalar) = self.scalar.as_ref() {
+            let tensor = div_compute_tensor_scalar(&upstream_gradient, scalar.clone());
+            let tensor = compute_conj_if_complex(&tensor);
+
+            return Rc::new(gradient_from_broadcast(&tensor, &self.input_shapes[0]));
+        } else {
             if let Some(edge) = edge {
                 let edge_nr = edge.input_nr;
 
                 let tensor;
-                let intended_shape;
 
                 if edge_nr == 0 {
-                    let other_tensor = Rc::clone(&self.input_refs[1]);
+                    let other_tensor = self.get_saved_input(1);
                     tensor = div_compute_tensorimpl_tensorimpl(
                         upstream_gradient.__get_tensor_impl(),
-                        other_tensor.deref(),
+                        other_tensor,
                     );
-                    intended_shape = self.input_refs[0].borrow().get_raw_shape();
                 } else {
-                    let other_tensor = Rc::clone(&self.input_refs[0]);
-                    let self_tensor = Rc::clone(&self.input_refs[1]);
+                    let other_tensor = self.get_saved_input(0);
+                    let self_tensor = self.get_saved_input(1);
 
                     let self_tensor =
                         mul_compute_tensorimpl_tensorimpl(self_tensor.deref(), self_tensor.deref());
-                    let reverse_upstream_gradient = neg_compute_tensor(upstream_gradient.deref());
+                    let reverse_upstream_gradient = neg_compute_tensor(&upstream_gradient);
 
                     let product_tensor = mul_compute_tensorimpl_tensorimpl(
                         other_tensor.deref(),
@@ -118,28 +123,16 @@ where
                     );
 
                     tensor = div_compute_tensor_tensor(&product_tensor, &self_tensor);
-                    intended_shape = self.input_refs[1].borrow().get_raw_shape();
                 }
-                return Rc::new(gradient_from_broadcast(&tensor, &intended_shape));
+
+                let tensor = compute_conj_if_complex(&tensor);
+                return Rc::new(gradient_from_broadcast(
+                    &tensor,
+                    &self.input_shapes[edge_nr],
+                ));
             } else {
-                panic!(
-                    "Cannot calculate gradient because of wrong number of inputs. Expected 2 inputs, received {} inputs.",
-                    self.input_refs.len()
-                );
+                panic!("Cannot calculate gradient because of missing inputs");
             }
-        } else {
-            let tensor = div_compute_tensor_scalar(
-                upstream_gradient.deref(),
-                self.scalar
-                    .as_ref()
-                    .expect("Cannot calculate gradient, missing scalar")
-                    .clone(),
-            );
-
-            return Rc::new(gradient_from_broadcast(
-                &tensor,
-                &self.input_refs[0].borrow().get_raw_shape(),
-            ));
         }
     }
 
@@ -155,6 +148,15 @@ where
         self.input_refs.extend(input_refs);
     }
 
+    fn get_input_refs(&self) -> &[Rc<RefCell<TensorImpl<T>>>] {
+        return &self.input_refs;
+    }
+
+    fn clear_input_refs(&mut self) {
+        self.input_refs.clear();
+        self.saved_input_nrs.clear();
+    }
+
     fn get_id(&self) -> usize {
         return self.id;
     }
@@ -163,6 +165,10 @@ where
         return self.name.to_string();
     }
 
+    fn get_location(&self) -> &'static Location<'static> {
+        return self.location;
+    }
+
     fn get_origin(&self) -> Option<Rc<RefCell<TensorImpl<T>>>> {
         match &self.origin {
             Some(origin_weak) => {
@@ -181,19 +187,46 @@ where
     T: Clone + DTComp + Debug,
     S: ScalarOperand + Clone + Debug,
 {
+    #[track_caller]
     pub fn new(id: usize, edge_list: Vec<Edge<T>>, origin: &Rc<RefCell<TensorImpl<T>>>) -> Self {
         let node = DivBackward {
             name: BackwardType::DivBackward,
             input_refs: vec![],
+            saved_input_nrs: vec![],
+            input_shapes: vec![],
             id,
             edge_list,
             scalar: None,
             origin: Some(Rc::downgrade(origin)),
+            location: Location::caller(),
         };
 
         return node;
     }
 
+    /// Saves a single input under its input number. Only the inputs needed by an existing edge
+    /// are saved, so the others can be freed after the forward pass.
+    pub fn save_input(&mut self, input_nr: usize, input_ref: Rc<RefCell<TensorImpl<T>>>) {
+        self.saved_input_nrs.push(input_nr);
+        self.input_refs.push(input_ref);
+    }
+
+    fn get_saved_input(&self, input_nr: usize) -> &Rc<RefCell<TensorImpl<T>>> {
+        let position = self
+            .saved_input_nrs
+            .iter()
+            .position(|saved_nr| *saved_nr == input_nr)
+            .unwrap_or_else(|| panic!("Error, input {} was not saved on DivBackward", input_nr));
+
+        return &self.input_refs[position];
+    }
+
+    /// Saves the input shapes to undo broadcasting. The operands themselves are saved with
+    /// `save_input`.
+    pub fn save_input_shapes(&mut self, input_shapes: Vec<Vec<usize>>) {
+        self.input_shapes.extend(input_shapes);
+    }
+
     pub fn save_scalar(&mut self, scalar: S) {
         self.scalar = Some(scalar);
     }
@@ -1,21 +1,20 @@
-use num_traits::Float;
-
 use super::DTComp;
 use super::Tensor;
 
 use crate::graph::backward::Backward;
 use crate::graph::backward::backward_types::BackwardType;
+use crate::graph::backward::backward_utils::apply_node;
 use crate::graph::edge::Edge;
-use crate::ops::compute::add_compute::add_compute_tensor_tensor;
-use crate::ops::compute::exp_compute::exp_compute_tensorimpl;
-use crate::ops::compute::exp_compute::exp2_compute_tensorimpl;
-use crate::ops::compute::mul_compute::mul_compute_tensor_tensor;
+use crate::ops::compute::complex_compute::compute_conj_if_complex;
+use crate::ops::compute::mul_compute::mul_compute_tensorimpl_tensorimpl;
+use crate::tensor_core::dtypes::DTFloat;
 use crate::tensor_core::tensor_impl::TensorImpl;
 
 use std::cell::RefCell;
 use std::fmt::Debug;
 use std::ops::Add;
 use std::ops::Deref;
+use std::panic::Location;
 use std::rc::{Rc, Weak};
 
 #[derive(Debug)]
@@ -27,34 +26,28 @@ where
     name: BackwardType,
     id: usize,
     edge_list: Vec<Edge<T>>,
-    natural: bool,
     origin: Option<Weak<RefCell<TensorImpl<T>>>>,
+    location: &'static Location<'static>,
 }
 
 impl<T> Backward<T> for ExpBackward<T>
 where
-    T: Clone + DTComp + Debug + 'static + Add<Output = T> + Float,
+    T: Clone + DTComp + Debug + 'static + Add<Output = T> + DTFloat,
 {
     fn save_grad_to_origin_tensor(&self, grad: &Rc<Tensor<T>>) {
         if let Some(origin_as_option_ref) = self.origin.as_ref() {
             if let Some(origin_as_strong_rc) = origin_as_option_ref.upgrade() {
                 if let Some(origin_ref) = origin_as_strong_rc.borrow().get_autograd_ref_().as_ref()
                 {
-                    if origin_ref.grad_is_set() {
-                        let old_grad = origin_ref.get_grad_as_tensor();
-                        let new_grad = add_compute_tensor_tensor(old_grad.deref(), grad.deref());
-
-                        origin_ref.set_grad(Rc::new(new_grad));
-                    } else {
-                        origin_ref.set_grad(Rc::clone(grad));
-                    }
+                    origin_ref.accumulate_grad(grad);
                 }
             }
         } else {
             panic!(
-                "Dangling graph node, no origin tensor found at node: {} with id: {}",
+                "Dangling graph node, no origin tensor found at node: {} with id: {}, created at {}",
                 self.get_name(),
                 self.get_id(),
+                self.get_location(),
             );
         }
     }
@@ -68,7 +61,7 @@ where
             let next_grad = self.calculate_gradient_for_next_node(&upstream_gradient, Some(&edge));
 
             let next_node = edge.get_next_grad_fn();
-            next_node.borrow().apply(next_grad, retain_graph);
+            apply_node(&next_node, next_grad, retain_graph);
         }
     }
 
@@ -78,18 +71,16 @@ where
         edge: Option<&Edge<T>>,
     ) -> Rc<Tensor<T>> {
         if let Some(_) = edge {
-            let input = Rc::clone(&self.input_refs[0]);
-            if self.natural {
-                let input = exp_compute_tensorimpl(input.deref());
-                let result_tensor = mul_compute_tensor_tensor(upstream_gradient.deref(), &input);
+            let upstream_gradient = compute_conj_if_complex(upstream_gradient.deref());
 
-                return Rc::new(result_tensor);
-            } else {
-                let input = exp2_compute_tensorimpl(input.deref());
-                let result_tensor = mul_compute_tensor_tensor(upstream_gradient.deref(), &input);
+            // the saved tensor is the detached output, so the exponent is not recomputed
+            let output = Rc::clone(&self.input_refs[0]);
+            let result_tensor = mul_compute_tensorimpl_tensorimpl(
+                output.deref(),
+                upstream_gradient.__get_tensor_impl(),
+            );
 
-                return Rc::new(result_tensor);
-            }
+            return Rc::new(compute_conj_if_complex(&result_tensor));
         } else {
             panic!(
                 "Error: No edge found to connect to and calculate gradient because exponent is an operation requiring one or more tensor"
@@ -109,6 +100,14 @@ where
         self.input_refs.extend(input_refs);
     }
 
+    fn get_input_refs(&self) -> &[Rc<RefCell<TensorImpl<T>>>] {
+        return &self.input_refs;
+    }
+
+    fn clear_input_refs(&mut self) {
+        self.input_refs.clear();
+    }
+
     fn get_id(&self) -> usize {
         return self.id;
     }
@@ -117,6 +116,10 @@ where
         return self.name.to_string();
     }
 
+    fn get_location(&self) -> &'static Location<'static> {
+        return self.location;
+    }
+
     fn get_origin(&self) -> Option<Rc<RefCell<TensorImpl<T>>>> {
         match &self.origin {
             Some(origin_weak) => {
@@ -134,22 +137,19 @@ impl<T> ExpBackward<T>
 where
     T: Clone + DTComp + Debug,
 {
+    #[track_caller]
     pub fn new(id: usize, edge_list: Vec<Edge<T>>, origin: &Rc<RefCell<TensorImpl<T>>>) -> Self {
         let node = ExpBackward {
             name: BackwardType::ExpBackward,
             input_refs: vec![],
             id,
             edge_list,
-            natural: false,
             origin: Some(Rc::downgrade(origin)),
+            location: Location::caller(),
         };
 
         return node;
     }
-
-    pub fn save_natural_state(&mut self, natural: bool) {
-        self.natural = natural;
-    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,241 @@
+use num_traits::Float;
+
+use super::DTComp;
+use super::Tensor;
+
+use crate::graph::backward::Backward;
+use crate::graph::backward::backward_types::BackwardType;
+use crate::graph::backward::backward_utils::{apply_node, gradient_from_broadcast};
+use crate::graph::edge::Edge;
+use crate::ops::compute::fused_compute::fused_compute_backward;
+use crate::ops::lazy::fused_program::FusedProgram;
+use crate::tensor_core::tensor_impl::TensorImpl;
+
+use std::cell::RefCell;
+use std::fmt::Debug;
+use std::ops::Add;
+use std::ops::Deref;
+use std::panic::Location;
+use std::rc::{Rc, Weak};
+
+#[derive(Debug)]
+pub struct FusedBackward<T>
+where
+    T: DTComp + Clone + Debug,
+{
+    input_refs: Vec<Rc<RefCell<TensorImpl<T>>>>,
+    name: BackwardType,
+    id: usize,
+    edge_list: Vec<Edge<T>>,
+    program: Option<Rc<FusedProgram<T>>>,
+    origin: Option<Weak<RefCell<TensorImpl<T>>>>,
+    location: &'static Location<'static>,
+}
+
+impl<T> Backward<T> for FusedBackward<T>
+where
+    T: Clone + DTComp + Debug + 'static + Add<Output = T> + Float,
+{
+    fn save_grad_to_origin_tensor(&self, grad: &Rc<Tensor<T>>) {
+        if let Some(origin_as_option_ref) = self.origin.as_ref() {
+            if let Some(origin_as_strong_rc) = origin_as_option_ref.upgrade() {
+                if let Some(origin_ref) = origin_as_strong_rc.borrow().get_autograd_ref_().as_ref()
+                {
+                    origin_ref.accumulate_grad(grad);
+                }
+            }
+        } else {
+            panic!(
+                "Dangling graph node, no origin tensor found at node: {} with id: {}, created at {}",
+                self.get_name(),
+                self.get_id(),
+                self.get_location(),
+            );
+        }
+    }
+
+    fn apply(&self, upstream_gradient: Rc<Tensor<T>>, retain_graph: bool) {
+        if retain_graph {
+            self.save_grad_to_origin_tensor(&upstream_gradient);
+        }
+
+        let next_grads = self.calculate_gradients_for_all_edges(&upstream_gradient);
+
+        for (edge, next_grad) in self.get_edge_list().iter().zip(next_grads) {
+            let next_node = edge.get_next_grad_fn();
+            apply_node(&next_node, next_grad, retain_graph);
+        }
+    }
+
+    fn calculate_gradient_for_next_node(
+        &self,
+        upstream_gradient: &Rc<Tensor<T>>,
+        edge: Option<&Edge<T>>,
+    ) -> Rc<Tensor<T>> {
+        if let Some(edge) = edge {
+            let mut needs_grad = vec![false; self.input_refs.len()];
+            needs_grad[edge.input_nr] = true;
+
+            let mut grads = fused_compute_backward(
+                self.get_program(),
+                &self.input_refs,
+                upstream_gradient.deref(),
+                &needs_grad,
+            );
+
+            let grad = grads[edge.input_nr]
+                .take()
+                .expect("Error: Fused kernel did not produce a gradient for the requested input");
+
+            return Rc::new(gradient_from_broadcast(
+                &grad,
+                &self.input_refs[edge.input_nr].borrow().get_raw_shape(),
+            ));
+        } else {
+            panic!(
+                "Error: No edge found to connect to and calculate gradient because a fused kernel requires one or more tensor"
+            );
+        }
+    }
+
+    /// One backward pass over the fused kernel yields the gradient of every input at once
+    fn calculate_gradients_for_all_edges(
+        &self,
+        upstream_gradient: &Rc<Tensor<T>>,
+    ) -> Vec<Rc<Tensor<T>>> {
+        let mut needs_grad = vec![false; self.input_refs.len()];
+        for edge in self.get_edge_list().iter() {
+            needs_grad[edge.input_nr] = true;
+        }
+
+        let mut grads = fused_compute_backward(
+            self.get_program(),
+            &self.input_refs,
+            upstream_gradient.deref(),
+            &needs_grad,
+        );
+
+        return self
+            .get_edge_list()
+            .iter()
+            .map(|edge| {
+                let grad = grads[edge.input_nr]
+                    .take()
+                    .expect("Error: Fused kernel did not produce a gradient for an edge");
+
+                Rc::new(gradient_from_broadcast(
+                    &grad,
+                    &self.input_refs[edge.input_nr].borrow().get_raw_shape(),
+                ))
+            })
+            .collect();
+    }
+
+    fn get_edge_list(&self) -> &[Edge<T>] {
+        return &self.edge_list;
+    }
+
+    fn add_to_edge_list(&mut self, edge: Edge<T>) {
+        self.edge_list.push(edge);
+    }
+
+    fn save_input_refs(&mut self, input_refs: Vec<Rc<RefCell<TensorImpl<T>>>>) {
+        self.input_refs.extend(input_refs);
+    }
+
+    fn get_input_refs(&self) -> &[Rc<RefCell<TensorImpl<T>>>] {
+        return &self.input_refs;
+    }
+
+    fn clear_input_refs(&mut self) {
+        self.input_refs.clear();
+    }
+
+    fn get_id(&self) -> usize {
+        return self.id;
+    }
+
+    fn get_name(&self) -> String {
+        return self.name.to_string();
+    }
+
+    fn get_location(&self) -> &'static Location<'static> {
+        return self.location;
+    }
+
+    fn get_origin(&self) -> Option<Rc<RefCell<TensorImpl<T>>>> {
+        match &self.origin {
+            Some(origin_weak) => {
+                let origin_rc = origin_weak.upgrade();
+                return origin_rc;
+            }
+            None => {
+                panic!("no origin found on this tensor");
+            }
+        }
+    }
+}
+
+impl<T> FusedBackward<T>
+where
+    T: Clone + DTComp + Debug,
+{
+    #[track_caller]
+    pub fn new(id: usize, edge_list: Vec<Edge<T>>, origin: &Rc<RefCell<TensorImpl<T>>>) -> Self {
+        let node = FusedBackward {
+            name: BackwardType::FusedBackward,
+            input_refs: vec![],
+            id,
+            edge_list,
+            program: None,
+            origin: Some(Rc::downgrade(origin)),
+            location: Location::caller(),
+        };
+
+        return node;
+    }
+
+    pub fn save_program(&mut self, program: Rc<FusedProgram<T>>) {
+        self.program = Some(program);
+    }
+
+    pub fn get_program(&self) -> &FusedProgram<T> {
+        return self
+            .program
+            .as_ref()
+            .expect("Error: Fused kernel node was created without a program");
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    #[allow(unused)]
+    use super::*;
+    use crate::utils::testing_utils::total_test_for_backward_operation;
+
+    #[test]
+    fn fused_backward_operation() {
+        let x1 = Tensor::new(vec![1, 2, 3, 4], vec![4, 1], true).as_float_32();
+        let x2 = Tensor::new(vec![1, 2], vec![1, 2], true).as_float_32();
+
+        let z = (x1.lazy().ln() * x1.lazy() + x2.lazy().exp()).eval();
+
+        let expected_z: Vec<f32> = [1.0_f32, 2.0, 3.0, 4.0]
+            .iter()
+            .flat_map(|a| [1.0_f32, 2.0].map(|b| a.ln() * a + b.exp()))
+            .collect();
+
+        // d/dx1 of sum over the broadcasted dim: 2 * (ln(x1) + 1)
+        // d/dx2 of sum over the broadcasted dim: 4 * exp(x2)
+        total_test_for_backward_operation(
+            vec![&x1, &x2],
+            vec![
+                Tensor::new(vec![2.0, 3.3863, 4.1972, 4.7726], vec![4, 1], false).as_float_32(),
+                Tensor::new(vec![10.8731, 29.5562], vec![1, 2], false).as_float_32(),
+            ],
+            &z,
+            "FusedBackward",
+            Tensor::new(expected_z, vec![4, 2], false),
+        );
+    }
+}
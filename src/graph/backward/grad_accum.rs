@@ -2,7 +2,7 @@ use super::super::super::tensor_core::tensor_impl::TensorImpl;
 use super::super::backward::Backward;
 use super::super::edge::Edge;
 use crate::graph::backward::backward_types::BackwardType;
-use crate::ops::compute::add_compute::add_compute_tensor_tensor;
+use crate::graph::backward::backward_utils::next_node_id;
 
 use super::DTComp;
 use super::Tensor;
@@ -10,7 +10,7 @@ use super::Tensor;
 use std::cell::RefCell;
 use std::fmt::Debug;
 use std::ops::Add;
-use std::ops::Deref;
+use std::panic::Location;
 use std::rc::{Rc, Weak};
 
 #[derive(Debug)]
@@ -22,6 +22,7 @@ where
     id: usize,
     edge_list: Vec<Edge<T>>,
     origin: Option<Weak<RefCell<TensorImpl<T>>>>,
+    location: &'static Location<'static>,
 }
 
 impl<T> Backward<T> for GradAccum<T>
@@ -33,21 +34,15 @@ where
             if let Some(origin_as_strong_rc) = origin_as_option_ref.upgrade() {
                 if let Some(origin_ref) = origin_as_strong_rc.borrow().get_autograd_ref_().as_ref()
                 {
-                    if origin_ref.grad_is_set() {
-                        let old_grad = origin_ref.get_grad_as_tensor();
-                        let new_grad = add_compute_tensor_tensor(old_grad.deref(), grad.deref());
-
-                        origin_ref.set_grad(Rc::new(new_grad));
-                    } else {
-                        origin_ref.set_grad(Rc::clone(grad));
-                    }
+                    origin_ref.accumulate_grad(grad);
                 }
             }
         } else {
             panic!(
-                "Dangling graph node, no origin tensor found at node: {} with id: {}",
+                "Dangling graph node, no origin tensor found at node: {} with id: {}, created at {}",
                 self.get_name(),
                 self.get_id(),
+                self.get_location(),
             );
         }
     }
@@ -77,6 +72,14 @@ where
         return;
     }
 
+    fn get_input_refs(&self) -> &[Rc<RefCell<TensorImpl<T>>>] {
+        return &[];
+    }
+
+    fn clear_input_refs(&mut self) {
+        return;
+    }
+
     fn get_id(&self) -> usize {
         return self.id;
     }
@@ -85,6 +88,10 @@ where
         return self.name.to_string();
     }
 
+    fn get_location(&self) -> &'static Location<'static> {
+        return self.location;
+    }
+
     fn get_origin(&self) -> Option<Rc<RefCell<TensorImpl<T>>>> {
         match &self.origin {
             Some(origin_weak) => {
@@ -102,23 +109,27 @@ impl<T> GradAccum<T>
 where
     T: DTComp + Debug,
 {
+    #[track_caller]
     pub fn new(edge_list: Vec<Edge<T>>) -> Self {
         let grad_accum = GradAccum {
             name: BackwardType::GradAccum,
-            id: 0,
+            id: next_node_id(),
             edge_list: edge_list,
             origin: None,
+            location: Location::caller(),
         };
 
         return grad_accum;
     }
 
+    #[track_caller]
     pub fn new_with_origin(edge_list: Vec<Edge<T>>, origin: Rc<RefCell<TensorImpl<T>>>) -> Self {
         let grad_accum = GradAccum {
             name: BackwardType::GradAccum,
-            id: 0,
+            id: next_node_id(),
             edge_list: edge_list,
             origin: Some(GradAccum::convert_origin_to_weak(origin)),
+            location: Location::caller(),
         };
 
         return grad_accum;
@@ -134,3 +145,32 @@ where
         self.origin = Some(Rc::downgrade(&origin));
     }
 }
+
+#[cfg(test)]
+pub mod test {
+    #[allow(unused)]
+    use super::*;
+
+    #[test]
+    fn grad_accumulates_in_place() {
+        let x1 = Tensor::new(vec![1, 2, 3, 4], vec![4, 1], true).as_float_32();
+
+        let z = &x1 * 2.0;
+        z.backward(Tensor::ones_like(&z, None), false);
+
+        let grad_ptr = x1
+            .get_autograd_ref()
+            .as_ref()
+            .unwrap()
+            .get_grad_as_tensor()
+            .get_raw_data()
+            .as_ptr();
+
+        let z = &x1 * 3.0;
+        z.backward(Tensor::ones_like(&z, None), false);
+
+        let grad = x1.get_autograd_ref().as_ref().unwrap().get_grad_as_tensor();
+        assert_eq!(grad.get_raw_data().as_ptr(), grad_ptr);
+        assert!(grad.get_raw_data().iter().all(|elem| *elem == 5.0));
+    }
+}
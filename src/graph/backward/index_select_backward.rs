@@ -0,0 +1,205 @@
+use ndarray::ArrayD;
+use ndarray::Axis;
+use num_traits::Zero;
+
+use super::DTComp;
+use super::Tensor;
+
+use crate::graph::backward::Backward;
+use crate::graph::backward::backward_types::BackwardType;
+use crate::graph::backward::backward_utils::apply_node;
+use crate::graph::edge::Edge;
+use crate::tensor_core::tensor_impl::TensorImpl;
+
+use std::cell::RefCell;
+use std::fmt::Debug;
+use std::ops::Add;
+use std::panic::Location;
+use std::rc::{Rc, Weak};
+
+#[derive(Debug)]
+pub struct IndexSelectBackward<T>
+where
+    T: DTComp + Clone + Debug,
+{
+    input_refs: Vec<Rc<RefCell<TensorImpl<T>>>>,
+    input_shapes: Vec<Vec<usize>>,
+    name: BackwardType,
+    id: usize,
+    edge_list: Vec<Edge<T>>,
+    origin: Option<Weak<RefCell<TensorImpl<T>>>>,
+    indices: Vec<usize>,
+    dim: Axis,
+    location: &'static Location<'static>,
+}
+
+impl<T> Backward<T> for IndexSelectBackward<T>
+where
+    T: Clone + DTComp + Debug + 'static + Add<Output = T> + Zero,
+{
+    fn save_grad_to_origin_tensor(&self, grad: &Rc<Tensor<T>>) {
+        if let Some(origin_as_option_ref) = self.origin.as_ref() {
+            if let Some(origin_as_strong_rc) = origin_as_option_ref.upgrade() {
+                if let Some(origin_ref) = origin_as_strong_rc.borrow().get_autograd_ref_().as_ref()
+                {
+                    origin_ref.accumulate_grad(grad);
+                }
+            }
+        } else {
+            panic!(
+                "Dangling graph node, no origin tensor found at node: {} with id: {}, created at {}",
+                self.get_name(),
+                self.get_id(),
+                self.get_location(),
+            );
+        }
+    }
+
+    fn apply(&self, upstream_gradient: Rc<Tensor<T>>, retain_graph: bool) {
+        if retain_graph {
+            self.save_grad_to_origin_tensor(&upstream_gradient);
+        }
+
+        for edge in self.get_edge_list().iter() {
+            let next_grad = self.calculate_gradient_for_next_node(&upstream_gradient, Some(&edge));
+
+            let next_node = edge.get_next_grad_fn();
+            apply_node(&next_node, next_grad, retain_graph);
+        }
+    }
+
+    fn calculate_gradient_for_next_node(
+        &self,
+        upstream_gradient: &Rc<Tensor<T>>,
+        edge: Option<&Edge<T>>,
+    ) -> Rc<Tensor<T>> {
+        if let Some(_) = edge {
+            let upstream_gradient_raw_data = upstream_gradient.get_raw_data();
+
+            let intended_shape = self.input_shapes[0].clone();
+            let mut grad_output = ArrayD::<T>::zeros(intended_shape);
+
+            // an index selected more than once receives the sum of the gradients of its copies
+            for (i, &index) in self.indices.iter().enumerate() {
+                let upstream_lane = upstream_gradient_raw_data.index_axis(self.dim, i);
+
+                grad_output.index_axis_mut(self.dim, index).zip_mut_with(
+                    &upstream_lane,
+                    |grad, up_grad| {
+                        *grad = grad.clone() + up_grad.clone();
+                    },
+                );
+            }
+
+            let result_tensor = Tensor::from_raw_array(grad_output, false);
+            return Rc::new(result_tensor);
+        } else {
+            panic!(
+                "Error, no edge found to connect to and calculate gradient because index select requires a tensor to operate"
+            );
+        }
+    }
+
+    fn get_edge_list(&self) -> &[Edge<T>] {
+        return &self.edge_list;
+    }
+
+    fn add_to_edge_list(&mut self, edge: Edge<T>) {
+        self.edge_list.push(edge);
+    }
+
+    fn save_input_refs(&mut self, input_refs: Vec<Rc<RefCell<TensorImpl<T>>>>) {
+        self.input_refs.extend(input_refs);
+    }
+
+    fn get_input_refs(&self) -> &[Rc<RefCell<TensorImpl<T>>>] {
+        return &self.input_refs;
+    }
+
+    fn clear_input_refs(&mut self) {
+        self.input_refs.clear();
+    }
+
+    fn get_id(&self) -> usize {
+        return self.id;
+    }
+
+    fn get_name(&self) -> String {
+        return self.name.to_string();
+    }
+
+    fn get_location(&self) -> &'static Location<'static> {
+        return self.location;
+    }
+
+    fn get_origin(&self) -> Option<Rc<RefCell<TensorImpl<T>>>> {
+        match &self.origin {
+            Some(origin_weak) => {
+                let origin_rc = origin_weak.upgrade();
+                return origin_rc;
+            }
+            None => {
+                panic!("no origin found on this tensor");
+            }
+        }
+    }
+}
+
+impl<T> IndexSelectBackward<T>
+where
+    T: Clone + DTComp + Debug,
+{
+    #[track_caller]
+    pub fn new(id: usize, edge_list: Vec<Edge<T>>, origin: &Rc<RefCell<TensorImpl<T>>>) -> Self {
+        let node = IndexSelectBackward {
+            input_refs: vec![],
+            input_shapes: vec![],
+            name: BackwardType::IndexSelectBackward,
+            id,
+            edge_list,
+            origin: Some(Rc::downgrade(origin)),
+            location: Location::caller(),
+            indices: vec![],
+            dim: Axis(0),
+        };
+
+        return node;
+    }
+
+    /// Saves the shape of the input, the gradient is added into zeros of this shape along the
+    /// selected indices
+    pub fn save_input_shapes(&mut self, input_shapes: Vec<Vec<usize>>) {
+        self.input_shapes.extend(input_shapes);
+    }
+
+    pub fn save_indices(&mut self, indices: Vec<usize>) {
+        self.indices = indices;
+    }
+
+    pub fn save_dim(&mut self, dim: Axis) {
+        self.dim = dim;
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    #[allow(unused)]
+    use super::*;
+    use crate::utils::testing_utils::total_test_for_backward_operation;
+
+    #[test]
+    fn index_select_backward_operation() {
+        let x1 = Tensor::new(vec![1, 2, 3, 4, 5, 6], vec![3, 2], true).as_float_32();
+        let indices = Tensor::new(vec![2, 0, 2], vec![3], false);
+
+        let z = x1.index_select(Axis(0), &indices);
+
+        total_test_for_backward_operation(
+            vec![&x1],
+            vec![Tensor::new(vec![1, 1, 0, 0, 2, 2], vec![3, 2], false).as_float_32()],
+            &z,
+            "IndexSelectBackward",
+            Tensor::new(vec![5, 6, 1, 2, 5, 6], vec![3, 2], false).as_float_32(),
+        );
+    }
+}
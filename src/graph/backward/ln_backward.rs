@@ -3,8 +3,9 @@ use super::Tensor;
 
 use crate::graph::backward::Backward;
 use crate::graph::backward::backward_types::BackwardType;
+use crate::graph::backward::backward_utils::apply_node;
 use crate::graph::edge::Edge;
-use crate::ops::compute::add_compute::add_compute_tensor_tensor;
+use crate::ops::compute::complex_compute::compute_conj_if_complex;
 use crate::ops::compute::div_compute::div_compute_tensorimpl_tensorimpl;
 use crate::tensor_core::tensor_impl::TensorImpl;
 
@@ -13,6 +14,7 @@ use std::fmt::Debug;
 use std::ops::Add;
 use std::ops::Deref;
 use std::ops::Div;
+use std::panic::Location;
 use std::rc::{Rc, Weak};
 
 #[derive(Debug)]
@@ -25,6 +27,7 @@ where
     id: usize,
     edge_list: Vec<Edge<T>>,
     origin: Option<Weak<RefCell<TensorImpl<T>>>>,
+    location: &'static Location<'static>,
 }
 
 impl<T> Backward<T> for LnBackward<T>
@@ -36,21 +39,15 @@ where
             if let Some(origin_as_strong_rc) = origin_as_option_ref.upgrade() {
                 if let Some(origin_ref) = origin_as_strong_rc.borrow().get_autograd_ref_().as_ref()
                 {
-                    if origin_ref.grad_is_set() {
-                        let old_grad = origin_ref.get_grad_as_tensor();
-                        let new_grad = add_compute_tensor_tensor(old_grad.deref(), grad.deref());
-
-                        origin_ref.set_grad(Rc::new(new_grad));
-                    } else {
-                        origin_ref.set_grad(Rc::clone(grad));
-                    }
+                    origin_ref.accumulate_grad(grad);
                 }
             }
         } else {
             panic!(
-                "Dangling graph node, no origin tensor found at node: {} with id: {}",
+                "Dangling graph node, no origin tensor found at node: {} with id: {}, created at {}",
                 self.get_name(),
                 self.get_id(),
+                self.get_location(),
             );
         }
     }
@@ -64,7 +61,7 @@ where
             let next_grad = self.calculate_gradient_for_next_node(&upstream_gradient, Some(&edge));
 
             let next_node = edge.get_next_grad_fn();
-            next_node.borrow().apply(next_grad, retain_graph);
+            apply_node(&next_node, next_grad, retain_graph);
         }
     }
 
@@ -74,13 +71,15 @@ where
         edge: Option<&Edge<T>>,
     ) -> Rc<Tensor<T>> {
         if let Some(_) = edge {
+            let upstream_gradient = compute_conj_if_complex(upstream_gradient.deref());
+
             let self_tensor = Rc::clone(&self.input_refs[0]);
             let tensor = div_compute_tensorimpl_tensorimpl(
                 upstream_gradient.__get_tensor_impl(),
                 self_tensor.deref(),
             );
 
-            return Rc::new(tensor);
+            return Rc::new(compute_conj_if_complex(&tensor));
         } else {
             panic!(
                 "No edge found to connect to and calculate gradient because ln is a self operation"
@@ -100,6 +99,14 @@ where
         self.input_refs.extend(input_refs);
     }
 
+    fn get_input_refs(&self) -> &[Rc<RefCell<TensorImpl<T>>>] {
+        return &self.input_refs;
+    }
+
+    fn clear_input_refs(&mut self) {
+        self.input_refs.clear();
+    }
+
     fn get_id(&self) -> usize {
         return self.id;
     }
@@ -108,6 +115,10 @@ where
         return self.name.to_string();
     }
 
+    fn get_location(&self) -> &'static Location<'static> {
+        return self.location;
+    }
+
     fn get_origin(&self) -> Option<Rc<RefCell<TensorImpl<T>>>> {
         match &self.origin {
             Some(origin_weak) => {
@@ -125,6 +136,7 @@ impl<T> LnBackward<T>
 where
     T: Clone + DTComp + Debug,
 {
+    #[track_caller]
     pub fn new(id: usize, edge_list: Vec<Edge<T>>, origin: &Rc<RefCell<TensorImpl<T>>>) -> Self {
         let node = LnBackward {
             name: BackwardType::LnBackward,
@@ -132,6 +144,7 @@ where
             id,
             edge_list,
             origin: Some(Rc::downgrade(origin)),
+            location: Location::caller(),
         };
 
         return node;
@@ -5,9 +5,10 @@ use super::Tensor;
 
 use crate::graph::backward::Backward;
 use crate::graph::backward::backward_types::BackwardType;
+use crate::graph::backward::backward_utils::apply_node;
 use crate::graph::edge::Edge;
-use crate::ops::compute::add_compute::add_compute_tensor_tensor;
-use crate::ops::compute::matmul_compute::matmul_compute_tensor_tensor;
+use crate::ops::compute::complex_compute::compute_conj_if_complex;
+use crate::ops::compute::matmul_compute::{dot_grad_compute, matmul_compute_tensor_tensor};
 use crate::ops::compute::shape_compute::compute_transpose_tensorimpl;
 use crate::tensor_core::tensor_impl::TensorImpl;
 use crate::utils::shaping_utils::get_shape_to_transpose_last_2_dim;
@@ -16,6 +17,7 @@ use std::cell::RefCell;
 use std::fmt::Debug;
 use std::ops::Add;
 use std::ops::Deref;
+use std::panic::Location;
 use std::rc::{Rc, Weak};
 
 #[derive(Debug)]
@@ -24,10 +26,12 @@ where
     T: DTComp + Clone + Debug,
 {
     input_refs: Vec<Rc<RefCell<TensorImpl<T>>>>,
+    saved_input_nrs: Vec<usize>,
     name: BackwardType,
     id: usize,
     edge_list: Vec<Edge<T>>,
     origin: Option<Weak<RefCell<TensorImpl<T>>>>,
+    location: &'static Location<'static>,
 }
 
 impl<T> Backward<T> for MatmulBackward<T>
@@ -39,21 +43,15 @@ where
             if let Some(origin_as_strong_rc) = origin_as_option_ref.upgrade() {
                 if let Some(origin_ref) = origin_as_strong_rc.borrow().get_autograd_ref_().as_ref()
                 {
-                    if origin_ref.grad_is_set() {
-                        let old_grad = origin_ref.get_grad_as_tensor();
-                        let new_grad = add_compute_tensor_tensor(old_grad.deref(), grad.deref());
-
-                        origin_ref.set_grad(Rc::new(new_grad));
-                    } else {
-                        origin_ref.set_grad(Rc::clone(grad));
-                    }
+                    origin_ref.accumulate_grad(grad);
                 }
             }
         } else {
             panic!(
-                "Dangling graph node, no origin tensor found at node: {} with id: {}",
+                "Dangling graph node, no origin tensor found at node: {} with id: {}, created at {}",
                 self.get_name(),
                 self.get_id(),
+                self.get_location(),
             );
         }
     }
@@ -67,7 +65,7 @@ where
             let next_grad = self.calculate_gradient_for_next_node(&upstream_gradient, Some(&edge));
 
             let next_node = edge.get_next_grad_fn();
-            next_node.borrow().apply(next_grad, retain_graph);
+            apply_node(&next_node, next_grad, retain_graph);
         }
     }
 
@@ -76,10 +74,22 @@ where
         upstream_gradient: &Rc<Tensor<T>>,
         edge: Option<&Edge<T>>,
     ) -> Rc<Tensor<T>> {
+        let upstream_gradient = compute_conj_if_complex(upstream_gradient.deref());
+
         if let Some(e) = edge {
             let edge_index = e.get_edge_nr();
+
+            // dot products may have 1D inputs and a 0-d output, which have no last 2 dims
+            let other_ref = self.get_saved_input(1 - edge_index);
+            if other_ref.borrow().get_raw_shape().len() <= 2
+                && upstream_gradient.get_shape().len() <= 2
+            {
+                let tensor = dot_grad_compute(&upstream_gradient, other_ref, edge_index);
+                return Rc::new(compute_conj_if_complex(&tensor));
+            }
+
             if edge_index == 0 {
-                let other_ref = &self.input_refs[1];
+                let other_ref = self.get_saved_input(1);
                 let intended_shape =
                     get_shape_to_transpose_last_2_dim(other_ref.borrow().get_raw_shape());
 
@@ -88,12 +98,11 @@ where
                     Some(intended_shape),
                 );
 
-                let tensor =
-                    matmul_compute_tensor_tensor(upstream_gradient.deref(), &other_transposed);
+                let tensor = matmul_compute_tensor_tensor(&upstream_gradient, &other_transposed);
 
-                return Rc::new(tensor);
+                return Rc::new(compute_conj_if_complex(&tensor));
             } else {
-                let other_ref = &self.input_refs[0];
+                let other_ref = self.get_saved_input(0);
                 let intended_shape =
                     get_shape_to_transpose_last_2_dim(other_ref.borrow().get_raw_shape());
 
@@ -101,10 +110,9 @@ where
                     other_ref.deref(),
                     Some(intended_shape),
                 );
-                let tensor =
-                    matmul_compute_tensor_tensor(&other_transposed, upstream_gradient.deref());
+                let tensor = matmul_compute_tensor_tensor(&other_transposed, &upstream_gradient);
 
-                return Rc::new(tensor);
+                return Rc::new(compute_conj_if_complex(&tensor));
             }
         } else {
             panic!(
@@ -125,6 +133,15 @@ where
         self.input_refs.extend(input_refs);
     }
 
+    fn get_input_refs(&self) -> &[Rc<RefCell<TensorImpl<T>>>] {
+        return &self.input_refs;
+    }
+
+    fn clear_input_refs(&mut self) {
+        self.input_refs.clear();
+        self.saved_input_nrs.clear();
+    }
+
     fn get_id(&self) -> usize {
         return self.id;
     }
@@ -133,6 +150,10 @@ where
         return self.name.to_string();
     }
 
+    fn get_location(&self) -> &'static Location<'static> {
+        return self.location;
+    }
+
     fn get_origin(&self) -> Option<Rc<RefCell<TensorImpl<T>>>> {
         match &self.origin {
             Some(origin_weak) => {
@@ -150,17 +171,37 @@ impl<T> MatmulBackward<T>
 where
     T: Clone + DTComp + Debug,
 {
+    #[track_caller]
     pub fn new(id: usize, edge_list: Vec<Edge<T>>, origin: &Rc<RefCell<TensorImpl<T>>>) -> Self {
         let node = MatmulBackward {
             name: BackwardType::MatmulBackward,
             input_refs: vec![],
+            saved_input_nrs: vec![],
             id,
             edge_list,
             origin: Some(Rc::downgrade(origin)),
+            location: Location::caller(),
         };
 
         return node;
     }
+
+    /// Saves a single input under its input number. Only the inputs needed by an existing edge
+    /// are saved, so the others can be freed after the forward pass.
+    pub fn save_input(&mut self, input_nr: usize, input_ref: Rc<RefCell<TensorImpl<T>>>) {
+        self.saved_input_nrs.push(input_nr);
+        self.input_refs.push(input_ref);
+    }
+
+    fn get_saved_input(&self, input_nr: usize) -> &Rc<RefCell<TensorImpl<T>>> {
+        let position = self
+            .saved_input_nrs
+            .iter()
+            .position(|saved_nr| *saved_nr == input_nr)
+            .unwrap_or_else(|| panic!("Error, input {} was not saved on MatmulBackward", input_nr));
+
+        return &self.input_refs[position];
+    }
 }
 
 #[cfg(test)]
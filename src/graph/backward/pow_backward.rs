@@ -1,16 +1,17 @@
 use ndarray::ScalarOperand;
-use num_traits::Float;
 
 use super::DTComp;
 use super::Tensor;
 
 use crate::graph::backward::Backward;
 use crate::graph::backward::backward_types::BackwardType;
+use crate::graph::backward::backward_utils::apply_node;
 use crate::graph::edge::Edge;
-use crate::ops::compute::add_compute::add_compute_tensor_tensor;
+use crate::ops::compute::complex_compute::compute_conj_if_complex;
 use crate::ops::compute::mul_compute::mul_compute_tensor_scalar;
 use crate::ops::compute::mul_compute::mul_compute_tensor_tensor;
 use crate::ops::compute::pow_compute::pow_compute_tensorimpl;
+use crate::tensor_core::dtypes::DTFloat;
 use crate::tensor_core::tensor_impl::TensorImpl;
 
 use std::cell::RefCell;
@@ -18,6 +19,7 @@ use std::fmt::Debug;
 use std::ops::Add;
 use std::ops::Deref;
 use std::ops::Mul;
+use std::panic::Location;
 use std::rc::{Rc, Weak};
 
 #[derive(Debug)]
@@ -31,32 +33,34 @@ where
     edge_list: Vec<Edge<T>>,
     scalar: Option<T>,
     origin: Option<Weak<RefCell<TensorImpl<T>>>>,
+    location: &'static Location<'static>,
 }
 
 impl<T> Backward<T> for PowBackward<T>
 where
-    T: Clone + DTComp + Debug + 'static + Add<Output = T> + Mul<Output = T> + Float + ScalarOperand,
+    T: Clone
+        + DTComp
+        + Debug
+        + 'static
+        + Add<Output = T>
+        + Mul<Output = T>
+        + DTFloat
+        + ScalarOperand,
 {
     fn save_grad_to_origin_tensor(&self, grad: &Rc<Tensor<T>>) {
         if let Some(origin_as_option_ref) = self.origin.as_ref() {
             if let Some(origin_as_strong_rc) = origin_as_option_ref.upgrade() {
                 if let Some(origin_ref) = origin_as_strong_rc.borrow().get_autograd_ref_().as_ref()
                 {
-                    if origin_ref.grad_is_set() {
-                        let old_grad = origin_ref.get_grad_as_tensor();
-                        let new_grad = add_compute_tensor_tensor(old_grad.deref(), grad.deref());
-
-                        origin_ref.set_grad(Rc::new(new_grad));
-                    } else {
-                        origin_ref.set_grad(Rc::clone(grad));
-                    }
+                    origin_ref.accumulate_grad(grad);
                 }
             }
         } else {
             panic!(
-                "Dangling graph node, no origin tensor found at node: {} with id: {}",
+                "Dangling graph node, no origin tensor found at node: {} with id: {}, created at {}",
                 self.get_name(),
                 self.get_id(),
+                self.get_location(),
             );
         }
     }
@@ -70,7 +74,7 @@ where
             let next_grad = self.calculate_gradient_for_next_node(&upstream_gradient, Some(&edge));
 
             let next_node = edge.get_next_grad_fn();
-            next_node.borrow().apply(next_grad, retain_graph);
+            apply_node(&next_node, next_grad, retain_graph);
         }
     }
 
@@ -85,9 +89,10 @@ where
                 let self_tensor = pow_compute_tensorimpl(self_tensor.deref(), scalar - T::one());
                 let self_tensor = mul_compute_tensor_scalar(&self_tensor, scalar);
 
-                let tensor = mul_compute_tensor_tensor(&self_tensor, upstream_gradient);
+                let upstream_gradient = compute_conj_if_complex(upstream_gradient.deref());
+                let tensor = mul_compute_tensor_tensor(&self_tensor, &upstream_gradient);
 
-                return Rc::new(tensor);
+                return Rc::new(compute_conj_if_complex(&tensor));
             } else {
                 panic!("Error, no scalar found on a exp operation of base different than base e");
             }
@@ -110,6 +115,14 @@ where
         self.input_refs.extend(input_refs);
     }
 
+    fn get_input_refs(&self) -> &[Rc<RefCell<TensorImpl<T>>>] {
+        return &self.input_refs;
+    }
+
+    fn clear_input_refs(&mut self) {
+        self.input_refs.clear();
+    }
+
     fn get_id(&self) -> usize {
         return self.id;
     }
@@ -118,6 +131,10 @@ where
         return self.name.to_string();
     }
 
+    fn get_location(&self) -> &'static Location<'static> {
+        return self.location;
+    }
+
     fn get_origin(&self) -> Option<Rc<RefCell<TensorImpl<T>>>> {
         match &self.origin {
             Some(origin_weak) => {
@@ -135,6 +152,7 @@ impl<T> PowBackward<T>
 where
     T: Clone + DTComp + Debug,
 {
+    #[track_caller]
     pub fn new(id: usize, edge_list: Vec<Edge<T>>, origin: &Rc<RefCell<TensorImpl<T>>>) -> Self {
         let node = PowBackward {
             name: BackwardType::PowBackward,
@@ -143,6 +161,7 @@ where
             edge_list,
             scalar: None,
             origin: Some(Rc::downgrade(origin)),
+            location: Location::caller(),
         };
 
         return node;
@@ -0,0 +1,202 @@
+use ndarray::Axis;
+use num_traits::Zero;
+
+use super::DTComp;
+use super::Tensor;
+
+use crate::graph::backward::Backward;
+use crate::graph::backward::backward_types::BackwardType;
+use crate::graph::backward::backward_utils::apply_node;
+use crate::graph::edge::Edge;
+use crate::ops::compute::indexing_compute::compute_gather;
+use crate::tensor_core::tensor_impl::TensorImpl;
+
+use std::cell::RefCell;
+use std::fmt::Debug;
+use std::ops::Add;
+use std::panic::Location;
+use std::rc::{Rc, Weak};
+
+#[derive(Debug)]
+pub struct ScatterAddBackward<T>
+where
+    T: DTComp + Clone + Debug,
+{
+    input_refs: Vec<Rc<RefCell<TensorImpl<T>>>>,
+    input_shapes: Vec<Vec<usize>>,
+    name: BackwardType,
+    id: usize,
+    edge_list: Vec<Edge<T>>,
+    origin: Option<Weak<RefCell<TensorImpl<T>>>>,
+    indices: Option<Rc<Tensor<usize>>>,
+    dim: Axis,
+    location: &'static Location<'static>,
+}
+
+impl<T> Backward<T> for ScatterAddBackward<T>
+where
+    T: Clone + DTComp + Debug + 'static + Add<Output = T> + Zero,
+{
+    fn save_grad_to_origin_tensor(&self, grad: &Rc<Tensor<T>>) {
+        if let Some(origin_as_option_ref) = self.origin.as_ref() {
+            if let Some(origin_as_strong_rc) = origin_as_option_ref.upgrade() {
+                if let Some(origin_ref) = origin_as_strong_rc.borrow().get_autograd_ref_().as_ref()
+                {
+                    origin_ref.accumulate_grad(grad);
+                }
+            }
+        } else {
+            panic!(
+                "Dangling graph node, no origin tensor found at node: {} with id: {}, created at {}",
+                self.get_name(),
+                self.get_id(),
+                self.get_location(),
+            );
+        }
+    }
+
+    fn apply(&self, upstream_gradient: Rc<Tensor<T>>, retain_graph: bool) {
+        if retain_graph {
+            self.save_grad_to_origin_tensor(&upstream_gradient);
+        }
+
+        for edge in self.get_edge_list().iter() {
+            let next_grad = self.calculate_gradient_for_next_node(&upstream_gradient, Some(&edge));
+
+            let next_node = edge.get_next_grad_fn();
+            apply_node(&next_node, next_grad, retain_graph);
+        }
+    }
+
+    fn calculate_gradient_for_next_node(
+        &self,
+        upstream_gradient: &Rc<Tensor<T>>,
+        edge: Option<&Edge<T>>,
+    ) -> Rc<Tensor<T>> {
+        if let Some(edge) = edge {
+            // the elements of the tensor that is added into pass through unchanged
+            if edge.input_nr == 0 {
+                return Rc::clone(upstream_gradient);
+            }
+
+            if let Some(indices) = self.indices.as_ref() {
+                // every element of the source receives the gradient of the position it was added to
+                let result_tensor = compute_gather(upstream_gradient, self.dim, indices);
+                return Rc::new(result_tensor);
+            } else {
+                panic!(
+                    "Error, trying to calculate gradient of a scatter add function without any indices set"
+                );
+            }
+        } else {
+            panic!(
+                "Error, no edge found to connect to and calculate gradient because scatter add requires two tensors to operate"
+            );
+        }
+    }
+
+    fn get_edge_list(&self) -> &[Edge<T>] {
+        return &self.edge_list;
+    }
+
+    fn add_to_edge_list(&mut self, edge: Edge<T>) {
+        self.edge_list.push(edge);
+    }
+
+    fn save_input_refs(&mut self, input_refs: Vec<Rc<RefCell<TensorImpl<T>>>>) {
+        self.input_refs.extend(input_refs);
+    }
+
+    fn get_input_refs(&self) -> &[Rc<RefCell<TensorImpl<T>>>] {
+        return &self.input_refs;
+    }
+
+    fn clear_input_refs(&mut self) {
+        self.input_refs.clear();
+    }
+
+    fn get_id(&self) -> usize {
+        return self.id;
+    }
+
+    fn get_name(&self) -> String {
+        return self.name.to_string();
+    }
+
+    fn get_location(&self) -> &'static Location<'static> {
+        return self.location;
+    }
+
+    fn get_origin(&self) -> Option<Rc<RefCell<TensorImpl<T>>>> {
+        match &self.origin {
+            Some(origin_weak) => {
+                let origin_rc = origin_weak.upgrade();
+                return origin_rc;
+            }
+            None => {
+                panic!("no origin found on this tensor");
+            }
+        }
+    }
+}
+
+impl<T> ScatterAddBackward<T>
+where
+    T: Clone + DTComp + Debug,
+{
+    #[track_caller]
+    pub fn new(id: usize, edge_list: Vec<Edge<T>>, origin: &Rc<RefCell<TensorImpl<T>>>) -> Self {
+        let node = ScatterAddBackward {
+            input_refs: vec![],
+            input_shapes: vec![],
+            name: BackwardType::ScatterAddBackward,
+            id,
+            edge_list,
+            origin: Some(Rc::downgrade(origin)),
+            location: Location::caller(),
+            indices: None,
+            dim: Axis(0),
+        };
+
+        return node;
+    }
+
+    pub fn save_input_shapes(&mut self, input_shapes: Vec<Vec<usize>>) {
+        self.input_shapes.extend(input_shapes);
+    }
+
+    pub fn save_indices(&mut self, indices: Rc<Tensor<usize>>) {
+        self.indices = Some(indices);
+    }
+
+    pub fn save_dim(&mut self, dim: Axis) {
+        self.dim = dim;
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    #[allow(unused)]
+    use super::*;
+    use crate::utils::testing_utils::total_test_for_backward_operation;
+
+    #[test]
+    fn scatter_add_backward_operation() {
+        let x1 = Tensor::new(vec![1, 2, 3, 4, 5, 6], vec![2, 3], true).as_float_32();
+        let x2 = Tensor::new(vec![10, 20, 30, 40], vec![2, 2], true).as_float_32();
+        let indices = Tensor::new(vec![2, 0, 0, 0], vec![2, 2], false);
+
+        let z = x1.scatter_add(Axis(1), &indices, &x2);
+
+        total_test_for_backward_operation(
+            vec![&x1, &x2],
+            vec![
+                Tensor::new(vec![1, 1, 1, 1, 1, 1], vec![2, 3], false).as_float_32(),
+                Tensor::new(vec![1, 1, 1, 1], vec![2, 2], false).as_float_32(),
+            ],
+            &z,
+            "ScatterAddBackward",
+            Tensor::new(vec![21, 2, 13, 74, 5, 6], vec![2, 3], false).as_float_32(),
+        );
+    }
+}
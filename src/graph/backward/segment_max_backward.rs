@@ -0,0 +1,215 @@
+use ndarray::ArrayD;
+use ndarray::Dimension;
+use ndarray::IxDyn;
+use num_traits::Zero;
+
+use super::DTComp;
+use super::Tensor;
+
+use crate::graph::backward::Backward;
+use crate::graph::backward::backward_types::BackwardType;
+use crate::graph::backward::backward_utils::apply_node;
+use crate::graph::edge::Edge;
+use crate::tensor_core::tensor_impl::TensorImpl;
+
+use std::cell::RefCell;
+use std::fmt::Debug;
+use std::ops::Add;
+use std::panic::Location;
+use std::rc::{Rc, Weak};
+
+#[derive(Debug)]
+pub struct SegmentMaxBackward<T>
+where
+    T: DTComp + Clone + Debug,
+{
+    input_refs: Vec<Rc<RefCell<TensorImpl<T>>>>,
+    input_shapes: Vec<Vec<usize>>,
+    name: BackwardType,
+    id: usize,
+    edge_list: Vec<Edge<T>>,
+    origin: Option<Weak<RefCell<TensorImpl<T>>>>,
+    indices: Option<Rc<Tensor<usize>>>,
+    location: &'static Location<'static>,
+}
+
+impl<T> Backward<T> for SegmentMaxBackward<T>
+where
+    T: Clone + DTComp + Debug + 'static + Add<Output = T> + Zero,
+{
+    fn save_grad_to_origin_tensor(&self, grad: &Rc<Tensor<T>>) {
+        if let Some(origin_as_option_ref) = self.origin.as_ref() {
+            if let Some(origin_as_strong_rc) = origin_as_option_ref.upgrade() {
+                if let Some(origin_ref) = origin_as_strong_rc.borrow().get_autograd_ref_().as_ref()
+                {
+                    origin_ref.accumulate_grad(grad);
+                }
+            }
+        } else {
+            panic!(
+                "Dangling graph node, no origin tensor found at node: {} with id: {}, created at {}",
+                self.get_name(),
+                self.get_id(),
+                self.get_location(),
+            );
+        }
+    }
+
+    fn apply(&self, upstream_gradient: Rc<Tensor<T>>, retain_graph: bool) {
+        if retain_graph {
+            self.save_grad_to_origin_tensor(&upstream_gradient);
+        }
+
+        for edge in self.get_edge_list().iter() {
+            let next_grad = self.calculate_gradient_for_next_node(&upstream_gradient, Some(&edge));
+
+            let next_node = edge.get_next_grad_fn();
+            apply_node(&next_node, next_grad, retain_graph);
+        }
+    }
+
+    fn calculate_gradient_for_next_node(
+        &self,
+        upstream_gradient: &Rc<Tensor<T>>,
+        edge: Option<&Edge<T>>,
+    ) -> Rc<Tensor<T>> {
+        if let Some(_) = edge {
+            if let Some(indices) = self.indices.clone() {
+                let indices_raw_data = indices.get_raw_data();
+
+                let upstream_gradient_raw_data = upstream_gradient.get_raw_data();
+
+                let intended_shape = self.input_shapes[0].clone();
+                let num_rows = intended_shape[0];
+                let mut grad_output = ArrayD::<T>::zeros(intended_shape);
+
+                // only the row holding the maximum of a segment receives its gradient, and empty
+                // segments, whose index is the number of rows, send it nowhere
+                for ((position, &index), up_grad) in indices_raw_data
+                    .indexed_iter()
+                    .zip(upstream_gradient_raw_data.iter())
+                {
+                    if index == num_rows {
+                        continue;
+                    }
+
+                    let mut full_index = position.as_array_view().to_vec();
+                    full_index[0] = index;
+
+                    let grad = &mut grad_output[IxDyn(&full_index)];
+                    *grad = grad.clone() + up_grad.clone();
+                }
+
+                let result_tensor = Tensor::from_raw_array(grad_output, false);
+                return Rc::new(result_tensor);
+            } else {
+                panic!(
+                    "Error, trying to calculate gradient of a segment max function without any indices set"
+                );
+            }
+        } else {
+            panic!(
+                "Error, no edge found to connect to and calculate gradient because segment max requires a tensor to operate"
+            );
+        }
+    }
+
+    fn get_edge_list(&self) -> &[Edge<T>] {
+        return &self.edge_list;
+    }
+
+    fn add_to_edge_list(&mut self, edge: Edge<T>) {
+        self.edge_list.push(edge);
+    }
+
+    fn save_input_refs(&mut self, input_refs: Vec<Rc<RefCell<TensorImpl<T>>>>) {
+        self.input_refs.extend(input_refs);
+    }
+
+    fn get_input_refs(&self) -> &[Rc<RefCell<TensorImpl<T>>>] {
+        return &self.input_refs;
+    }
+
+    fn clear_input_refs(&mut self) {
+        self.input_refs.clear();
+    }
+
+    fn get_id(&self) -> usize {
+        return self.id;
+    }
+
+    fn get_name(&self) -> String {
+        return self.name.to_string();
+    }
+
+    fn get_location(&self) -> &'static Location<'static> {
+        return self.location;
+    }
+
+    fn get_origin(&self) -> Option<Rc<RefCell<TensorImpl<T>>>> {
+        match &self.origin {
+            Some(origin_weak) => {
+                let origin_rc = origin_weak.upgrade();
+                return origin_rc;
+            }
+            None => {
+                panic!("no origin found on this tensor");
+            }
+        }
+    }
+}
+
+impl<T> SegmentMaxBackward<T>
+where
+    T: Clone + DTComp + Debug,
+{
+    #[track_caller]
+    pub fn new(id: usize, edge_list: Vec<Edge<T>>, origin: &Rc<RefCell<TensorImpl<T>>>) -> Self {
+        let node = SegmentMaxBackward {
+            input_refs: vec![],
+            input_shapes: vec![],
+            name: BackwardType::SegmentMaxBackward,
+            id,
+            edge_list,
+            origin: Some(Rc::downgrade(origin)),
+            location: Location::caller(),
+            indices: None,
+        };
+
+        return node;
+    }
+
+    /// Saves the shape of the input, the gradient of every segment goes to the row holding its
+    /// maximum in zeros of this shape
+    pub fn save_input_shapes(&mut self, input_shapes: Vec<Vec<usize>>) {
+        self.input_shapes.extend(input_shapes);
+    }
+
+    pub fn save_indices(&mut self, indices: Rc<Tensor<usize>>) {
+        self.indices = Some(indices);
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    #[allow(unused)]
+    use super::*;
+    use crate::ops::public_ops::segment::segment_max;
+    use crate::utils::testing_utils::total_test_for_backward_operation;
+
+    #[test]
+    fn segment_max_backward_operation() {
+        let x1 = Tensor::new(vec![1, 8, 3, 4, 5, 2, 7, 6], vec![4, 2], true).as_float_32();
+        let segment_ids = Tensor::new(vec![1, 0, 1, 1], vec![4], false);
+
+        let z = segment_max(&x1, &segment_ids, 3);
+
+        total_test_for_backward_operation(
+            vec![&x1],
+            vec![Tensor::new(vec![0, 1, 1, 1, 0, 0, 1, 0], vec![4, 2], false).as_float_32()],
+            &z,
+            "SegmentMaxBackward",
+            Tensor::new(vec![3, 4, 7, 8, 0, 0], vec![3, 2], false).as_float_32(),
+        );
+    }
+}
@@ -0,0 +1,221 @@
+use ndarray::Axis;
+use num_traits::{NumCast, Zero};
+
+use super::DTComp;
+use super::Tensor;
+
+use crate::graph::backward::Backward;
+use crate::graph::backward::backward_types::BackwardType;
+use crate::graph::backward::backward_utils::apply_node;
+use crate::graph::edge::Edge;
+use crate::ops::compute::indexing_compute::compute_index_select;
+use crate::ops::compute::scatter_compute::segment_counts;
+use crate::tensor_core::tensor_impl::TensorImpl;
+
+use std::cell::RefCell;
+use std::fmt::Debug;
+use std::ops::{Add, Div};
+use std::panic::Location;
+use std::rc::{Rc, Weak};
+
+#[derive(Debug)]
+pub struct SegmentMeanBackward<T>
+where
+    T: DTComp + Clone + Debug,
+{
+    input_refs: Vec<Rc<RefCell<TensorImpl<T>>>>,
+    input_shapes: Vec<Vec<usize>>,
+    name: BackwardType,
+    id: usize,
+    edge_list: Vec<Edge<T>>,
+    origin: Option<Weak<RefCell<TensorImpl<T>>>>,
+    segment_ids: Option<Rc<Tensor<usize>>>,
+    num_segments: usize,
+    location: &'static Location<'static>,
+}
+
+impl<T> Backward<T> for SegmentMeanBackward<T>
+where
+    T: Clone + DTComp + Debug + 'static + Add<Output = T> + Zero + Div<Output = T> + NumCast,
+{
+    fn save_grad_to_origin_tensor(&self, grad: &Rc<Tensor<T>>) {
+        if let Some(origin_as_option_ref) = self.origin.as_ref() {
+            if let Some(origin_as_strong_rc) = origin_as_option_ref.upgrade() {
+                if let Some(origin_ref) = origin_as_strong_rc.borrow().get_autograd_ref_().as_ref()
+                {
+                    origin_ref.accumulate_grad(grad);
+                }
+            }
+        } else {
+            panic!(
+                "Dangling graph node, no origin tensor found at node: {} with id: {}, created at {}",
+                self.get_name(),
+                self.get_id(),
+                self.get_location(),
+            );
+        }
+    }
+
+    fn apply(&self, upstream_gradient: Rc<Tensor<T>>, retain_graph: bool) {
+        if retain_graph {
+            self.save_grad_to_origin_tensor(&upstream_gradient);
+        }
+
+        for edge in self.get_edge_list().iter() {
+            let next_grad = self.calculate_gradient_for_next_node(&upstream_gradient, Some(&edge));
+
+            let next_node = edge.get_next_grad_fn();
+            apply_node(&next_node, next_grad, retain_graph);
+        }
+    }
+
+    fn calculate_gradient_for_next_node(
+        &self,
+        upstream_gradient: &Rc<Tensor<T>>,
+        edge: Option<&Edge<T>>,
+    ) -> Rc<Tensor<T>> {
+        if let Some(_) = edge {
+            if let Some(segment_ids) = self.segment_ids.as_ref() {
+                let segment_ids_vec = segment_ids
+                    .get_raw_data()
+                    .iter()
+                    .copied()
+                    .collect::<Vec<usize>>();
+                let counts = segment_counts(&segment_ids_vec, self.num_segments);
+
+                // every row receives the gradient of its segment, divided by the length of the segment
+                let grad_output = compute_index_select(upstream_gradient, Axis(0), segment_ids);
+                let mut grad_output = grad_output.get_raw_data().to_owned();
+
+                for (mut row, segment_id) in grad_output
+                    .axis_iter_mut(Axis(0))
+                    .zip(segment_ids_vec.iter())
+                {
+                    let count = T::from(counts[*segment_id]).expect(
+                        "Error: Could not convert segment length to scalar type for segment mean backward",
+                    );
+                    row.mapv_inplace(|x| x / count.clone());
+                }
+
+                let result_tensor = Tensor::from_raw_array(grad_output, false);
+                return Rc::new(result_tensor);
+            } else {
+                panic!(
+                    "Error, trying to calculate gradient of a segment mean function without any segment ids set"
+                );
+            }
+        } else {
+            panic!(
+                "Error, no edge found to connect to and calculate gradient because segment mean requires a tensor to operate"
+            );
+        }
+    }
+
+    fn get_edge_list(&self) -> &[Edge<T>] {
+        return &self.edge_list;
+    }
+
+    fn add_to_edge_list(&mut self, edge: Edge<T>) {
+        self.edge_list.push(edge);
+    }
+
+    fn save_input_refs(&mut self, input_refs: Vec<Rc<RefCell<TensorImpl<T>>>>) {
+        self.input_refs.extend(input_refs);
+    }
+
+    fn get_input_refs(&self) -> &[Rc<RefCell<TensorImpl<T>>>] {
+        return &self.input_refs;
+    }
+
+    fn clear_input_refs(&mut self) {
+        self.input_refs.clear();
+    }
+
+    fn get_id(&self) -> usize {
+        return self.id;
+    }
+
+    fn get_name(&self) -> String {
+        return self.name.to_string();
+    }
+
+    fn get_location(&self) -> &'static Location<'static> {
+        return self.location;
+    }
+
+    fn get_origin(&self) -> Option<Rc<RefCell<TensorImpl<T>>>> {
+        match &self.origin {
+            Some(origin_weak) => {
+                let origin_rc = origin_weak.upgrade();
+                return origin_rc;
+            }
+            None => {
+                panic!("no origin found on this tensor");
+            }
+        }
+    }
+}
+
+impl<T> SegmentMeanBackward<T>
+where
+    T: Clone + DTComp + Debug,
+{
+    #[track_caller]
+    pub fn new(id: usize, edge_list: Vec<Edge<T>>, origin: &Rc<RefCell<TensorImpl<T>>>) -> Self {
+        let node = SegmentMeanBackward {
+            input_refs: vec![],
+            input_shapes: vec![],
+            name: BackwardType::SegmentMeanBackward,
+            id,
+            edge_list,
+            origin: Some(Rc::downgrade(origin)),
+            location: Location::caller(),
+            segment_ids: None,
+            num_segments: 0,
+        };
+
+        return node;
+    }
+
+    pub fn save_input_shapes(&mut self, input_shapes: Vec<Vec<usize>>) {
+        self.input_shapes.extend(input_shapes);
+    }
+
+    pub fn save_segment_ids(&mut self, segment_ids: Rc<Tensor<usize>>, num_segments: usize) {
+        self.segment_ids = Some(segment_ids);
+        self.num_segments = num_segments;
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    #[allow(unused)]
+    use super::*;
+    use crate::ops::public_ops::segment::segment_mean;
+    use crate::utils::testing_utils::total_test_for_backward_operation;
+
+    #[test]
+    fn segment_mean_backward_operation() {
+        let x1 = Tensor::new(vec![1, 2, 3, 4, 5, 6, 7, 8], vec![4, 2], true).as_float_32();
+        let segment_ids = Tensor::new(vec![1, 0, 1, 1], vec![4], false);
+
+        let z = segment_mean(&x1, &segment_ids, 3);
+        let third = 1.0_f32 / 3.0;
+
+        total_test_for_backward_operation(
+            vec![&x1],
+            vec![Tensor::new(
+                vec![third, third, 1.0, 1.0, third, third, third, third],
+                vec![4, 2],
+                false,
+            )],
+            &z,
+            "SegmentMeanBackward",
+            Tensor::new(
+                vec![3.0, 4.0, 13.0 / 3.0, 16.0 / 3.0, 0.0, 0.0],
+                vec![3, 2],
+                false,
+            ),
+        );
+    }
+}
@@ -3,16 +3,17 @@ use super::Tensor;
 
 use crate::graph::backward::Backward;
 use crate::graph::backward::backward_types::BackwardType;
-use crate::graph::backward::backward_utils::gradient_from_broadcast;
+use crate::graph::backward::backward_utils::{apply_node, gradient_from_broadcast};
 use crate::graph::edge::Edge;
-use crate::ops::compute::add_compute::add_compute_tensor_tensor;
 use crate::ops::compute::neg_compute::neg_compute_tensor;
 use crate::tensor_core::tensor_impl::TensorImpl;
 
-use num_traits::Signed;
 use std::cell::RefCell;
 use std::fmt::Debug;
+use std::ops::Add;
 use std::ops::Deref;
+use std::ops::Neg;
+use std::panic::Location;
 use std::rc::{Rc, Weak};
 
 #[derive(Debug)]
@@ -21,36 +22,32 @@ where
     T: DTComp + Clone + Debug + 'static,
 {
     input_refs: Vec<Rc<RefCell<TensorImpl<T>>>>,
+    input_shapes: Vec<Vec<usize>>,
     name: BackwardType,
     id: usize,
     edge_list: Vec<Edge<T>>,
     origin: Option<Weak<RefCell<TensorImpl<T>>>>,
+    location: &'static Location<'static>,
 }
 
 impl<T> Backward<T> for SubBackward<T>
 where
-    T: Clone + DTComp + Debug + 'static + Signed,
+    T: Clone + DTComp + Debug + 'static + Add<Output = T> + Neg<Output = T>,
 {
     fn save_grad_to_origin_tensor(&self, grad: &Rc<Tensor<T>>) {
         if let Some(origin_as_option_ref) = self.origin.as_ref() {
             if let Some(origin_as_strong_rc) = origin_as_option_ref.upgrade() {
                 if let Some(origin_ref) = origin_as_strong_rc.borrow().get_autograd_ref_().as_ref()
                 {
-                    if origin_ref.grad_is_set() {
-                        let old_grad = origin_ref.get_grad_as_tensor();
-                        let new_grad = add_compute_tensor_tensor(old_grad.deref(), grad.deref());
-
-                        origin_ref.set_grad(Rc::new(new_grad));
-                    } else {
-                        origin_ref.set_grad(Rc::clone(grad));
-                    }
+                    origin_ref.accumulate_grad(grad);
                 }
             }
         } else {
             panic!(
-                "Dangling graph node, no origin tensor found at node: {} with id: {}",
+                "Dangling graph node, no origin tensor found at node: {} with id: {}, created at {}",
                 self.get_name(),
                 self.get_id(),
+                self.get_location(),
             );
         }
     }
@@ -64,7 +61,7 @@ where
             let grad = self.calculate_gradient_for_next_node(&upstream_gradient, Some(edge));
 
             let node = edge.get_next_grad_fn();
-            node.borrow().apply(grad, retain_graph);
+            apply_node(&node, grad, retain_graph);
         }
     }
 
@@ -79,14 +76,14 @@ where
             if edge_nr == 0 {
                 return Rc::new(gradient_from_broadcast(
                     upstream_gradient.deref(),
-                    &self.input_refs[0].borrow().get_raw_shape(),
+                    &self.input_shapes[0],
                 ));
             } else {
                 let subtrahend_grad = neg_compute_tensor(upstream_gradient.deref());
 
                 return Rc::new(gradient_from_broadcast(
                     &subtrahend_grad,
-                    &self.input_refs[1].borrow().get_raw_shape(),
+                    &self.input_shapes[1],
                 ));
             }
         } else {
@@ -106,6 +103,14 @@ where
         self.input_refs.extend(input_refs);
     }
 
+    fn get_input_refs(&self) -> &[Rc<RefCell<TensorImpl<T>>>] {
+        return &self.input_refs;
+    }
+
+    fn clear_input_refs(&mut self) {
+        self.input_refs.clear();
+    }
+
     fn get_id(&self) -> usize {
         return self.id;
     }
@@ -114,6 +119,10 @@ where
         return self.name.to_string();
     }
 
+    fn get_location(&self) -> &'static Location<'static> {
+        return self.location;
+    }
+
     fn get_origin(&self) -> Option<Rc<RefCell<TensorImpl<T>>>> {
         match &self.origin {
             Some(origin_weak) => {
@@ -131,17 +140,26 @@ impl<T> SubBackward<T>
 where
     T: Clone + DTComp + Debug,
 {
+    #[track_caller]
     pub fn new(id: usize, edge_list: Vec<Edge<T>>, origin: &Rc<RefCell<TensorImpl<T>>>) -> Self {
         let node = SubBackward {
             input_refs: vec![],
+            input_shapes: vec![],
             name: BackwardType::SubBackward,
             id,
             edge_list,
             origin: Some(Rc::downgrade(origin)),
+            location: Location::caller(),
         };
 
         return node;
     }
+
+    /// Saves the input shapes, the gradient of either side is summed back down to them over
+    /// broadcast dims
+    pub fn save_input_shapes(&mut self, input_shapes: Vec<Vec<usize>>) {
+        self.input_shapes.extend(input_shapes);
+    }
 }
 
 #[cfg(test)]
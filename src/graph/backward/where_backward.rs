@@ -0,0 +1,200 @@
+use num_traits::Zero;
+
+use super::DTComp;
+use super::Tensor;
+
+use crate::graph::backward::Backward;
+use crate::graph::backward::backward_types::BackwardType;
+use crate::graph::backward::backward_utils::{apply_node, gradient_from_broadcast};
+use crate::graph::edge::Edge;
+use crate::ops::compute::logical_compute::compute_keep_by_mask;
+use crate::tensor_core::tensor_impl::TensorImpl;
+
+use std::cell::RefCell;
+use std::fmt::Debug;
+use std::ops::Add;
+use std::panic::Location;
+use std::rc::{Rc, Weak};
+
+#[derive(Debug)]
+pub struct WhereBackward<T>
+where
+    T: DTComp + Clone + Debug,
+{
+    input_refs: Vec<Rc<RefCell<TensorImpl<T>>>>,
+    input_shapes: Vec<Vec<usize>>,
+    name: BackwardType,
+    id: usize,
+    edge_list: Vec<Edge<T>>,
+    origin: Option<Weak<RefCell<TensorImpl<T>>>>,
+    condition: Option<Rc<Tensor<bool>>>,
+    location: &'static Location<'static>,
+}
+
+impl<T> Backward<T> for WhereBackward<T>
+where
+    T: Clone + DTComp + Debug + 'static + Add<Output = T> + Zero,
+{
+    fn save_grad_to_origin_tensor(&self, grad: &Rc<Tensor<T>>) {
+        if let Some(origin_as_option_ref) = self.origin.as_ref() {
+            if let Some(origin_as_strong_rc) = origin_as_option_ref.upgrade() {
+                if let Some(origin_ref) = origin_as_strong_rc.borrow().get_autograd_ref_().as_ref()
+                {
+                    origin_ref.accumulate_grad(grad);
+                }
+            }
+        } else {
+            panic!(
+                "Dangling graph node, no origin tensor found at node: {} with id: {}, created at {}",
+                self.get_name(),
+                self.get_id(),
+                self.get_location(),
+            );
+        }
+    }
+
+    fn apply(&self, upstream_gradient: Rc<Tensor<T>>, retain_graph: bool) {
+        if retain_graph {
+            self.save_grad_to_origin_tensor(&upstream_gradient);
+        }
+
+        for edge in self.get_edge_list().iter() {
+            let next_grad = self.calculate_gradient_for_next_node(&upstream_gradient, Some(&edge));
+
+            let next_node = edge.get_next_grad_fn();
+            apply_node(&next_node, next_grad, retain_graph);
+        }
+    }
+
+    fn calculate_gradient_for_next_node(
+        &self,
+        upstream_gradient: &Rc<Tensor<T>>,
+        edge: Option<&Edge<T>>,
+    ) -> Rc<Tensor<T>> {
+        if let Some(edge) = edge {
+            if let Some(condition) = self.condition.as_ref() {
+                let edge_nr = edge.input_nr;
+
+                // the first operand was taken where the condition holds and the second elsewhere
+                let tensor = compute_keep_by_mask(upstream_gradient, condition, edge_nr == 0);
+
+                return Rc::new(gradient_from_broadcast(
+                    &tensor,
+                    &self.input_shapes[edge_nr],
+                ));
+            } else {
+                panic!(
+                    "Error, trying to calculate gradient of a where function without any condition set"
+                );
+            }
+        } else {
+            panic!(
+                "Error, no edge found to connect to and calculate gradient because where requires two tensors to operate"
+            );
+        }
+    }
+
+    fn get_edge_list(&self) -> &[Edge<T>] {
+        return &self.edge_list;
+    }
+
+    fn add_to_edge_list(&mut self, edge: Edge<T>) {
+        self.edge_list.push(edge);
+    }
+
+    fn save_input_refs(&mut self, input_refs: Vec<Rc<RefCell<TensorImpl<T>>>>) {
+        self.input_refs.extend(input_refs);
+    }
+
+    fn get_input_refs(&self) -> &[Rc<RefCell<TensorImpl<T>>>] {
+        return &self.input_refs;
+    }
+
+    fn clear_input_refs(&mut self) {
+        self.input_refs.clear();
+    }
+
+    fn get_id(&self) -> usize {
+        return self.id;
+    }
+
+    fn get_name(&self) -> String {
+        return self.name.to_string();
+    }
+
+    fn get_location(&self) -> &'static Location<'static> {
+        return self.location;
+    }
+
+    fn get_origin(&self) -> Option<Rc<RefCell<TensorImpl<T>>>> {
+        match &self.origin {
+            Some(origin_weak) => {
+                let origin_rc = origin_weak.upgrade();
+                return origin_rc;
+            }
+            None => {
+                panic!("no origin found on this tensor");
+            }
+        }
+    }
+}
+
+impl<T> WhereBackward<T>
+where
+    T: Clone + DTComp + Debug,
+{
+    #[track_caller]
+    pub fn new(id: usize, edge_list: Vec<Edge<T>>, origin: &Rc<RefCell<TensorImpl<T>>>) -> Self {
+        let node = WhereBackward {
+            input_refs: vec![],
+            input_shapes: vec![],
+            name: BackwardType::WhereBackward,
+            id,
+            edge_list,
+            origin: Some(Rc::downgrade(origin)),
+            location: Location::caller(),
+            condition: None,
+        };
+
+        return node;
+    }
+
+    /// Saves the shapes of both branches, the gradient masked by the condition is summed back down
+    /// to them
+    pub fn save_input_shapes(&mut self, input_shapes: Vec<Vec<usize>>) {
+        self.input_shapes.extend(input_shapes);
+    }
+
+    /// Saves the condition broadcast to the shape of the output
+    pub fn save_condition(&mut self, condition: Rc<Tensor<bool>>) {
+        self.condition = Some(condition);
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    #[allow(unused)]
+    use super::*;
+    use crate::ops::public_ops::where_::where_;
+    use crate::utils::testing_utils::total_test_for_backward_operation;
+
+    #[test]
+    fn where_backward_operation() {
+        let x1 = Tensor::new(vec![1, 2, 3, 4], vec![2, 2], true).as_float_32();
+        let x2 = Tensor::new(vec![10, 20], vec![2], true).as_float_32();
+        let condition = Tensor::new(vec![true, false, true, true], vec![2, 2], false);
+
+        let z = where_(&condition, &x1, &x2);
+
+        total_test_for_backward_operation(
+            vec![&x1, &x2],
+            vec![
+                Tensor::new(vec![1, 0, 1, 1], vec![2, 2], false).as_float_32(),
+                Tensor::new(vec![0, 1], vec![2], false).as_float_32(),
+            ],
+            &z,
+            "WhereBackward",
+            Tensor::new(vec![1, 20, 3, 4], vec![2, 2], false).as_float_32(),
+        );
+    }
+}
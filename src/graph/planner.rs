@@ -0,0 +1,2 @@
+pub mod backward_plan;
+pub mod liveness;
@@ -0,0 +1,200 @@
+use crate::graph::backward::Backward;
+use crate::graph::backward::backward_utils::with_node_panic_context;
+use crate::graph::planner::liveness::GraphLiveness;
+use crate::ops::compute::add_compute::add_compute_tensor_tensor;
+use crate::profiler::memory_profiler;
+use crate::profiler::timing_profiler::{self, SpanCategory};
+use crate::tensor_core::dtypes::DTComp;
+use crate::tensor_core::tensor::Tensor;
+
+use std::cell::RefCell;
+use std::fmt::{self, Debug, Display};
+use std::ops::Add;
+use std::rc::Rc;
+
+/// Predicted memory of a backward sweep, in bytes
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MemoryReport {
+    pub saved_bytes: usize,
+    pub gradient_bytes: usize,
+    pub planned_peak_bytes: usize,
+    pub unplanned_peak_bytes: usize,
+}
+
+impl Display for MemoryReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Backward memory report:")?;
+        writeln!(f, "  Saved tensors: {} bytes", self.saved_bytes)?;
+        writeln!(f, "  Gradient buffers: {} bytes", self.gradient_bytes)?;
+        writeln!(f, "  Planned peak: {} bytes", self.planned_peak_bytes)?;
+        write!(f, "  Unplanned peak: {} bytes", self.unplanned_peak_bytes)
+    }
+}
+
+/// Execution plan for the backward pass of a tensor. Every node is executed exactly once in
+/// topological order, gradients arriving at a node are accumulated into a single buffer, and
+/// gradient buffers and saved tensors are released as soon as they have been used.
+#[derive(Debug)]
+pub struct BackwardPlan<T>
+where
+    T: DTComp + Debug,
+{
+    liveness: GraphLiveness<T>,
+}
+
+impl<T> BackwardPlan<T>
+where
+    T: DTComp + Debug + Clone + Add<Output = T> + 'static,
+{
+    pub fn new(tensor: &Tensor<T>) -> Self {
+        if !tensor.does_require_grad() {
+            panic!(
+                "Error: Cannot plan backward for a tensor that does not have gradient tracking enabled."
+            );
+        }
+
+        let root: Rc<RefCell<dyn Backward<T>>> = match tensor.is_leaf() {
+            true => tensor.get_grad_accum(),
+            false => tensor.get_grad_fn(),
+        };
+
+        let plan = BackwardPlan {
+            liveness: GraphLiveness::new(root),
+        };
+
+        return plan;
+    }
+
+    pub fn get_liveness(&self) -> &GraphLiveness<T> {
+        return &self.liveness;
+    }
+
+    pub fn memory_report(&self) -> MemoryReport {
+        let report = MemoryReport {
+            saved_bytes: self.liveness.get_saved_bytes(),
+            gradient_bytes: self.liveness.get_grad_bytes().iter().sum(),
+            planned_peak_bytes: self.liveness.planned_peak_bytes(),
+            unplanned_peak_bytes: self.liveness.unplanned_peak_bytes(),
+        };
+
+        return report;
+    }
+
+    /// Run the planned backward sweep. Unless `retain_graph` is set, the tensors saved by every
+    /// node are dropped once the node has run, so the graph cannot be backpropagated again.
+    pub fn execute(&self, starting_gradient: Tensor<T>, retain_graph: bool) {
+        let nodes = self.liveness.get_nodes();
+
+        let mut pending_grads: Vec<Option<Rc<Tensor<T>>>> = vec![None; nodes.len()];
+        pending_grads[0] = Some(Rc::new(starting_gradient));
+
+        for (step, node) in nodes.iter().enumerate() {
+            let upstream_gradient = pending_grads[step].take().expect(
+                "Error: Backward plan reached a node before all of its incoming gradients were computed",
+            );
+
+            {
+                let node_ref = node.borrow();
+                let _span = timing_profiler::span(
+                    SpanCategory::Backward,
+                    &node_ref.get_name(),
+                    &[upstream_gradient.as_ref()],
+                );
+
+                with_node_panic_context(&*node_ref, || {
+                    memory_profiler::enter_node(&*node_ref);
+
+                    if node_ref.get_edge_list().is_empty() {
+                        node_ref.apply(upstream_gradient, retain_graph);
+                    } else {
+                        if retain_graph {
+                            node_ref.save_grad_to_origin_tensor(&upstream_gradient);
+                        }
+
+                        let next_grads =
+                            node_ref.calculate_gradients_for_all_edges(&upstream_gradient);
+                        drop(upstream_gradient);
+
+                        let next_indices = self.liveness.next_node_indices(step);
+                        for (next_index, next_grad) in next_indices.into_iter().zip(next_grads) {
+                            let accumulated = match pending_grads[next_index].take() {
+                                Some(existing) => {
+                                    Rc::new(add_compute_tensor_tensor(&existing, &next_grad))
+                                }
+                                None => next_grad,
+                            };
+
+                            pending_grads[next_index] = Some(accumulated);
+                        }
+                    }
+
+                    memory_profiler::exit_node();
+                });
+            }
+
+            if !retain_graph {
+                node.borrow_mut().clear_input_refs();
+            }
+        }
+    }
+}
+
+impl<T> Tensor<T>
+where
+    T: DTComp + Debug + Clone + Add<Output = T> + 'static,
+{
+    /// Analyse the graph behind this tensor and build a memory efficient backward plan
+    pub fn plan_backward(&self) -> BackwardPlan<T> {
+        return BackwardPlan::new(self);
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    #[allow(unused)]
+    use super::*;
+    use crate::utils::testing_utils::test_for_correct_gradient;
+
+    #[test]
+    fn planned_backward_matches_recursive_backward() {
+        let x1 = Tensor::new(vec![1, 2, 3, 4], vec![4, 1], true).as_float_32();
+        let x2 = Tensor::new(vec![0.5, 0.25, 0.1, 0.2], vec![4, 1], true).as_float_32();
+
+        // a is used twice, so the plan has to accumulate gradients reaching mul
+        let a = &x1 * &x2;
+        let z = &a.exp() + &a;
+
+        let plan = z.plan_backward();
+        let report = plan.memory_report();
+
+        assert!(report.planned_peak_bytes <= report.unplanned_peak_bytes);
+        assert_eq!(plan.get_liveness().get_nodes().len(), 5);
+
+        plan.execute(Tensor::ones_like(&z, None), false);
+
+        // dz/dx1 = (exp(x1 * x2) + 1) * x2, dz/dx2 = (exp(x1 * x2) + 1) * x1
+        test_for_correct_gradient(
+            vec![&x1, &x2],
+            vec![
+                Tensor::new(vec![1.3244, 0.6622, 0.2350, 0.6451], vec![4, 1], false).as_float_32(),
+                Tensor::new(vec![2.6487, 5.2974, 7.0496, 12.9021], vec![4, 1], false).as_float_32(),
+            ],
+            1e-4,
+        );
+
+        for node in plan.get_liveness().get_nodes().iter() {
+            assert!(node.borrow().get_input_refs().is_empty());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "in node MulBackward")]
+    fn planned_backward_panics_name_the_node() {
+        let x = Tensor::new(vec![1.0_f32, 2.0], vec![2], true);
+        let w = Tensor::new(vec![3.0_f32, 4.0], vec![2], false);
+        let z = &x * &w;
+
+        z.plan_backward()
+            .execute(Tensor::new(vec![1.0_f32, 1.0, 1.0], vec![3], false), false);
+    }
+}
@@ -0,0 +1,250 @@
+use crate::graph::backward::Backward;
+use crate::graph::backward::backward_types::BackwardType;
+use crate::tensor_core::dtypes::DTComp;
+use crate::tensor_core::tensor_impl::TensorImpl;
+
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Debug;
+use std::ops::Add;
+use std::rc::Rc;
+
+pub fn node_key<T>(node: &Rc<RefCell<dyn Backward<T>>>) -> *const ()
+where
+    T: DTComp + Debug,
+{
+    return Rc::as_ptr(node) as *const ();
+}
+
+/// The backward graph of a tensor flattened into execution order, together with the lifetime of
+/// every buffer held during the backward sweep. Step `i` of the sweep executes node `i`.
+#[derive(Debug)]
+pub struct GraphLiveness<T>
+where
+    T: DTComp + Debug,
+{
+    nodes: Vec<Rc<RefCell<dyn Backward<T>>>>,
+    node_index: HashMap<*const (), usize>,
+    grad_bytes: Vec<usize>,
+    grad_first_write: Vec<usize>,
+    saved_bytes: usize,
+    saved_released_at: Vec<usize>,
+}
+
+impl<T> GraphLiveness<T>
+where
+    T: DTComp + Debug + Clone + Add<Output = T> + 'static,
+{
+    pub fn new(root: Rc<RefCell<dyn Backward<T>>>) -> Self {
+        let nodes = GraphLiveness::topological_order(root);
+
+        let mut node_index = HashMap::new();
+        for (i, node) in nodes.iter().enumerate() {
+            node_index.insert(node_key(node), i);
+        }
+
+        let mut liveness = GraphLiveness {
+            grad_bytes: vec![0; nodes.len()],
+            grad_first_write: vec![0; nodes.len()],
+            saved_bytes: 0,
+            saved_released_at: vec![0; nodes.len()],
+            nodes,
+            node_index,
+        };
+
+        liveness.compute_gradient_liveness();
+        liveness.compute_saved_liveness();
+
+        return liveness;
+    }
+
+    /// Order nodes so that every node comes after all of the nodes sending gradient to it. The root
+    /// is always the first node.
+    fn topological_order(root: Rc<RefCell<dyn Backward<T>>>) -> Vec<Rc<RefCell<dyn Backward<T>>>> {
+        let mut visited = HashSet::new();
+        let mut postorder = vec![];
+        let mut stack = vec![(root, false)];
+
+        while let Some((node, expanded)) = stack.pop() {
+            if expanded {
+                postorder.push(node);
+                continue;
+            }
+
+            if !visited.insert(node_key(&node)) {
+                continue;
+            }
+
+            stack.push((Rc::clone(&node), true));
+
+            for edge in node.borrow().get_edge_list().iter() {
+                let next_node = edge.get_next_grad_fn();
+                if !visited.contains(&node_key(&next_node)) {
+                    stack.push((next_node, false));
+                }
+            }
+        }
+
+        postorder.reverse();
+
+        return postorder;
+    }
+
+    /// Size every gradient buffer and find the step it is first written at. A buffer is sized from
+    /// the origin tensor of the node receiving it. When that tensor has already been dropped, the
+    /// gradient of the sending node is used instead, which is exact for shape only operations.
+    fn compute_gradient_liveness(&mut self) {
+        let mut sized = vec![false; self.nodes.len()];
+
+        self.grad_bytes[0] = self.origin_bytes(0).unwrap_or(0);
+        sized[0] = true;
+
+        for i in 0..self.nodes.len() {
+            let next_indices = self.next_node_indices(i);
+
+            for j in next_indices {
+                if sized[j] {
+                    continue;
+                }
+
+                self.grad_bytes[j] = self.origin_bytes(j).unwrap_or(self.grad_bytes[i]);
+                self.grad_first_write[j] = i;
+                sized[j] = true;
+            }
+        }
+    }
+
+    /// A saved tensor is released after the last node saving it has been executed
+    fn compute_saved_liveness(&mut self) {
+        let mut last_use: HashMap<*const RefCell<TensorImpl<T>>, (usize, usize)> = HashMap::new();
+
+        for (i, node) in self.nodes.iter().enumerate() {
+            for input_ref in node.borrow().get_input_refs().iter() {
+                let nbytes = input_ref.borrow().numel * std::mem::size_of::<T>();
+                last_use.insert(Rc::as_ptr(input_ref), (nbytes, i));
+            }
+        }
+
+        for (nbytes, step) in last_use.into_values() {
+            self.saved_bytes += nbytes;
+            self.saved_released_at[step] += nbytes;
+        }
+    }
+
+    fn origin_bytes(&self, index: usize) -> Option<usize> {
+        return self.nodes[index]
+            .borrow()
+            .get_origin()
+            .map(|origin| origin.borrow().numel * std::mem::size_of::<T>());
+    }
+
+    pub fn next_node_indices(&self, index: usize) -> Vec<usize> {
+        return self.nodes[index]
+            .borrow()
+            .get_edge_list()
+            .iter()
+            .map(|edge| self.node_index[&node_key(&edge.get_next_grad_fn())])
+            .collect();
+    }
+
+    /// Gradients reaching a leaf are kept on the leaf tensor after the sweep
+    pub fn is_leaf_accumulator(&self, index: usize) -> bool {
+        return self.nodes[index].borrow().get_name() == BackwardType::GradAccum.to_string();
+    }
+
+    pub fn get_nodes(&self) -> &[Rc<RefCell<dyn Backward<T>>>] {
+        return &self.nodes;
+    }
+
+    pub fn get_node_index(&self, node: &Rc<RefCell<dyn Backward<T>>>) -> Option<usize> {
+        return self.node_index.get(&node_key(node)).copied();
+    }
+
+    pub fn get_grad_bytes(&self) -> &[usize] {
+        return &self.grad_bytes;
+    }
+
+    pub fn get_grad_first_write(&self) -> &[usize] {
+        return &self.grad_first_write;
+    }
+
+    pub fn get_saved_bytes(&self) -> usize {
+        return self.saved_bytes;
+    }
+
+    /// Peak bytes when every node runs once in topological order, incoming gradients are
+    /// accumulated into one buffer per node and every buffer is released right after its last use
+    pub fn planned_peak_bytes(&self) -> usize {
+        let mut written = vec![false; self.nodes.len()];
+        written[0] = true;
+
+        let mut live = self.saved_bytes + self.grad_bytes[0];
+        let mut peak = live;
+
+        for i in 0..self.nodes.len() {
+            for j in self.next_node_indices(i) {
+                // accumulating into an existing buffer needs room for the new gradient and the sum
+                let transient = match written[j] {
+                    true => 2 * self.grad_bytes[j],
+                    false => self.grad_bytes[j],
+                };
+                peak = peak.max(live + transient);
+
+                if !written[j] {
+                    live += self.grad_bytes[j];
+                    written[j] = true;
+                }
+            }
+
+            if !self.is_leaf_accumulator(i) {
+                live -= self.grad_bytes[i];
+            }
+
+            live -= self.saved_released_at[i];
+        }
+
+        return peak;
+    }
+
+    /// Peak bytes of the recursive sweep done by `backward()`, where saved tensors are held until
+    /// the graph is dropped and every gradient on the current path of the traversal stays alive
+    pub fn unplanned_peak_bytes(&self) -> usize {
+        let mut leaf_grad_set = vec![false; self.nodes.len()];
+        let mut live = self.saved_bytes + self.grad_bytes[0];
+        let mut peak = live;
+
+        self.simulate_recursive_apply(0, &mut live, &mut peak, &mut leaf_grad_set);
+
+        return peak;
+    }
+
+    /// Returns true when the incoming gradient is kept alive by the node after it returns
+    fn simulate_recursive_apply(
+        &self,
+        index: usize,
+        live: &mut usize,
+        peak: &mut usize,
+        leaf_grad_set: &mut [bool],
+    ) -> bool {
+        if self.is_leaf_accumulator(index) {
+            if !leaf_grad_set[index] {
+                leaf_grad_set[index] = true;
+                return true;
+            }
+
+            *peak = (*peak).max(*live + self.grad_bytes[index]);
+            return false;
+        }
+
+        for j in self.next_node_indices(index) {
+            *live += self.grad_bytes[j];
+            *peak = (*peak).max(*live);
+
+            if !self.simulate_recursive_apply(j, live, peak, leaf_grad_set) {
+                *live -= self.grad_bytes[j];
+            }
+        }
+
+        return false;
+    }
+}
@@ -26,14 +26,19 @@ pub fn serialize_tensor<T>(tensorimpl: Rc<RefCell<TensorImpl<T>>>) -> TensorJSON
 where
     T: DTComp + Debug + Clone,
 {
-    let (data, offset) = tensorimpl
+    // logical order, so views are exported the way they read and not the way they are stored
+    let data: Vec<T> = tensorimpl
         .borrow()
         .get_raw_data_()
-        .to_owned()
-        .into_raw_vec_and_offset();
+        .iter()
+        .cloned()
+        .collect();
+    let offset = Some(0);
     let shape = tensorimpl.borrow().get_raw_shape().to_vec();
+    let name = tensorimpl.borrow().get_name_();
 
     let tensor_json = TensorJSON {
+        name: name,
         data: data,
         offset: offset,
         shape: shape,
@@ -47,10 +52,12 @@ where
     T: DTComp + Debug + Clone,
 {
     let name = node.borrow().get_name();
+    let location = node.borrow().get_location().to_string();
 
     let origin_serialized = match node.borrow().get_origin() {
         Some(origin) => serialize_tensor(origin),
         None => TensorJSON {
+            name: None,
             data: vec![],
             offset: None,
             shape: vec![],
@@ -67,6 +74,7 @@ where
             serialize_tensor(grad.__clone_ptr_to_tensor_impl())
         }
         None => TensorJSON {
+            name: None,
             data: vec![],
             offset: None,
             shape: vec![],
@@ -75,6 +83,7 @@ where
 
     let node_serialized = NodeJSON {
         name: name,
+        location: location,
         origin: origin_serialized,
         gradient: gradient_serialized,
         children: vec![],
@@ -109,6 +118,11 @@ pub fn export_single_node<T>(
     T: DTComp + Debug + Clone + 'static + Add<Output = T> + Serialize,
 {
     let name = node.borrow().get_name();
+    let location = node.borrow().get_location().to_string();
+    let origin_name = match node.borrow().get_origin() {
+        Some(origin) => origin.borrow().get_name_(),
+        None => None,
+    };
 
     let origin_serialized = match node.borrow().get_origin() {
         Some(origin) => {
@@ -156,6 +170,8 @@ pub fn export_single_node<T>(
 
     let node_serialized = NodeJSONAcyclic {
         name: name,
+        location: location,
+        origin_name: origin_name,
         origin: origin_serialized,
         gradient: gradient_serialized,
     };
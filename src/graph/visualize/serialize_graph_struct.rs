@@ -10,6 +10,7 @@ pub struct GraphJSON<T> {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct NodeJSON<T> {
     pub name: String,
+    pub location: String,
     pub origin: TensorJSON<T>,
     pub gradient: TensorJSON<T>,
     pub children: Vec<NodeJSON<T>>,
@@ -17,6 +18,7 @@ pub struct NodeJSON<T> {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TensorJSON<T> {
+    pub name: Option<String>,
     pub data: Vec<T>,
     pub offset: Option<usize>,
     pub shape: Vec<usize>,
@@ -25,6 +27,8 @@ pub struct TensorJSON<T> {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct NodeJSONAcyclic {
     pub name: String,
+    pub location: String,
+    pub origin_name: Option<String>,
     pub origin: String,
     pub gradient: String,
 }
@@ -58,20 +58,34 @@ where
 
         // Node label with color
         let label = format!("{}", borrowed);
+        let location = format!("@ {}", borrowed.get_location());
+
+        // name of the tensor this node produced, or of the leaf it accumulates into
+        let tensor_name = match borrowed.get_origin() {
+            Some(origin) => match origin.borrow().get_name_() {
+                Some(name) => format!("({}) ", name),
+                None => String::new(),
+            },
+            None => String::new(),
+        };
 
         if label == "GradAccum" {
             println!(
-                "{}{} {}",
+                "{}{} {}{} {}",
                 connector,
                 label.green(),
-                ("[ Gradient accumulation ]").yellow()
+                tensor_name.cyan(),
+                ("[ Gradient accumulation ]").yellow(),
+                location.dimmed()
             );
         } else {
             println!(
-                "{}{} {}",
+                "{}{} {}{} {}",
                 connector,
                 label.green(),
-                format!("[ {} child nodes ]", edge_count).blue()
+                tensor_name.cyan(),
+                format!("[ {} child nodes ]", edge_count).blue(),
+                location.dimmed()
             );
         }
 
@@ -0,0 +1,5 @@
+pub mod bytes;
+pub mod inflate;
+pub mod npy;
+pub mod safetensors;
+pub mod zip;
@@ -0,0 +1,230 @@
+use crate::tensor_core::{
+    dtypes::{DTComp, DTypes},
+    half::{BF16, F16},
+};
+use num_complex::Complex;
+
+use std::any::Any;
+
+// Little endian encoding of tensor elements, shared by the file formats. `isize` and `usize` are
+// always stored in 8 bytes, so files do not depend on the platform that wrote them.
+
+/// Number of bytes one element of `dtype` takes in a file
+pub fn element_size(dtype: DTypes) -> usize {
+    return match dtype {
+        DTypes::Bool | DTypes::Int8 | DTypes::Uint8 => 1,
+        DTypes::Float16 | DTypes::BFloat16 | DTypes::Int16 | DTypes::Uint16 => 2,
+        DTypes::Float32 | DTypes::Int32 | DTypes::Uint32 => 4,
+        DTypes::Float64 | DTypes::Int64 | DTypes::Uint64 => 8,
+        DTypes::Isize | DTypes::Usize | DTypes::Complex64 => 8,
+        DTypes::Int128 | DTypes::Uint128 | DTypes::Complex128 => 16,
+    };
+}
+
+macro_rules! encode_primitives {
+    ($value_list:expr, $($ty:ty),*) => {
+        $(
+            if T::dtype() == <$ty as DTComp>::dtype() {
+                return $value_list
+                    .iter()
+                    .flat_map(|value| as_concrete::<T, $ty>(value).to_le_bytes())
+                    .collect();
+            }
+        )*
+    };
+}
+
+macro_rules! decode_primitives {
+    ($bytes:expr, $($ty:ty),*) => {
+        $(
+            if T::dtype() == <$ty as DTComp>::dtype() {
+                let value_list: Vec<$ty> = $bytes
+                    .chunks_exact(std::mem::size_of::<$ty>())
+                    .map(|chunk| <$ty>::from_le_bytes(chunk.try_into().unwrap()))
+                    .collect();
+
+                return into_generic(value_list);
+            }
+        )*
+    };
+}
+
+/// Encodes elements as little endian bytes
+pub fn encode_le<T>(value_list: &[T]) -> Vec<u8>
+where
+    T: DTComp,
+{
+    encode_primitives!(
+        value_list, f32, f64, i8, i16, i32, i64, i128, u8, u16, u32, u64, u128
+    );
+
+    return match T::dtype() {
+        DTypes::Isize => value_list
+            .iter()
+            .flat_map(|value| (as_concrete::<T, isize>(value) as i64).to_le_bytes())
+            .collect(),
+        DTypes::Usize => value_list
+            .iter()
+            .flat_map(|value| (as_concrete::<T, usize>(value) as u64).to_le_bytes())
+            .collect(),
+        DTypes::Bool => value_list
+            .iter()
+            .map(|value| as_concrete::<T, bool>(value) as u8)
+            .collect(),
+        DTypes::Float16 => value_list
+            .iter()
+            .flat_map(|value| as_concrete::<T, F16>(value).to_bits().to_le_bytes())
+            .collect(),
+        DTypes::BFloat16 => value_list
+            .iter()
+            .flat_map(|value| as_concrete::<T, BF16>(value).to_bits().to_le_bytes())
+            .collect(),
+        DTypes::Complex64 => value_list
+            .iter()
+            .flat_map(|value| {
+                let value = as_concrete::<T, Complex<f32>>(value);
+                return [value.re.to_le_bytes(), value.im.to_le_bytes()].concat();
+            })
+            .collect(),
+        DTypes::Complex128 => value_list
+            .iter()
+            .flat_map(|value| {
+                let value = as_concrete::<T, Complex<f64>>(value);
+                return [value.re.to_le_bytes(), value.im.to_le_bytes()].concat();
+            })
+            .collect(),
+        dtype => panic!("Error: Cannot encode elements of dtype {}", dtype),
+    };
+}
+
+/// Decodes little endian bytes written by `encode_le`
+pub fn decode_le<T>(bytes: &[u8]) -> Vec<T>
+where
+    T: DTComp,
+{
+    let size = element_size(T::dtype());
+    if !bytes.len().is_multiple_of(size) {
+        panic!(
+            "Error: {} bytes do not hold a whole number of {} elements",
+            bytes.len(),
+            T::dtype()
+        );
+    }
+
+    decode_primitives!(
+        bytes, f32, f64, i8, i16, i32, i64, i128, u8, u16, u32, u64, u128
+    );
+
+    let chunk_list = bytes.chunks_exact(size);
+
+    return match T::dtype() {
+        DTypes::Isize => into_generic(
+            chunk_list
+                .map(|chunk| i64::from_le_bytes(chunk.try_into().unwrap()) as isize)
+                .collect::<Vec<_>>(),
+        ),
+        DTypes::Usize => into_generic(
+            chunk_list
+                .map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap()) as usize)
+                .collect::<Vec<_>>(),
+        ),
+        DTypes::Bool => into_generic(
+            chunk_list
+                .map(|chunk| match chunk[0] {
+                    0 => false,
+                    1 => true,
+                    byte => panic!("Error: {} is not a valid bool byte", byte),
+                })
+                .collect::<Vec<_>>(),
+        ),
+        DTypes::Float16 => into_generic(
+            chunk_list
+                .map(|chunk| F16::from_bits(u16::from_le_bytes(chunk.try_into().unwrap())))
+                .collect::<Vec<_>>(),
+        ),
+        DTypes::BFloat16 => into_generic(
+            chunk_list
+                .map(|chunk| BF16::from_bits(u16::from_le_bytes(chunk.try_into().unwrap())))
+                .collect::<Vec<_>>(),
+        ),
+        DTypes::Complex64 => into_generic(
+            chunk_list
+                .map(|chunk| {
+                    let re = f32::from_le_bytes(chunk[..4].try_into().unwrap());
+                    let im = f32::from_le_bytes(chunk[4..].try_into().unwrap());
+                    return Complex::new(re, im);
+                })
+                .collect::<Vec<_>>(),
+        ),
+        DTypes::Complex128 => into_generic(
+            chunk_list
+                .map(|chunk| {
+                    let re = f64::from_le_bytes(chunk[..8].try_into().unwrap());
+                    let im = f64::from_le_bytes(chunk[8..].try_into().unwrap());
+                    return Complex::new(re, im);
+                })
+                .collect::<Vec<_>>(),
+        ),
+        dtype => panic!("Error: Cannot decode elements of dtype {}", dtype),
+    };
+}
+
+fn as_concrete<T, U>(value: &T) -> U
+where
+    T: DTComp,
+    U: DTComp + Copy,
+{
+    return *(value as &dyn Any)
+        .downcast_ref::<U>()
+        .expect("Error: Internal error, encoded elements have the wrong dtype");
+}
+
+fn into_generic<T, U>(value_list: Vec<U>) -> Vec<T>
+where
+    T: DTComp,
+    U: DTComp,
+{
+    return *(Box::new(value_list) as Box<dyn Any>)
+        .downcast::<Vec<T>>()
+        .expect("Error: Internal error, decoded elements have the wrong dtype");
+}
+
+#[cfg(test)]
+pub mod test {
+    #[allow(unused)]
+    use super::*;
+
+    #[test]
+    fn bytes_round_trip_for_every_dtype() {
+        fn round_trip<T>(value_list: Vec<T>)
+        where
+            T: DTComp + PartialEq + std::fmt::Debug,
+        {
+            let bytes = encode_le(&value_list);
+            assert_eq!(bytes.len(), value_list.len() * element_size(T::dtype()));
+            assert_eq!(decode_le::<T>(&bytes), value_list);
+        }
+
+        round_trip(vec![true, false]);
+        round_trip(vec![F16::from_f32(1.5), F16::from_f32(-2.0)]);
+        round_trip(vec![BF16::from_f32(1.5), BF16::from_f32(-2.0)]);
+        round_trip(vec![1.5f32, -2.0]);
+        round_trip(vec![1.5f64, -2.0]);
+        round_trip(vec![-1i8, 2]);
+        round_trip(vec![-1i16, 2]);
+        round_trip(vec![-1i32, 2]);
+        round_trip(vec![-1i64, 2]);
+        round_trip(vec![-1i128, 2]);
+        round_trip(vec![-1isize, 2]);
+        round_trip(vec![1u8, 2]);
+        round_trip(vec![1u16, 2]);
+        round_trip(vec![1u32, 2]);
+        round_trip(vec![1u64, 2]);
+        round_trip(vec![1u128, 2]);
+        round_trip(vec![1usize, 2]);
+        round_trip(vec![Complex::new(1.0f32, -2.0)]);
+        round_trip(vec![Complex::new(1.0f64, -2.0)]);
+
+        assert_eq!(encode_le(&vec![1u16]), vec![1, 0]);
+    }
+}
@@ -0,0 +1,319 @@
+// Decoder for raw DEFLATE streams (RFC 1951), which is all the zip reader needs. It favors
+// simplicity over speed, the same way zlib's puff does: Huffman codes are decoded one bit at a
+// time from canonical code lengths.
+
+const MAX_BITS: usize = 15;
+
+/// Base lengths and extra bits of the length symbols 257 to 285
+const LENGTH_BASE: [u16; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131,
+    163, 195, 227, 258,
+];
+const LENGTH_EXTRA: [u8; 29] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
+];
+
+/// Base distances and extra bits of the distance symbols 0 to 29
+const DISTANCE_BASE: [u16; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537,
+    2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+];
+const DISTANCE_EXTRA: [u8; 30] = [
+    0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13,
+    13,
+];
+
+/// Order in which the code length code lengths of a dynamic block are stored
+const CODE_LENGTH_ORDER: [usize; 19] = [
+    16, 17, 18, 0, 8, 7, 9, 6, 10, 5, 11, 4, 12, 3, 13, 2, 14, 1, 15,
+];
+
+struct BitReader<'a> {
+    input: &'a [u8],
+    position: usize,
+    bit_buffer: u32,
+    bit_count: u32,
+}
+
+impl<'a> BitReader<'a> {
+    fn new(input: &'a [u8]) -> Self {
+        return BitReader {
+            input,
+            position: 0,
+            bit_buffer: 0,
+            bit_count: 0,
+        };
+    }
+
+    /// Next `count` bits, least significant bit first
+    fn bits(&mut self, count: u32) -> Result<u32, String> {
+        while self.bit_count < count {
+            let byte = match self.input.get(self.position) {
+                Some(byte) => *byte,
+                None => return Err(String::from("deflate stream ends early")),
+            };
+
+            self.bit_buffer |= (byte as u32) << self.bit_count;
+            self.position += 1;
+            self.bit_count += 8;
+        }
+
+        let value = self.bit_buffer & ((1u64 << count) - 1) as u32;
+        self.bit_buffer >>= count;
+        self.bit_count -= count;
+
+        return Ok(value);
+    }
+
+    /// Drops the bits left in the current byte
+    fn align_to_byte(&mut self) {
+        self.bit_buffer = 0;
+        self.bit_count = 0;
+    }
+}
+
+/// Canonical Huffman code, stored as the number of codes of every length and the symbols sorted
+/// by code
+struct Huffman {
+    count_list: [u16; MAX_BITS + 1],
+    symbol_list: Vec<u16>,
+}
+
+impl Huffman {
+    fn new(length_list: &[u8]) -> Result<Self, String> {
+        let mut count_list = [0u16; MAX_BITS + 1];
+        for length in length_list.iter() {
+            count_list[*length as usize] += 1;
+        }
+
+        // more codes of some length than the shorter codes leave room for
+        let mut left: i32 = 1;
+        for count in count_list.iter().skip(1) {
+            left = (left << 1) - *count as i32;
+            if left < 0 {
+                return Err(String::from(
+                    "deflate stream has an oversubscribed Huffman code",
+                ));
+            }
+        }
+
+        let mut offset_list = [0u16; MAX_BITS + 1];
+        for length in 1..MAX_BITS {
+            offset_list[length + 1] = offset_list[length] + count_list[length];
+        }
+
+        let mut symbol_list = vec![0u16; length_list.len()];
+        for (symbol, length) in length_list.iter().enumerate() {
+            if *length != 0 {
+                symbol_list[offset_list[*length as usize] as usize] = symbol as u16;
+                offset_list[*length as usize] += 1;
+            }
+        }
+
+        return Ok(Huffman {
+            count_list,
+            symbol_list,
+        });
+    }
+
+    fn decode(&self, reader: &mut BitReader) -> Result<u16, String> {
+        let mut code: i32 = 0;
+        let mut first: i32 = 0;
+        let mut index: i32 = 0;
+
+        for length in 1..=MAX_BITS {
+            code |= reader.bits(1)? as i32;
+            let count = self.count_list[length] as i32;
+
+            if code - count < first {
+                return Ok(self.symbol_list[(index + (code - first)) as usize]);
+            }
+
+            index += count;
+            first = (first + count) << 1;
+            code <<= 1;
+        }
+
+        return Err(String::from("deflate stream has an invalid Huffman code"));
+    }
+}
+
+/// Decompresses a raw DEFLATE stream, without zlib or gzip framing
+pub fn inflate(input: &[u8]) -> Result<Vec<u8>, String> {
+    let mut reader = BitReader::new(input);
+    let mut output: Vec<u8> = Vec::new();
+
+    loop {
+        let is_last = reader.bits(1)? == 1;
+
+        match reader.bits(2)? {
+            0 => inflate_stored(&mut reader, &mut output)?,
+            1 => {
+                let (length_code, distance_code) = fixed_codes()?;
+                inflate_codes(&mut reader, &mut output, &length_code, &distance_code)?;
+            }
+            2 => {
+                let (length_code, distance_code) = dynamic_codes(&mut reader)?;
+                inflate_codes(&mut reader, &mut output, &length_code, &distance_code)?;
+            }
+            _ => return Err(String::from("deflate stream has an invalid block type")),
+        }
+
+        if is_last {
+            return Ok(output);
+        }
+    }
+}
+
+fn inflate_stored(reader: &mut BitReader, output: &mut Vec<u8>) -> Result<(), String> {
+    reader.align_to_byte();
+
+    let header = match reader.input.get(reader.position..reader.position + 4) {
+        Some(header) => header,
+        None => return Err(String::from("deflate stream ends early")),
+    };
+    let length = u16::from_le_bytes([header[0], header[1]]);
+    let length_complement = u16::from_le_bytes([header[2], header[3]]);
+    if length != !length_complement {
+        return Err(String::from("deflate stored block has a corrupt length"));
+    }
+
+    let start = reader.position + 4;
+    let data = match reader.input.get(start..start + length as usize) {
+        Some(data) => data,
+        None => return Err(String::from("deflate stream ends early")),
+    };
+
+    output.extend_from_slice(data);
+    reader.position = start + length as usize;
+
+    return Ok(());
+}
+
+fn fixed_codes() -> Result<(Huffman, Huffman), String> {
+    let mut length_list = [0u8; 288];
+    length_list[..144].fill(8);
+    length_list[144..256].fill(9);
+    length_list[256..280].fill(7);
+    length_list[280..].fill(8);
+
+    return Ok((Huffman::new(&length_list)?, Huffman::new(&[5u8; 30])?));
+}
+
+fn dynamic_codes(reader: &mut BitReader) -> Result<(Huffman, Huffman), String> {
+    let length_count = reader.bits(5)? as usize + 257;
+    let distance_count = reader.bits(5)? as usize + 1;
+    let code_length_count = reader.bits(4)? as usize + 4;
+
+    let mut code_length_list = [0u8; 19];
+    for index in CODE_LENGTH_ORDER.iter().take(code_length_count) {
+        code_length_list[*index] = reader.bits(3)? as u8;
+    }
+    let code_length_code = Huffman::new(&code_length_list)?;
+
+    // literal/length and distance code lengths share one run length encoded sequence
+    let mut length_list: Vec<u8> = Vec::with_capacity(length_count + distance_count);
+    while length_list.len() < length_count + distance_count {
+        let symbol = code_length_code.decode(reader)?;
+
+        let (value, repeat) = match symbol {
+            0..=15 => (symbol as u8, 1),
+            16 => match length_list.last() {
+                Some(previous) => (*previous, 3 + reader.bits(2)?),
+                None => return Err(String::from("deflate stream repeats a missing length")),
+            },
+            17 => (0, 3 + reader.bits(3)?),
+            _ => (0, 11 + reader.bits(7)?),
+        };
+
+        if length_list.len() + repeat as usize > length_count + distance_count {
+            return Err(String::from("deflate stream has too many code lengths"));
+        }
+        length_list.extend(std::iter::repeat_n(value, repeat as usize));
+    }
+
+    if length_list[256] == 0 {
+        return Err(String::from("deflate stream has no end of block code"));
+    }
+
+    return Ok((
+        Huffman::new(&length_list[..length_count])?,
+        Huffman::new(&length_list[length_count..])?,
+    ));
+}
+
+fn inflate_codes(
+    reader: &mut BitReader,
+    output: &mut Vec<u8>,
+    length_code: &Huffman,
+    distance_code: &Huffman,
+) -> Result<(), String> {
+    loop {
+        let symbol = length_code.decode(reader)? as usize;
+
+        if symbol < 256 {
+            output.push(symbol as u8);
+            continue;
+        }
+        if symbol == 256 {
+            return Ok(());
+        }
+
+        let length_index = symbol - 257;
+        if length_index >= LENGTH_BASE.len() {
+            return Err(String::from("deflate stream has an invalid length symbol"));
+        }
+        let length = LENGTH_BASE[length_index] as usize
+            + reader.bits(LENGTH_EXTRA[length_index] as u32)? as usize;
+
+        let distance_index = distance_code.decode(reader)? as usize;
+        if distance_index >= DISTANCE_BASE.len() {
+            return Err(String::from(
+                "deflate stream has an invalid distance symbol",
+            ));
+        }
+        let distance = DISTANCE_BASE[distance_index] as usize
+            + reader.bits(DISTANCE_EXTRA[distance_index] as u32)? as usize;
+
+        if distance > output.len() {
+            return Err(String::from("deflate stream refers back past its start"));
+        }
+
+        // the copy may overlap the bytes it produces, so it goes one byte at a time
+        let start = output.len() - distance;
+        for offset in 0..length {
+            output.push(output[start + offset]);
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    #[allow(unused)]
+    use super::*;
+
+    #[test]
+    fn inflate_decodes_every_block_type() {
+        // stored block holding "abc"
+        let stored = [0x01, 0x03, 0x00, 0xfc, 0xff, b'a', b'b', b'c'];
+        assert_eq!(inflate(&stored).unwrap(), b"abc");
+
+        // zlib.compressobj(9, zlib.DEFLATED, -15) of b"hello hello hello", a fixed Huffman block
+        let fixed = [0xcb, 0x48, 0xcd, 0xc9, 0xc9, 0x57, 0xc8, 0x40, 0x90, 0x00];
+        assert_eq!(inflate(&fixed).unwrap(), b"hello hello hello");
+
+        // the same for a skewed alphabet repeated three times, a dynamic Huffman block
+        let dynamic = [
+            0xad, 0xca, 0x41, 0x11, 0x00, 0x00, 0x08, 0x02, 0xc1, 0xac, 0xc7, 0xd9, 0x3f, 0x83,
+            0xd8, 0x41, 0x5e, 0xec, 0x00, 0x19, 0x05, 0x62, 0x12, 0xda, 0xc2, 0x9c, 0xc4, 0xd2,
+            0xf2, 0xd2, 0xe9, 0xf9, 0xb7,
+        ];
+        assert_eq!(
+            inflate(&dynamic).unwrap(),
+            b"abdccaaabcbbbacaabadaabccacbaccabaaaaabb".repeat(3)
+        );
+
+        assert!(inflate(&[0x07]).is_err());
+        assert!(inflate(&fixed[..4]).is_err());
+    }
+}
@@ -0,0 +1,498 @@
+use crate::io::bytes::{decode_le, element_size, encode_le};
+use crate::io::zip::{read_zip, write_stored_zip};
+use crate::tensor_core::{
+    dtypes::{DTComp, DTypes},
+    tensor::Tensor,
+};
+use ndarray::{ArrayViewD, IxDyn, ShapeBuilder};
+
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Display},
+};
+
+// A `.npy` file is the magic string, a format version, a header length and a header holding a
+// Python dict literal with the dtype, memory order and shape, padded so the data is 64 byte
+// aligned. The data follows in the byte order and memory order given by the header. A `.npz`
+// file is a zip archive of `.npy` files.
+
+const MAGIC: &[u8] = b"\x93NUMPY";
+
+/// Errors from reading or writing `.npy` and `.npz` files
+#[derive(Debug)]
+pub enum NpyError {
+    Io(std::io::Error),
+    /// The file is not a valid `.npy` or `.npz` file
+    Format(String),
+    /// The file stores a dtype that differs from the requested one
+    DtypeMismatch {
+        expected: String,
+        found: String,
+    },
+    /// The dtype has no NumPy equivalent
+    UnsupportedDtype(DTypes),
+}
+
+impl Display for NpyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return match self {
+            NpyError::Io(e) => write!(f, "Error: {}", e),
+            NpyError::Format(message) => write!(f, "Error: Invalid npy data, {}", message),
+            NpyError::DtypeMismatch { expected, found } => write!(
+                f,
+                "Error: Array is stored as {} but was requested as {}",
+                found, expected
+            ),
+            NpyError::UnsupportedDtype(dtype) => {
+                write!(f, "Error: {} has no NumPy equivalent", dtype)
+            }
+        };
+    }
+}
+
+impl std::error::Error for NpyError {}
+
+impl From<std::io::Error> for NpyError {
+    fn from(e: std::io::Error) -> Self {
+        return NpyError::Io(e);
+    }
+}
+
+/// NumPy type code of `dtype`, without the byte order. `isize` and `usize` map to the 8 byte
+/// integers.
+fn dtype_to_descr(dtype: DTypes) -> Result<&'static str, NpyError> {
+    return match dtype {
+        DTypes::Bool => Ok("b1"),
+        DTypes::Float16 => Ok("f2"),
+        DTypes::Float32 => Ok("f4"),
+        DTypes::Float64 => Ok("f8"),
+        DTypes::Int8 => Ok("i1"),
+        DTypes::Int16 => Ok("i2"),
+        DTypes::Int32 => Ok("i4"),
+        DTypes::Int64 | DTypes::Isize => Ok("i8"),
+        DTypes::Uint8 => Ok("u1"),
+        DTypes::Uint16 => Ok("u2"),
+        DTypes::Uint32 => Ok("u4"),
+        DTypes::Uint64 | DTypes::Usize => Ok("u8"),
+        DTypes::Complex64 => Ok("c8"),
+        DTypes::Complex128 => Ok("c16"),
+        DTypes::BFloat16 | DTypes::Int128 | DTypes::Uint128 => {
+            Err(NpyError::UnsupportedDtype(dtype))
+        }
+    };
+}
+
+struct NpyHeader {
+    descr: String,
+    fortran_order: bool,
+    shape: Vec<usize>,
+}
+
+/// Value of `key` in the header dict, up to the next top level comma or closing brace
+fn header_value<'a>(header: &'a str, key: &str) -> Result<&'a str, NpyError> {
+    let key_pattern_list = [format!("'{}'", key), format!("\"{}\"", key)];
+    let key_end = key_pattern_list
+        .iter()
+        .find_map(|pattern| {
+            header
+                .find(pattern.as_str())
+                .map(|start| start + pattern.len())
+        })
+        .ok_or_else(|| NpyError::Format(format!("header has no {} key", key)))?;
+
+    let rest = header[key_end..].trim_start();
+    let rest = match rest.strip_prefix(':') {
+        Some(rest) => rest.trim_start(),
+        None => return Err(NpyError::Format(format!("header key {} has no value", key))),
+    };
+
+    let mut depth = 0;
+    for (index, character) in rest.char_indices() {
+        match character {
+            '(' | '[' => depth += 1,
+            ')' | ']' => depth -= 1,
+            ',' | '}' if depth == 0 => return Ok(rest[..index].trim()),
+            _ => {}
+        }
+    }
+
+    return Err(NpyError::Format(format!(
+        "header value of {} is not closed",
+        key
+    )));
+}
+
+fn parse_header(header: &str) -> Result<NpyHeader, NpyError> {
+    let descr = header_value(header, "descr")?
+        .trim_matches(|character| character == '\'' || character == '"')
+        .to_string();
+
+    let fortran_order = match header_value(header, "fortran_order")? {
+        "True" => true,
+        "False" => false,
+        value => {
+            return Err(NpyError::Format(format!(
+                "fortran_order is {}, expected True or False",
+                value
+            )));
+        }
+    };
+
+    let shape_value = header_value(header, "shape")?;
+    let shape_inner = shape_value
+        .strip_prefix('(')
+        .and_then(|shape| shape.strip_suffix(')'))
+        .ok_or_else(|| NpyError::Format(format!("shape {} is not a tuple", shape_value)))?;
+
+    let shape = shape_inner
+        .split(',')
+        .map(|dim| dim.trim())
+        .filter(|dim| !dim.is_empty())
+        .map(|dim| {
+            // Python 2 era files write dimensions as longs, like `3L`
+            return dim
+                .trim_end_matches('L')
+                .parse::<usize>()
+                .map_err(|_| NpyError::Format(format!("shape {} is not a tuple", shape_value)));
+        })
+        .collect::<Result<Vec<usize>, NpyError>>()?;
+
+    return Ok(NpyHeader {
+        descr,
+        fortran_order,
+        shape,
+    });
+}
+
+/// Reads a tensor from the bytes of a `.npy` file
+pub fn read_npy_bytes<T>(bytes: &[u8]) -> Result<Tensor<T>, NpyError>
+where
+    T: DTComp + Debug + Clone,
+{
+    if !bytes.starts_with(MAGIC) || bytes.len() < 10 {
+        return Err(NpyError::Format(String::from(
+            "missing the NUMPY magic string",
+        )));
+    }
+
+    let (header_length, header_start) = match bytes[6] {
+        1 => (u16::from_le_bytes([bytes[8], bytes[9]]) as usize, 10),
+        2 | 3 => match bytes.get(8..12) {
+            Some(length) => (u32::from_le_bytes(length.try_into().unwrap()) as usize, 12),
+            None => return Err(NpyError::Format(String::from("header length is cut off"))),
+        },
+        version => {
+            return Err(NpyError::Format(format!(
+                "format version {} is not supported",
+                version
+            )));
+        }
+    };
+
+    let header_bytes = bytes
+        .get(header_start..header_start + header_length)
+        .ok_or_else(|| NpyError::Format(String::from("header is cut off")))?;
+    let header = parse_header(&String::from_utf8_lossy(header_bytes))?;
+
+    // the byte order is `|` for single byte types and `=` for native order, little endian here
+    let (byte_order, type_code) = match header.descr.chars().next() {
+        Some(order @ ('<' | '>' | '|' | '=')) => (order, &header.descr[1..]),
+        _ => ('<', header.descr.as_str()),
+    };
+
+    let expected = dtype_to_descr(T::dtype())?;
+    if type_code != expected {
+        return Err(NpyError::DtypeMismatch {
+            expected: String::from(expected),
+            found: header.descr.clone(),
+        });
+    }
+
+    let size = element_size(T::dtype());
+    let nbytes = header
+        .shape
+        .iter()
+        .try_fold(size, |nbytes, dim| nbytes.checked_mul(*dim))
+        .ok_or_else(|| {
+            NpyError::Format(format!(
+                "shape {:?} is too large to fit in memory",
+                header.shape
+            ))
+        })?;
+    let data_start = header_start + header_length;
+
+    let data = bytes
+        .get(data_start..)
+        .filter(|data| data.len() == nbytes)
+        .ok_or_else(|| {
+            NpyError::Format(format!(
+                "shape {:?} needs {} bytes of data but the file has {}",
+                header.shape,
+                nbytes,
+                bytes.len().saturating_sub(data_start)
+            ))
+        })?;
+
+    if T::dtype() == DTypes::Bool {
+        if let Some(byte) = data.iter().find(|byte| **byte > 1) {
+            return Err(NpyError::Format(format!(
+                "{} is not a valid bool byte",
+                byte
+            )));
+        }
+    }
+
+    let value_list: Vec<T> = if byte_order == '>' {
+        // complex numbers swap each of their two parts
+        let part_size = if matches!(T::dtype(), DTypes::Complex64 | DTypes::Complex128) {
+            size / 2
+        } else {
+            size
+        };
+
+        let swapped: Vec<u8> = data
+            .chunks_exact(part_size)
+            .flat_map(|part| part.iter().rev().copied())
+            .collect();
+        decode_le(&swapped)
+    } else {
+        decode_le(data)
+    };
+
+    if !header.fortran_order {
+        return Ok(Tensor::new(value_list, header.shape, false));
+    }
+
+    let fortran_view = ArrayViewD::from_shape(IxDyn(&header.shape).f(), &value_list)
+        .map_err(|e| NpyError::Format(e.to_string()))?;
+    let c_order_list: Vec<T> = fortran_view.iter().cloned().collect();
+
+    return Ok(Tensor::new(c_order_list, header.shape, false));
+}
+
+/// Bytes of a little endian, C order `.npy` file holding `tensor`
+pub fn write_npy_bytes<T>(tensor: &Tensor<T>) -> Result<Vec<u8>, NpyError>
+where
+    T: DTComp + Debug + Clone,
+{
+    if tensor.is_meta() {
+        return Err(NpyError::Format(String::from(
+            "meta tensors have no data to save",
+        )));
+    }
+
+    let type_code = dtype_to_descr(T::dtype())?;
+    let byte_order = if element_size(T::dtype()) == 1 {
+        '|'
+    } else {
+        '<'
+    };
+
+    let shape = tensor.get_shape().clone();
+    let shape_str = match shape.len() {
+        1 => format!("({},)", shape[0]),
+        _ => format!(
+            "({})",
+            shape
+                .iter()
+                .map(|dim| dim.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    };
+
+    let mut header = format!(
+        "{{'descr': '{}{}', 'fortran_order': False, 'shape': {}, }}",
+        byte_order, type_code, shape_str
+    );
+
+    // version 1 has a 2 byte header length, longer headers need version 2
+    let version: u8 = if header.len() + 1 + 10 > u16::MAX as usize {
+        2
+    } else {
+        1
+    };
+    let prefix_length = if version == 1 { 10 } else { 12 };
+
+    let padding = (64 - (prefix_length + header.len() + 1) % 64) % 64;
+    header.push_str(&" ".repeat(padding));
+    header.push('\n');
+
+    let mut bytes = MAGIC.to_vec();
+    bytes.extend_from_slice(&[version, 0]);
+    if version == 1 {
+        bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+    } else {
+        bytes.extend_from_slice(&(header.len() as u32).to_le_bytes());
+    }
+    bytes.extend_from_slice(header.as_bytes());
+
+    let value_list: Vec<T> = tensor.get_raw_data().iter().cloned().collect();
+    bytes.extend_from_slice(&encode_le(&value_list));
+
+    return Ok(bytes);
+}
+
+impl<T> Tensor<T>
+where
+    T: DTComp + Debug + Clone,
+{
+    /// Reads a `.npy` file in either byte order and either memory order. The result is a leaf that
+    /// does not require gradients.
+    pub fn from_npy(path: &str) -> Result<Tensor<T>, NpyError> {
+        let bytes = std::fs::read(path)?;
+        return read_npy_bytes(&bytes);
+    }
+
+    /// Writes the tensor as a little endian, C order `.npy` file
+    pub fn to_npy(&self, path: &str) -> Result<(), NpyError> {
+        let bytes = write_npy_bytes(self)?;
+        std::fs::write(path, bytes)?;
+
+        return Ok(());
+    }
+}
+
+/// Writes every tensor as `{name}.npy` in an uncompressed `.npz` archive, like `numpy.savez`
+pub fn save_npz<T>(path: &str, tensor_list: &[(&str, &Tensor<T>)]) -> Result<(), NpyError>
+where
+    T: DTComp + Debug + Clone,
+{
+    let mut entry_list: Vec<(String, Vec<u8>)> = Vec::with_capacity(tensor_list.len());
+
+    for (name, tensor) in tensor_list.iter() {
+        let file_name = format!("{}.npy", name);
+        if entry_list
+            .iter()
+            .any(|(entry_name, _)| *entry_name == file_name)
+        {
+            return Err(NpyError::Format(format!(
+                "array name {} is used more than once",
+                name
+            )));
+        }
+
+        entry_list.push((file_name, write_npy_bytes(*tensor)?));
+    }
+
+    let archive = write_stored_zip(&entry_list).map_err(NpyError::Format)?;
+    std::fs::write(path, archive)?;
+
+    return Ok(());
+}
+
+/// Reads every array of a `.npz` archive, stored or deflated, keyed by name without `.npy`
+pub fn load_npz<T>(path: &str) -> Result<HashMap<String, Tensor<T>>, NpyError>
+where
+    T: DTComp + Debug + Clone,
+{
+    let archive = std::fs::read(path)?;
+
+    return read_zip(&archive)
+        .map_err(NpyError::Format)?
+        .into_iter()
+        .map(|(file_name, data)| {
+            let name = file_name
+                .strip_suffix(".npy")
+                .unwrap_or(&file_name)
+                .to_string();
+            return Ok((name, read_npy_bytes(&data)?));
+        })
+        .collect();
+}
+
+#[cfg(test)]
+pub mod test {
+    #[allow(unused)]
+    use super::*;
+
+    use crate::utils::testing_utils::raw_data_to_vec;
+    use num_complex::Complex;
+
+    fn npy_bytes(descr: &str, fortran_order: &str, shape: &str, data: &[u8]) -> Vec<u8> {
+        let header = format!(
+            "{{'descr': '{}', 'fortran_order': {}, 'shape': {}, }}\n",
+            descr, fortran_order, shape
+        );
+
+        let mut bytes = MAGIC.to_vec();
+        bytes.extend_from_slice(&[1, 0]);
+        bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(header.as_bytes());
+        bytes.extend_from_slice(data);
+
+        return bytes;
+    }
+
+    #[test]
+    fn npy_reads_byte_and_memory_orders() {
+        let tensor = Tensor::new(vec![1i32, 2, 3, 4, 5, 6], vec![2, 3], false);
+        let bytes = write_npy_bytes(&tensor).unwrap();
+        assert_eq!((bytes.len() - 24) % 64, 0);
+        assert_eq!(
+            raw_data_to_vec(&read_npy_bytes::<i32>(&bytes).unwrap()),
+            raw_data_to_vec(&tensor)
+        );
+
+        // [[1, 2, 3], [4, 5, 6]] stored column by column in big endian
+        let data: Vec<u8> = [1i32, 4, 2, 5, 3, 6]
+            .iter()
+            .flat_map(|value| value.to_be_bytes())
+            .collect();
+        let fortran = read_npy_bytes::<i32>(&npy_bytes(">i4", "True", "(2, 3)", &data)).unwrap();
+        assert_eq!(*fortran.get_shape(), vec![2, 3]);
+        assert_eq!(raw_data_to_vec(&fortran), vec![1, 2, 3, 4, 5, 6]);
+
+        let complex_data: Vec<u8> = [1.0f64, -2.0]
+            .iter()
+            .flat_map(|value| value.to_be_bytes())
+            .collect();
+        let complex =
+            read_npy_bytes::<Complex<f64>>(&npy_bytes(">c16", "False", "()", &complex_data))
+                .unwrap();
+        assert_eq!(raw_data_to_vec(&complex), vec![Complex::new(1.0, -2.0)]);
+
+        match read_npy_bytes::<f32>(&bytes) {
+            Err(NpyError::DtypeMismatch { expected, found }) => {
+                assert_eq!((expected.as_str(), found.as_str()), ("f4", "<i4"));
+            }
+            _ => panic!("Error: Expected a dtype mismatch"),
+        }
+        assert!(matches!(
+            read_npy_bytes::<i32>(&npy_bytes("<i4", "False", "(3,)", &[0u8; 8])),
+            Err(NpyError::Format(_))
+        ));
+        assert!(matches!(
+            read_npy_bytes::<i32>(&npy_bytes("<i4", "False", "(4294967296, 4294967296)", &[])),
+            Err(NpyError::Format(_))
+        ));
+        assert!(matches!(
+            read_npy_bytes::<bool>(&npy_bytes("|b1", "False", "(2,)", &[1, 2])),
+            Err(NpyError::Format(_))
+        ));
+        assert!(matches!(
+            write_npy_bytes(&Tensor::new(vec![1u128], vec![1], false)),
+            Err(NpyError::UnsupportedDtype(DTypes::Uint128))
+        ));
+    }
+
+    #[test]
+    fn npz_round_trip() {
+        let path = std::env::temp_dir().join("grad_engine_round_trip.npz");
+        let path = path.to_str().unwrap();
+
+        let x = Tensor::new(vec![1.0f32, 2.0, 3.0], vec![3], false);
+        let y = Tensor::new(vec![4.0f32, 5.0, 6.0, 7.0], vec![2, 2], false).transpose(None);
+        save_npz(path, &[("x", &x), ("y", &y)]).unwrap();
+
+        let loaded = load_npz::<f32>(path).unwrap();
+        assert_eq!(raw_data_to_vec(&loaded["x"]), vec![1.0, 2.0, 3.0]);
+        assert_eq!(raw_data_to_vec(&loaded["y"]), vec![4.0, 6.0, 5.0, 7.0]);
+
+        assert!(matches!(
+            load_npz::<f64>(path),
+            Err(NpyError::DtypeMismatch { .. })
+        ));
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,447 @@
+use crate::io::bytes::{decode_le, element_size, encode_le};
+use crate::tensor_core::{
+    dtypes::{DTComp, DTypes},
+    tensor::Tensor,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Debug,
+    fs::File,
+    io::{BufWriter, Read, Seek, SeekFrom, Write},
+};
+
+// A safetensors file is an 8 byte little endian header length, a JSON header mapping every tensor
+// name to its dtype, shape and byte range, and then the raw little endian data of every tensor.
+// See https://github.com/huggingface/safetensors for the format.
+
+/// Headers larger than this are rejected, like the reference implementation does
+const MAX_HEADER_SIZE: u64 = 100_000_000;
+
+const METADATA_KEY: &str = "__metadata__";
+
+/// Entry of the header describing one tensor
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TensorInfo {
+    pub dtype: String,
+    pub shape: Vec<usize>,
+    pub data_offsets: [u64; 2],
+}
+
+/// Dtype code of the format. `I128`, `U128`, `C64` and `C128` are not part of the format, so files
+/// holding them can only be read back by this crate.
+pub fn dtype_to_code(dtype: DTypes) -> &'static str {
+    return match dtype {
+        DTypes::Bool => "BOOL",
+        DTypes::Float16 => "F16",
+        DTypes::BFloat16 => "BF16",
+        DTypes::Float32 => "F32",
+        DTypes::Float64 => "F64",
+        DTypes::Int8 => "I8",
+        DTypes::Int16 => "I16",
+        DTypes::Int32 => "I32",
+        DTypes::Int64 | DTypes::Isize => "I64",
+        DTypes::Int128 => "I128",
+        DTypes::Uint8 => "U8",
+        DTypes::Uint16 => "U16",
+        DTypes::Uint32 => "U32",
+        DTypes::Uint64 | DTypes::Usize => "U64",
+        DTypes::Uint128 => "U128",
+        DTypes::Complex64 => "C64",
+        DTypes::Complex128 => "C128",
+    };
+}
+
+fn code_to_element_size(code: &str) -> Option<usize> {
+    let dtype = match code {
+        "BOOL" => DTypes::Bool,
+        "F16" => DTypes::Float16,
+        "BF16" => DTypes::BFloat16,
+        "F32" => DTypes::Float32,
+        "F64" => DTypes::Float64,
+        "I8" => DTypes::Int8,
+        "I16" => DTypes::Int16,
+        "I32" => DTypes::Int32,
+        "I64" => DTypes::Int64,
+        "I128" => DTypes::Int128,
+        "U8" => DTypes::Uint8,
+        "U16" => DTypes::Uint16,
+        "U32" => DTypes::Uint32,
+        "U64" => DTypes::Uint64,
+        "U128" => DTypes::Uint128,
+        "C64" => DTypes::Complex64,
+        "C128" => DTypes::Complex128,
+        _ => return None,
+    };
+
+    return Some(element_size(dtype));
+}
+
+/// Writes `tensor_list` to `path` as a safetensors file, in the given order
+pub fn save_safetensors<T>(path: &str, tensor_list: &[(&str, &Tensor<T>)])
+where
+    T: DTComp + Debug + Clone,
+{
+    save_safetensors_with_metadata(path, tensor_list, &BTreeMap::new());
+}
+
+/// Same as `save_safetensors`, with free form string metadata stored in the header
+pub fn save_safetensors_with_metadata<T>(
+    path: &str,
+    tensor_list: &[(&str, &Tensor<T>)],
+    metadata: &BTreeMap<String, String>,
+) where
+    T: DTComp + Debug + Clone,
+{
+    let mut header = Map::new();
+    if !metadata.is_empty() {
+        header.insert(
+            String::from(METADATA_KEY),
+            serde_json::to_value(metadata).unwrap(),
+        );
+    }
+
+    let mut data_list: Vec<Vec<u8>> = Vec::with_capacity(tensor_list.len());
+    let mut offset: u64 = 0;
+
+    for (name, tensor) in tensor_list.iter() {
+        if *name == METADATA_KEY {
+            panic!(
+                "Error: {} is reserved and cannot name a tensor",
+                METADATA_KEY
+            );
+        }
+        if header.contains_key(*name) {
+            panic!("Error: Tensor name {} is used more than once", name);
+        }
+        if tensor.is_meta() {
+            panic!("Error: Meta tensor {} has no data to save", name);
+        }
+
+        // iterating in logical order also makes views and transposes contiguous
+        let value_list: Vec<T> = tensor.get_raw_data().iter().cloned().collect();
+        let data = encode_le(&value_list);
+
+        let info = TensorInfo {
+            dtype: String::from(dtype_to_code(T::dtype())),
+            shape: tensor.get_shape().clone(),
+            data_offsets: [offset, offset + data.len() as u64],
+        };
+        header.insert(String::from(*name), serde_json::to_value(info).unwrap());
+
+        offset += data.len() as u64;
+        data_list.push(data);
+    }
+
+    let mut header_bytes = serde_json::to_vec(&Value::Object(header)).unwrap();
+    // pad with spaces so the data starts 8 byte aligned
+    while !header_bytes.len().is_multiple_of(8) {
+        header_bytes.push(b' ');
+    }
+
+    let file =
+        File::create(path).unwrap_or_else(|e| panic!("Error: Could not create {}: {}", path, e));
+    let mut writer = BufWriter::new(file);
+
+    let write_result = writer
+        .write_all(&(header_bytes.len() as u64).to_le_bytes())
+        .and_then(|_| writer.write_all(&header_bytes))
+        .and_then(|_| {
+            for data in data_list.iter() {
+                writer.write_all(data)?;
+            }
+            return writer.flush();
+        });
+
+    if let Err(e) = write_result {
+        panic!("Error: Could not write {}: {}", path, e);
+    }
+}
+
+/// Reads every tensor of a safetensors file. Every tensor must be stored with the dtype of `T`,
+/// use `SafeTensorsReader` for files mixing dtypes.
+pub fn load_safetensors<T>(path: &str) -> HashMap<String, Tensor<T>>
+where
+    T: DTComp + Debug,
+{
+    let mut reader = SafeTensorsReader::open(path);
+
+    return reader
+        .get_names()
+        .into_iter()
+        .map(|name| {
+            let tensor = reader.load::<T>(&name);
+            return (name, tensor);
+        })
+        .collect();
+}
+
+/// Safetensors file with a parsed and validated header. Tensors are only read from disk when they
+/// are loaded, so a few tensors can be taken out of a large file.
+#[derive(Debug)]
+pub struct SafeTensorsReader {
+    path: String,
+    file: File,
+    data_start: u64,
+    tensor_info_map: BTreeMap<String, TensorInfo>,
+    metadata: BTreeMap<String, String>,
+}
+
+impl SafeTensorsReader {
+    pub fn open(path: &str) -> Self {
+        let mut file =
+            File::open(path).unwrap_or_else(|e| panic!("Error: Could not open {}: {}", path, e));
+        let file_size = file
+            .metadata()
+            .unwrap_or_else(|e| panic!("Error: Could not read the size of {}: {}", path, e))
+            .len();
+
+        let mut header_size_bytes = [0u8; 8];
+        if file.read_exact(&mut header_size_bytes).is_err() {
+            panic!(
+                "Error: {} is too short to hold a safetensors header length",
+                path
+            );
+        }
+
+        let header_size = u64::from_le_bytes(header_size_bytes);
+        if header_size > MAX_HEADER_SIZE {
+            panic!(
+                "Error: Header of {} claims {} bytes, more than the {} allowed",
+                path, header_size, MAX_HEADER_SIZE
+            );
+        }
+        if 8 + header_size > file_size {
+            panic!(
+                "Error: Header of {} claims {} bytes but the file only has {} after the length",
+                path,
+                header_size,
+                file_size - 8
+            );
+        }
+
+        let mut header_bytes = vec![0u8; header_size as usize];
+        file.read_exact(&mut header_bytes)
+            .unwrap_or_else(|e| panic!("Error: Could not read the header of {}: {}", path, e));
+
+        let header: Map<String, Value> = serde_json::from_slice(&header_bytes)
+            .unwrap_or_else(|e| panic!("Error: Header of {} is not a JSON object: {}", path, e));
+
+        let mut tensor_info_map = BTreeMap::new();
+        let mut metadata = BTreeMap::new();
+
+        for (name, value) in header.into_iter() {
+            if name == METADATA_KEY {
+                metadata = serde_json::from_value(value).unwrap_or_else(|e| {
+                    panic!("Error: Metadata of {} is not a map of strings: {}", path, e)
+                });
+                continue;
+            }
+
+            let info: TensorInfo = serde_json::from_value(value).unwrap_or_else(|e| {
+                panic!(
+                    "Error: Header entry of tensor {} in {} is invalid: {}",
+                    name, path, e
+                )
+            });
+            tensor_info_map.insert(name, info);
+        }
+
+        let data_start = 8 + header_size;
+        validate_offsets(path, &tensor_info_map, file_size - data_start);
+
+        return SafeTensorsReader {
+            path: String::from(path),
+            file,
+            data_start,
+            tensor_info_map,
+            metadata,
+        };
+    }
+
+    /// Names of the stored tensors, sorted
+    pub fn get_names(&self) -> Vec<String> {
+        return self.tensor_info_map.keys().cloned().collect();
+    }
+
+    pub fn get_info(&self, name: &str) -> Option<&TensorInfo> {
+        return self.tensor_info_map.get(name);
+    }
+
+    pub fn get_metadata(&self) -> &BTreeMap<String, String> {
+        return &self.metadata;
+    }
+
+    /// Reads the tensor called `name`, and only its bytes, from disk
+    pub fn load<T>(&mut self, name: &str) -> Tensor<T>
+    where
+        T: DTComp + Debug,
+    {
+        let info = match self.tensor_info_map.get(name) {
+            Some(info) => info,
+            None => panic!("Error: {} has no tensor named {}", self.path, name),
+        };
+
+        let expected_code = dtype_to_code(T::dtype());
+        if info.dtype != expected_code {
+            panic!(
+                "Error: Tensor {} in {} is stored as {}, which cannot be loaded as {}",
+                name,
+                self.path,
+                info.dtype,
+                T::dtype()
+            );
+        }
+
+        let [begin, end] = info.data_offsets;
+        let mut data = vec![0u8; (end - begin) as usize];
+
+        let read_result = self
+            .file
+            .seek(SeekFrom::Start(self.data_start + begin))
+            .and_then(|_| self.file.read_exact(&mut data));
+        if let Err(e) = read_result {
+            panic!(
+                "Error: Could not read tensor {} from {}: {}",
+                name, self.path, e
+            );
+        }
+
+        return Tensor::new(decode_le::<T>(&data), info.shape.clone(), false);
+    }
+}
+
+/// Checks that every byte range matches its dtype and shape, and that the ranges tile the data
+/// section without gaps or overlaps
+fn validate_offsets(path: &str, tensor_info_map: &BTreeMap<String, TensorInfo>, data_size: u64) {
+    let mut range_list: Vec<(&String, &TensorInfo)> = tensor_info_map.iter().collect();
+    range_list.sort_by_key(|(_, info)| info.data_offsets);
+
+    let mut expected_begin: u64 = 0;
+
+    for (name, info) in range_list.into_iter() {
+        let size = match code_to_element_size(&info.dtype) {
+            Some(size) => size as u64,
+            None => panic!(
+                "Error: Tensor {} in {} has unknown dtype {}",
+                name, path, info.dtype
+            ),
+        };
+
+        let [begin, end] = info.data_offsets;
+        if begin != expected_begin || end < begin {
+            panic!(
+                "Error: Tensor {} in {} has data offsets [{}, {}], expected it to start at {}",
+                name, path, begin, end, expected_begin
+            );
+        }
+
+        let numel = info
+            .shape
+            .iter()
+            .try_fold(1u64, |numel, dim| numel.checked_mul(*dim as u64));
+        let expected_size = numel.and_then(|numel| numel.checked_mul(size));
+        if expected_size != Some(end - begin) {
+            panic!(
+                "Error: Tensor {} in {} has shape {:?} and dtype {}, which do not fit in its {} bytes",
+                name,
+                path,
+                info.shape,
+                info.dtype,
+                end - begin
+            );
+        }
+
+        expected_begin = end;
+    }
+
+    if expected_begin != data_size {
+        panic!(
+            "Error: Tensors in {} cover {} bytes but the data section has {}",
+            path, expected_begin, data_size
+        );
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    #[allow(unused)]
+    use super::*;
+
+    fn temp_path(file_name: &str) -> String {
+        let path = std::env::temp_dir().join(format!("grad_engine_{}", file_name));
+        return path.to_string_lossy().into_owned();
+    }
+
+    #[test]
+    fn safetensors_round_trip() {
+        let path = temp_path("round_trip.safetensors");
+
+        let weight = Tensor::new(vec![1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0], vec![2, 3], true);
+        let bias = Tensor::new(vec![0.5f32, -0.5], vec![2], false);
+        let transposed = weight.transpose(None);
+
+        let mut metadata = BTreeMap::new();
+        metadata.insert(String::from("format"), String::from("pt"));
+        save_safetensors_with_metadata(
+            &path,
+            &[
+                ("weight", &weight),
+                ("bias", &bias),
+                ("weight_t", &transposed),
+            ],
+            &metadata,
+        );
+
+        let loaded = load_safetensors::<f32>(&path);
+        assert_eq!(loaded.len(), 3);
+        assert_eq!(*loaded["weight"].get_shape(), vec![2, 3]);
+        assert_eq!(*loaded["weight_t"].get_shape(), vec![3, 2]);
+        assert_eq!(
+            loaded["weight_t"]
+                .get_raw_data()
+                .iter()
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![1.0, 4.0, 2.0, 5.0, 3.0, 6.0]
+        );
+        assert!(!loaded["weight"].does_require_grad());
+
+        let mut reader = SafeTensorsReader::open(&path);
+        assert_eq!(reader.get_metadata()["format"], "pt");
+        assert_eq!(reader.get_info("bias").unwrap().data_offsets, [24, 32]);
+        assert_eq!(
+            reader
+                .load::<f32>("bias")
+                .get_raw_data()
+                .iter()
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![0.5, -0.5]
+        );
+
+        // the header starts 8 byte aligned data
+        let bytes = std::fs::read(&path).unwrap();
+        let header_size = u64::from_le_bytes(bytes[..8].try_into().unwrap());
+        assert_eq!(header_size % 8, 0);
+        assert_eq!(bytes.len() as u64, 8 + header_size + 56);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "which do not fit in its")]
+    fn safetensors_rejects_mismatched_shapes() {
+        let path = temp_path("bad_shape.safetensors");
+
+        let header = r#"{"x":{"dtype":"F32","shape":[3],"data_offsets":[0,8]}}"#;
+        let mut bytes = (header.len() as u64).to_le_bytes().to_vec();
+        bytes.extend_from_slice(header.as_bytes());
+        bytes.extend_from_slice(&[0u8; 8]);
+        std::fs::write(&path, bytes).unwrap();
+
+        SafeTensorsReader::open(&path);
+    }
+}
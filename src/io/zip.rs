@@ -0,0 +1,240 @@
+use crate::io::inflate::inflate;
+
+// Just enough of the zip format for `.npz` archives: the writer stores entries uncompressed, the
+// reader takes stored and deflated entries, including the zip64 fields NumPy writes. Multi disk
+// archives and encryption are not supported.
+
+const LOCAL_HEADER_SIGNATURE: u32 = 0x04034b50;
+const CENTRAL_HEADER_SIGNATURE: u32 = 0x02014b50;
+const END_OF_CENTRAL_DIRECTORY_SIGNATURE: u32 = 0x06054b50;
+const ZIP64_EXTRA_ID: u16 = 0x0001;
+
+const METHOD_STORED: u16 = 0;
+const METHOD_DEFLATED: u16 = 8;
+
+/// 1980-01-01, the earliest date the format can hold
+const DOS_DATE: u16 = 0x21;
+
+fn crc32_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+
+    for (index, entry) in table.iter_mut().enumerate() {
+        let mut crc = index as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                0xedb88320 ^ (crc >> 1)
+            } else {
+                crc >> 1
+            };
+        }
+        *entry = crc;
+    }
+
+    return table;
+}
+
+pub fn crc32(data: &[u8]) -> u32 {
+    let table = crc32_table();
+    let mut crc = 0xffffffffu32;
+
+    for byte in data.iter() {
+        crc = table[((crc ^ *byte as u32) & 0xff) as usize] ^ (crc >> 8);
+    }
+
+    return !crc;
+}
+
+/// Archive of uncompressed entries
+pub fn write_stored_zip(entry_list: &[(String, Vec<u8>)]) -> Result<Vec<u8>, String> {
+    let mut archive: Vec<u8> = Vec::new();
+    let mut central_directory: Vec<u8> = Vec::new();
+
+    for (name, data) in entry_list.iter() {
+        if data.len() >= u32::MAX as usize || archive.len() >= u32::MAX as usize {
+            return Err(format!(
+                "entry {} does not fit in an archive without zip64",
+                name
+            ));
+        }
+
+        let crc = crc32(data);
+        let local_header_offset = archive.len() as u32;
+
+        archive.extend_from_slice(&LOCAL_HEADER_SIGNATURE.to_le_bytes());
+        write_common_fields(&mut archive, name, data, crc);
+        archive.extend_from_slice(name.as_bytes());
+        archive.extend_from_slice(data);
+
+        central_directory.extend_from_slice(&CENTRAL_HEADER_SIGNATURE.to_le_bytes());
+        central_directory.extend_from_slice(&20u16.to_le_bytes());
+        write_common_fields(&mut central_directory, name, data, crc);
+        // comment length, disk number, internal and external attributes
+        central_directory.extend_from_slice(&[0u8; 10]);
+        central_directory.extend_from_slice(&local_header_offset.to_le_bytes());
+        central_directory.extend_from_slice(name.as_bytes());
+    }
+
+    let central_directory_offset = archive.len() as u32;
+    archive.extend_from_slice(&central_directory);
+
+    archive.extend_from_slice(&END_OF_CENTRAL_DIRECTORY_SIGNATURE.to_le_bytes());
+    archive.extend_from_slice(&[0u8; 4]);
+    archive.extend_from_slice(&(entry_list.len() as u16).to_le_bytes());
+    archive.extend_from_slice(&(entry_list.len() as u16).to_le_bytes());
+    archive.extend_from_slice(&(central_directory.len() as u32).to_le_bytes());
+    archive.extend_from_slice(&central_directory_offset.to_le_bytes());
+    archive.extend_from_slice(&0u16.to_le_bytes());
+
+    return Ok(archive);
+}
+
+/// Fields shared by local and central headers, from the version needed up to the extra length
+fn write_common_fields(output: &mut Vec<u8>, name: &str, data: &[u8], crc: u32) {
+    output.extend_from_slice(&20u16.to_le_bytes());
+    output.extend_from_slice(&0u16.to_le_bytes());
+    output.extend_from_slice(&METHOD_STORED.to_le_bytes());
+    output.extend_from_slice(&0u16.to_le_bytes());
+    output.extend_from_slice(&DOS_DATE.to_le_bytes());
+    output.extend_from_slice(&crc.to_le_bytes());
+    output.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    output.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    output.extend_from_slice(&(name.len() as u16).to_le_bytes());
+    output.extend_from_slice(&0u16.to_le_bytes());
+}
+
+fn read_u16(bytes: &[u8], offset: usize) -> Result<u16, String> {
+    return match bytes.get(offset..offset + 2) {
+        Some(field) => Ok(u16::from_le_bytes([field[0], field[1]])),
+        None => Err(String::from("archive ends inside a header")),
+    };
+}
+
+fn read_u32(bytes: &[u8], offset: usize) -> Result<u32, String> {
+    return match bytes.get(offset..offset + 4) {
+        Some(field) => Ok(u32::from_le_bytes(field.try_into().unwrap())),
+        None => Err(String::from("archive ends inside a header")),
+    };
+}
+
+fn read_u64(bytes: &[u8], offset: usize) -> Result<u64, String> {
+    return match bytes.get(offset..offset + 8) {
+        Some(field) => Ok(u64::from_le_bytes(field.try_into().unwrap())),
+        None => Err(String::from("archive ends inside a header")),
+    };
+}
+
+/// Every entry of an archive as its name and decompressed data, in central directory order
+pub fn read_zip(archive: &[u8]) -> Result<Vec<(String, Vec<u8>)>, String> {
+    // the end record sits at the very end, unless the archive has a comment
+    let end_offset = (0..=archive.len().saturating_sub(22))
+        .rev()
+        .find(|offset| read_u32(archive, *offset) == Ok(END_OF_CENTRAL_DIRECTORY_SIGNATURE))
+        .ok_or_else(|| String::from("archive has no end of central directory record"))?;
+
+    let entry_count = read_u16(archive, end_offset + 10)? as usize;
+    let mut offset = read_u32(archive, end_offset + 16)? as usize;
+
+    let mut entry_list = Vec::with_capacity(entry_count);
+
+    for _ in 0..entry_count {
+        if read_u32(archive, offset)? != CENTRAL_HEADER_SIGNATURE {
+            return Err(String::from("archive has a corrupt central directory"));
+        }
+
+        let flags = read_u16(archive, offset + 8)?;
+        let method = read_u16(archive, offset + 10)?;
+        let crc = read_u32(archive, offset + 16)?;
+        let mut compressed_size = read_u32(archive, offset + 20)? as u64;
+        let mut size = read_u32(archive, offset + 24)? as u64;
+        let name_length = read_u16(archive, offset + 28)? as usize;
+        let extra_length = read_u16(archive, offset + 30)? as usize;
+        let comment_length = read_u16(archive, offset + 32)? as usize;
+        let mut local_header_offset = read_u32(archive, offset + 42)? as u64;
+
+        let name_start = offset + 46;
+        let name = match archive.get(name_start..name_start + name_length) {
+            Some(name) => String::from_utf8_lossy(name).into_owned(),
+            None => return Err(String::from("archive ends inside a file name")),
+        };
+
+        if flags & 1 == 1 {
+            return Err(format!("entry {} is encrypted", name));
+        }
+
+        // zip64 values are only present for the fields saturated in the header, in this order
+        let mut extra_offset = name_start + name_length;
+        let extra_end = extra_offset + extra_length;
+        while extra_offset + 4 <= extra_end {
+            let id = read_u16(archive, extra_offset)?;
+            let length = read_u16(archive, extra_offset + 2)? as usize;
+
+            if id == ZIP64_EXTRA_ID {
+                let mut field_offset = extra_offset + 4;
+                for field in [&mut size, &mut compressed_size, &mut local_header_offset] {
+                    if *field == u32::MAX as u64 {
+                        *field = read_u64(archive, field_offset)?;
+                        field_offset += 8;
+                    }
+                }
+            }
+
+            extra_offset += 4 + length;
+        }
+
+        let local_offset = local_header_offset as usize;
+        if read_u32(archive, local_offset)? != LOCAL_HEADER_SIGNATURE {
+            return Err(format!("entry {} has a corrupt local header", name));
+        }
+
+        let data_start = local_offset
+            + 30
+            + read_u16(archive, local_offset + 26)? as usize
+            + read_u16(archive, local_offset + 28)? as usize;
+        let raw_data = match archive.get(data_start..data_start + compressed_size as usize) {
+            Some(raw_data) => raw_data,
+            None => return Err(format!("entry {} runs past the end of the archive", name)),
+        };
+
+        let data = match method {
+            METHOD_STORED => raw_data.to_vec(),
+            METHOD_DEFLATED => inflate(raw_data).map_err(|e| format!("entry {}: {}", name, e))?,
+            _ => {
+                return Err(format!(
+                    "entry {} uses unsupported compression method {}",
+                    name, method
+                ));
+            }
+        };
+
+        if data.len() as u64 != size || crc32(&data) != crc {
+            return Err(format!("entry {} fails its size or CRC check", name));
+        }
+
+        entry_list.push((name, data));
+        offset = extra_end + comment_length;
+    }
+
+    return Ok(entry_list);
+}
+
+#[cfg(test)]
+pub mod test {
+    #[allow(unused)]
+    use super::*;
+
+    #[test]
+    fn zip_round_trip() {
+        assert_eq!(crc32(b"123456789"), 0xcbf43926);
+
+        let entry_list = vec![
+            (String::from("a.npy"), b"first".to_vec()),
+            (String::from("b.npy"), Vec::new()),
+        ];
+        let archive = write_stored_zip(&entry_list).unwrap();
+        assert_eq!(read_zip(&archive).unwrap(), entry_list);
+
+        let mut corrupt = archive.clone();
+        corrupt[30 + 5] = b'F';
+        assert!(read_zip(&corrupt).is_err());
+    }
+}
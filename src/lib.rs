@@ -1,6 +1,9 @@
+pub mod amp;
 pub mod cli;
 pub mod config;
 pub mod graph;
+pub mod io;
 pub mod ops;
+pub mod profiler;
 pub mod tensor_core;
 pub mod utils;
@@ -1,3 +1,4 @@
 pub mod central;
 pub mod compute;
+pub mod lazy;
 pub mod public_ops;
@@ -1,10 +1,19 @@
 pub mod add_impl;
 pub mod broadcast_impl;
+pub mod cast_impl;
+pub mod complex_impl;
 pub mod concat_impl;
+pub mod contiguous_impl;
 pub mod div_impl;
 pub mod exp_impl;
+pub mod fused_impl;
+pub mod gather_impl;
+pub mod index_add_impl;
+pub mod index_select_impl;
 pub mod ln_impl;
 pub mod log_impl;
+pub mod masked_fill_impl;
+pub mod masked_select_impl;
 pub mod matmul_impl;
 pub mod max_impl;
 pub mod mean_impl;
@@ -12,6 +21,11 @@ pub mod min_impl;
 pub mod mul_impl;
 pub mod pow_impl;
 pub mod reshape_impl;
+pub mod scatter_add_impl;
+pub mod segment_max_impl;
+pub mod segment_mean_impl;
+pub mod segment_sum_impl;
+pub mod slice_impl;
 pub mod squeeze_impl;
 pub mod stack_impl;
 pub mod sub_impl;
@@ -19,3 +33,4 @@ pub mod sum_impl;
 pub mod tanh_impl;
 pub mod transpose_impl;
 pub mod unsqueeze_impl;
+pub mod where_impl;
@@ -5,10 +5,13 @@ use std::rc::Rc;
 
 use crate::graph::backward::Backward;
 use crate::graph::backward::add_backward::AddBackward;
+use crate::graph::backward::backward_utils::next_node_id;
 use crate::graph::edge::Edge;
+use crate::profiler::timing_profiler::{self, SpanCategory};
 use crate::tensor_core::dtypes::DTComp;
 use crate::tensor_core::tensor::Tensor;
 
+#[track_caller]
 pub fn add_impl<T>(
     lhs_tensor: Option<&Tensor<T>>,
     rhs_tensor: Option<&Tensor<T>>,
@@ -20,7 +23,9 @@ pub fn add_impl<T>(
         return;
     }
 
-    let mut node = AddBackward::new(0, vec![], result_tensor.__get_tensor_impl());
+    let _span = timing_profiler::span(SpanCategory::Graph, "add", &[result_tensor]);
+
+    let mut node = AddBackward::new(next_node_id(), vec![], result_tensor.__get_tensor_impl());
 
     match (lhs_tensor, rhs_tensor) {
         (Some(l), Some(r)) => {
@@ -32,24 +37,21 @@ pub fn add_impl<T>(
                 node.add_to_edge_list(Edge::maybe_create_connect(r, 1));
             }
 
-            node.save_input_refs(vec![
-                l.__clone_ptr_to_tensor_impl(),
-                r.__clone_ptr_to_tensor_impl(),
-            ]);
+            node.save_input_shapes(vec![l.get_shape().clone(), r.get_shape().clone()]);
         }
         (Some(l), None) => {
             if l.does_require_grad() {
                 node.add_to_edge_list(Edge::maybe_create_connect(l, 0));
             }
 
-            node.save_input_refs(vec![l.__clone_ptr_to_tensor_impl()]);
+            node.save_input_shapes(vec![l.get_shape().clone()]);
         }
         (None, Some(r)) => {
             if r.does_require_grad() {
                 node.add_to_edge_list(Edge::maybe_create_connect(r, 0));
             }
 
-            node.save_input_refs(vec![r.__clone_ptr_to_tensor_impl()]);
+            node.save_input_shapes(vec![r.get_shape().clone()]);
         }
         (None, None) => {
             return;
@@ -4,12 +4,15 @@ use std::ops::Add;
 use std::rc::Rc;
 
 use crate::graph::backward::Backward;
+use crate::graph::backward::backward_utils::next_node_id;
 use crate::graph::backward::broadcast_backward::BroadcastBackward;
 use crate::graph::edge::Edge;
+use crate::profiler::timing_profiler::{self, SpanCategory};
 use crate::tensor_core::dtypes::DTComp;
 use crate::tensor_core::tensor::Tensor;
 
 /// For exponent, base None is the natural exponent
+#[track_caller]
 pub fn broadcast_impl<T>(lhs_tensor: Option<&Tensor<T>>, result_tensor: &Tensor<T>)
 where
     T: Clone + DTComp + Debug + 'static + Add<Output = T>,
@@ -18,7 +21,10 @@ where
         return;
     }
 
-    let mut node = BroadcastBackward::new(0, vec![], result_tensor.__get_tensor_impl());
+    let _span = timing_profiler::span(SpanCategory::Graph, "broadcast", &[result_tensor]);
+
+    let mut node =
+        BroadcastBackward::new(next_node_id(), vec![], result_tensor.__get_tensor_impl());
 
     match lhs_tensor {
         Some(l) => {
@@ -26,7 +32,7 @@ where
                 node.add_to_edge_list(Edge::maybe_create_connect(l, 0));
             }
 
-            node.save_input_refs(vec![l.__clone_ptr_to_tensor_impl()]);
+            node.save_input_shapes(vec![l.get_shape().clone()]);
         }
         None => {
             panic!(
@@ -0,0 +1,45 @@
+use std::cell::RefCell;
+use std::fmt::Debug;
+use std::ops::Add;
+use std::rc::Rc;
+
+use num_traits::AsPrimitive;
+
+use crate::graph::backward::backward_utils::next_node_id;
+use crate::graph::backward::cast_backward::CastBackward;
+use crate::graph::edge::Edge;
+use crate::profiler::timing_profiler::{self, SpanCategory};
+use crate::tensor_core::dtypes::DTComp;
+use crate::tensor_core::tensor::Tensor;
+
+#[track_caller]
+pub fn cast_impl<T, U>(lhs_tensor: Option<&Tensor<T>>, result_tensor: &Tensor<U>)
+where
+    T: Copy + DTComp + Debug + Add<Output = T>,
+    U: Copy + DTComp + Debug + Add<Output = U> + AsPrimitive<T>,
+{
+    if !result_tensor.does_require_grad() {
+        return;
+    }
+
+    let _span = timing_profiler::span(SpanCategory::Graph, "cast", &[result_tensor]);
+
+    let mut node =
+        CastBackward::<U, T>::new(next_node_id(), vec![], result_tensor.__get_tensor_impl());
+
+    match lhs_tensor {
+        Some(l) => {
+            if l.does_require_grad() {
+                node.set_input_edge(Edge::maybe_create_connect(l, 0));
+            }
+        }
+        None => {
+            panic!(
+                "Error, No input found, input is needed to calculate gradient of a cast operation."
+            );
+        }
+    }
+
+    let node = Rc::new(RefCell::new(node));
+    result_tensor.set_grad_fn(node);
+}
@@ -0,0 +1,84 @@
+use num_complex::Complex;
+use num_traits::Float;
+use std::cell::RefCell;
+use std::fmt::Debug;
+use std::ops::Add;
+use std::rc::Rc;
+
+use crate::graph::backward::Backward;
+use crate::graph::backward::backward_utils::next_node_id;
+use crate::graph::backward::complex_part_backward::ComplexPartBackward;
+use crate::graph::backward::conj_backward::ConjBackward;
+use crate::graph::edge::Edge;
+use crate::profiler::timing_profiler::{self, SpanCategory};
+use crate::tensor_core::dtypes::DTComp;
+use crate::tensor_core::tensor::Tensor;
+
+#[track_caller]
+pub fn complex_part_impl<F>(
+    lhs_tensor: Option<&Tensor<Complex<F>>>,
+    result_tensor: &Tensor<F>,
+    grad_factor: Rc<Tensor<Complex<F>>>,
+) where
+    F: DTComp + Debug + Float,
+    Complex<F>: DTComp,
+{
+    if !result_tensor.does_require_grad() {
+        return;
+    }
+
+    let _span = timing_profiler::span(SpanCategory::Graph, "complex part", &[result_tensor]);
+
+    let mut node =
+        ComplexPartBackward::new(next_node_id(), vec![], result_tensor.__get_tensor_impl());
+
+    match lhs_tensor {
+        Some(l) => {
+            if l.does_require_grad() {
+                node.set_input_edge(Edge::maybe_create_connect(l, 0));
+            }
+
+            node.save_grad_factor(grad_factor);
+        }
+        None => {
+            panic!(
+                "Error, No input found, input is needed to calculate gradient of a complex part operation."
+            );
+        }
+    }
+
+    let node = Rc::new(RefCell::new(node));
+    result_tensor.set_grad_fn(node);
+}
+
+#[track_caller]
+pub fn conj_impl<T>(lhs_tensor: Option<&Tensor<T>>, result_tensor: &Tensor<T>)
+where
+    T: Clone + DTComp + Debug + 'static + Add<Output = T>,
+{
+    if !result_tensor.does_require_grad() {
+        return;
+    }
+
+    let _span = timing_profiler::span(SpanCategory::Graph, "conj", &[result_tensor]);
+
+    let mut node = ConjBackward::new(next_node_id(), vec![], result_tensor.__get_tensor_impl());
+
+    match lhs_tensor {
+        Some(l) => {
+            if l.does_require_grad() {
+                node.add_to_edge_list(Edge::maybe_create_connect(l, 0));
+            }
+
+            node.save_input_shapes(vec![l.get_shape().clone()]);
+        }
+        None => {
+            panic!(
+                "Error, No input found, input is needed to calculate gradient of a conj operation."
+            );
+        }
+    }
+
+    let node = Rc::new(RefCell::new(node));
+    result_tensor.set_grad_fn(node);
+}
@@ -6,16 +6,21 @@ use std::rc::Rc;
 use ndarray::Axis;
 
 use crate::graph::backward::Backward;
+use crate::graph::backward::backward_utils::next_node_id;
 use crate::graph::backward::concat_backward::ConcatBackward;
 use crate::graph::edge::Edge;
+use crate::profiler::timing_profiler::{self, SpanCategory};
 use crate::tensor_core::dtypes::DTComp;
 use crate::tensor_core::tensor::Tensor;
 
+#[track_caller]
 pub fn concat_impl<T>(tensor_list: &[&Tensor<T>], result_tensor: &Tensor<T>, dim: Axis)
 where
     T: Clone + DTComp + Debug + 'static + Add<Output = T>,
 {
-    let mut node = ConcatBackward::new(0, vec![], result_tensor.__get_tensor_impl());
+    let _span = timing_profiler::span(SpanCategory::Graph, "concat", &[result_tensor]);
+
+    let mut node = ConcatBackward::new(next_node_id(), vec![], result_tensor.__get_tensor_impl());
     node.save_dim(dim);
 
     let mut result_does_require_grad = false;
@@ -24,7 +29,6 @@ where
     for (i, tensor) in tensor_list.iter().enumerate() {
         if tensor.does_require_grad() {
             node.add_to_edge_list(Edge::maybe_create_connect(tensor, i));
-            node.save_input_refs(vec![tensor.__clone_ptr_to_tensor_impl()]);
 
             let range = (
                 accumulated_size,
@@ -36,8 +40,7 @@ where
 
             // set result to also require grad if any grad tracking is enabled
             if !result_does_require_grad {
-                result_tensor
-                    .requires_grad_intermediate("Intermediate tensor from concat operation");
+                result_tensor.requires_grad_intermediate("concat");
                 result_does_require_grad = true;
             }
         }
@@ -0,0 +1,43 @@
+use std::cell::RefCell;
+use std::fmt::Debug;
+use std::ops::Add;
+use std::rc::Rc;
+
+use crate::graph::backward::Backward;
+use crate::graph::backward::backward_utils::next_node_id;
+use crate::graph::backward::contiguous_backward::ContiguousBackward;
+use crate::graph::edge::Edge;
+use crate::profiler::timing_profiler::{self, SpanCategory};
+use crate::tensor_core::dtypes::DTComp;
+use crate::tensor_core::tensor::Tensor;
+
+#[track_caller]
+pub fn contiguous_impl<T>(lhs_tensor: Option<&Tensor<T>>, result_tensor: &Tensor<T>)
+where
+    T: Clone + DTComp + Debug + 'static + Add<Output = T>,
+{
+    if !result_tensor.does_require_grad() {
+        return;
+    }
+
+    let _span = timing_profiler::span(SpanCategory::Graph, "contiguous", &[result_tensor]);
+
+    let mut node =
+        ContiguousBackward::new(next_node_id(), vec![], result_tensor.__get_tensor_impl());
+
+    match lhs_tensor {
+        Some(l) => {
+            if l.does_require_grad() {
+                node.add_to_edge_list(Edge::maybe_create_connect(l, 0));
+            }
+        }
+        None => {
+            panic!(
+                "Error, No input found, input is needed to calculate gradient of a contiguous operation."
+            );
+        }
+    }
+
+    let node = Rc::new(RefCell::new(node));
+    result_tensor.set_grad_fn(node);
+}
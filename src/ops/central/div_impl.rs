@@ -1,16 +1,18 @@
 use ndarray::ScalarOperand;
-use num_traits::Signed;
 use std::cell::RefCell;
 use std::fmt::Debug;
-use std::ops::{Add, Div};
+use std::ops::{Add, Div, Mul, Neg};
 use std::rc::Rc;
 
 use crate::graph::backward::Backward;
+use crate::graph::backward::backward_utils::next_node_id;
 use crate::graph::backward::div_backward::DivBackward;
 use crate::graph::edge::Edge;
+use crate::profiler::timing_profiler::{self, SpanCategory};
 use crate::tensor_core::dtypes::DTComp;
 use crate::tensor_core::tensor::Tensor;
 
+#[track_caller]
 pub fn div_impl<T, S>(
     lhs_tensor: Option<&Tensor<T>>,
     rhs_tensor: Option<&Tensor<T>>,
@@ -24,14 +26,17 @@ pub fn div_impl<T, S>(
         + 'static
         + Add<Output = T>
         + Div<S, Output = T>
-        + Signed,
+        + Mul<Output = T>
+        + Neg<Output = T>,
     S: ScalarOperand + Clone + Debug,
 {
     if !result_tensor.does_require_grad() {
         return;
     }
 
-    let mut node = DivBackward::new(0, vec![], result_tensor.__get_tensor_impl());
+    let _span = timing_profiler::span(SpanCategory::Graph, "div", &[result_tensor]);
+
+    let mut node = DivBackward::new(next_node_id(), vec![], result_tensor.__get_tensor_impl());
 
     match (lhs_tensor, rhs_tensor) {
         (Some(l), Some(r)) => {
@@ -43,16 +48,22 @@ pub fn div_impl<T, S>(
                 node.add_to_edge_list(Edge::maybe_create_connect(r, 1));
             }
 
-            node.save_input_refs(vec![
-                l.__clone_ptr_to_tensor_impl(),
-                r.__clone_ptr_to_tensor_impl(),
-            ]);
+            // the divisor is needed by both edges, the dividend only by the divisor's edge
+            if r.does_require_grad() {
+                node.save_input(0, l.__clone_ptr_to_tensor_impl());
+            }
+
+            if l.does_require_grad() || r.does_require_grad() {
+                node.save_input(1, r.__clone_ptr_to_tensor_impl());
+            }
+
+            node.save_input_shapes(vec![l.get_shape().clone(), r.get_shape().clone()]);
         }
         (Some(l), None) => {
             if l.does_require_grad() {
                 node.add_to_edge_list(Edge::maybe_create_connect(l, 0));
 
-                node.save_input_refs(vec![l.__clone_ptr_to_tensor_impl()]);
+                node.save_input_shapes(vec![l.get_shape().clone()]);
                 node.save_scalar(scalar.expect("Error, trying to set a MulBackward node on multiplication of tensor and scalar, but scalar is not found."));
             }
         }
@@ -60,7 +71,7 @@ pub fn div_impl<T, S>(
             if r.does_require_grad() {
                 node.add_to_edge_list(Edge::maybe_create_connect(r, 0));
 
-                node.save_input_refs(vec![r.__clone_ptr_to_tensor_impl()]);
+                node.save_input_shapes(vec![r.get_shape().clone()]);
                 node.save_scalar(scalar.expect("Error, trying to set a MulBackward node on multiplication of tensor and scalar, but scalar is not found."));
             }
         }
@@ -3,24 +3,27 @@ use std::fmt::Debug;
 use std::ops::Add;
 use std::rc::Rc;
 
-use num_traits::Float;
-
 use crate::graph::backward::Backward;
+use crate::graph::backward::backward_utils::next_node_id;
 use crate::graph::backward::exp_backward::ExpBackward;
 use crate::graph::edge::Edge;
-use crate::tensor_core::dtypes::DTComp;
+use crate::profiler::timing_profiler::{self, SpanCategory};
+use crate::tensor_core::dtypes::{DTComp, DTFloat};
 use crate::tensor_core::tensor::Tensor;
 
 /// For exponent, base None is the natural exponent
-pub fn exp_impl<T>(lhs_tensor: Option<&Tensor<T>>, result_tensor: &Tensor<T>, natural: bool)
+#[track_caller]
+pub fn exp_impl<T>(lhs_tensor: Option<&Tensor<T>>, result_tensor: &Tensor<T>)
 where
-    T: Clone + DTComp + Debug + 'static + Add<Output = T> + Float,
+    T: Clone + DTComp + Debug + 'static + Add<Output = T> + DTFloat,
 {
     if !result_tensor.does_require_grad() {
         return;
     }
 
-    let mut node = ExpBackward::new(0, vec![], result_tensor.__get_tensor_impl());
+    let _span = timing_profiler::span(SpanCategory::Graph, "exp", &[result_tensor]);
+
+    let mut node = ExpBackward::new(next_node_id(), vec![], result_tensor.__get_tensor_impl());
 
     match lhs_tensor {
         Some(l) => {
@@ -28,9 +31,8 @@ where
                 node.add_to_edge_list(Edge::maybe_create_connect(l, 0));
             }
 
-            node.save_input_refs(vec![l.__clone_ptr_to_tensor_impl()]);
-
-            node.save_natural_state(natural);
+            // the gradient only needs the output, so the input can be freed
+            node.save_input_refs(vec![result_tensor.detach().__clone_ptr_to_tensor_impl()]);
         }
         None => {
             panic!(
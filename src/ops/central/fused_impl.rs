@@ -0,0 +1,57 @@
+use std::cell::RefCell;
+use std::fmt::Debug;
+use std::ops::Add;
+use std::rc::Rc;
+
+use num_traits::Float;
+
+use crate::graph::backward::Backward;
+use crate::graph::backward::backward_utils::next_node_id;
+use crate::graph::backward::fused_backward::FusedBackward;
+use crate::graph::edge::Edge;
+use crate::ops::lazy::fused_program::FusedProgram;
+use crate::profiler::timing_profiler::{self, SpanCategory};
+use crate::tensor_core::dtypes::DTComp;
+use crate::tensor_core::tensor::Tensor;
+use crate::tensor_core::tensor_impl::TensorImpl;
+
+/// Inputs are ordered by the `Load` index of the program, which is also the input number of the
+/// edge connecting to each of them
+#[track_caller]
+pub fn fused_impl<T>(
+    inputs: Vec<Rc<RefCell<TensorImpl<T>>>>,
+    result_tensor: &Tensor<T>,
+    program: Rc<FusedProgram<T>>,
+) where
+    T: Clone + DTComp + Debug + 'static + Add<Output = T> + Float,
+{
+    if !result_tensor.does_require_grad() {
+        return;
+    }
+
+    let _span = timing_profiler::span(SpanCategory::Graph, "fused", &[result_tensor]);
+
+    if inputs.is_empty() {
+        panic!(
+            "Error, No input found, input is needed to calculate gradient of a fused element wise kernel."
+        );
+    }
+
+    let mut node = FusedBackward::new(next_node_id(), vec![], result_tensor.__get_tensor_impl());
+
+    for (input_nr, input) in inputs.iter().enumerate() {
+        let input_tensor = Tensor {
+            tensor_impl: Rc::clone(input),
+        };
+
+        if input_tensor.does_require_grad() {
+            node.add_to_edge_list(Edge::maybe_create_connect(&input_tensor, input_nr));
+        }
+    }
+
+    node.save_input_refs(inputs);
+    node.save_program(program);
+
+    let node = Rc::new(RefCell::new(node));
+    result_tensor.set_grad_fn(node);
+}
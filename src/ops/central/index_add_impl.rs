@@ -0,0 +1,58 @@
+use std::cell::RefCell;
+use std::fmt::Debug;
+use std::ops::Add;
+use std::rc::Rc;
+
+use ndarray::Axis;
+use num_traits::Zero;
+
+use crate::graph::backward::Backward;
+use crate::graph::backward::backward_utils::next_node_id;
+use crate::graph::backward::index_add_backward::IndexAddBackward;
+use crate::graph::edge::Edge;
+use crate::profiler::timing_profiler::{self, SpanCategory};
+use crate::tensor_core::dtypes::DTComp;
+use crate::tensor_core::tensor::Tensor;
+
+#[track_caller]
+pub fn index_add_impl<T>(
+    lhs_tensor: Option<&Tensor<T>>,
+    src_tensor: Option<&Tensor<T>>,
+    result_tensor: &Tensor<T>,
+    indices: Rc<Tensor<usize>>,
+    dim: Axis,
+) where
+    T: Clone + DTComp + Debug + 'static + Add<Output = T> + Zero,
+{
+    if !result_tensor.does_require_grad() {
+        return;
+    }
+
+    let _span = timing_profiler::span(SpanCategory::Graph, "index add", &[result_tensor]);
+
+    let mut node = IndexAddBackward::new(next_node_id(), vec![], result_tensor.__get_tensor_impl());
+
+    match (lhs_tensor, src_tensor) {
+        (Some(l), Some(s)) => {
+            if l.does_require_grad() {
+                node.add_to_edge_list(Edge::maybe_create_connect(l, 0));
+            }
+
+            if s.does_require_grad() {
+                node.add_to_edge_list(Edge::maybe_create_connect(s, 1));
+            }
+
+            node.save_input_shapes(vec![l.get_shape().clone(), s.get_shape().clone()]);
+            node.save_indices(indices);
+            node.save_dim(dim);
+        }
+        _ => {
+            panic!(
+                "Error, No input found, both the tensor and the source are needed to calculate gradient of a index add operation."
+            );
+        }
+    }
+
+    let node = Rc::new(RefCell::new(node));
+    result_tensor.set_grad_fn(node);
+}
@@ -4,11 +4,14 @@ use std::ops::{Add, Div};
 use std::rc::Rc;
 
 use crate::graph::backward::Backward;
+use crate::graph::backward::backward_utils::next_node_id;
 use crate::graph::backward::ln_backward::LnBackward;
 use crate::graph::edge::Edge;
+use crate::profiler::timing_profiler::{self, SpanCategory};
 use crate::tensor_core::dtypes::DTComp;
 use crate::tensor_core::tensor::Tensor;
 
+#[track_caller]
 pub fn ln_impl<T>(lhs_tensor: Option<&Tensor<T>>, result_tensor: &Tensor<T>)
 where
     T: DTComp + Clone + Debug + Div<Output = T> + Add<Output = T> + 'static,
@@ -17,7 +20,9 @@ where
         return;
     }
 
-    let mut node = LnBackward::new(0, vec![], result_tensor.__get_tensor_impl());
+    let _span = timing_profiler::span(SpanCategory::Graph, "ln", &[result_tensor]);
+
+    let mut node = LnBackward::new(next_node_id(), vec![], result_tensor.__get_tensor_impl());
 
     match lhs_tensor {
         Some(l) => {
@@ -7,11 +7,14 @@ use ndarray::ScalarOperand;
 use num_traits::Float;
 
 use crate::graph::backward::Backward;
+use crate::graph::backward::backward_utils::next_node_id;
 use crate::graph::backward::log_backward::LogBackward;
 use crate::graph::edge::Edge;
+use crate::profiler::timing_profiler::{self, SpanCategory};
 use crate::tensor_core::dtypes::DTComp;
 use crate::tensor_core::tensor::Tensor;
 
+#[track_caller]
 pub fn log_impl<T, S>(lhs_tensor: Option<&Tensor<T>>, result_tensor: &Tensor<T>, scalar: Option<S>)
 where
     T: Clone + DTComp + Debug + 'static + Div<Output = T> + Add<Output = T> + Mul<S, Output = T>,
@@ -21,7 +24,9 @@ where
         return;
     }
 
-    let mut node = LogBackward::new(0, vec![], result_tensor.__get_tensor_impl());
+    let _span = timing_profiler::span(SpanCategory::Graph, "log", &[result_tensor]);
+
+    let mut node = LogBackward::new(next_node_id(), vec![], result_tensor.__get_tensor_impl());
 
     match lhs_tensor {
         Some(l) => {
@@ -0,0 +1,51 @@
+use std::cell::RefCell;
+use std::fmt::Debug;
+use std::ops::Add;
+use std::rc::Rc;
+
+use num_traits::Zero;
+
+use crate::graph::backward::Backward;
+use crate::graph::backward::backward_utils::next_node_id;
+use crate::graph::backward::masked_fill_backward::MaskedFillBackward;
+use crate::graph::edge::Edge;
+use crate::profiler::timing_profiler::{self, SpanCategory};
+use crate::tensor_core::dtypes::DTComp;
+use crate::tensor_core::tensor::Tensor;
+
+#[track_caller]
+pub fn masked_fill_impl<T>(
+    lhs_tensor: Option<&Tensor<T>>,
+    result_tensor: &Tensor<T>,
+    mask: Rc<Tensor<bool>>,
+) where
+    T: Clone + DTComp + Debug + 'static + Add<Output = T> + Zero,
+{
+    if !result_tensor.does_require_grad() {
+        return;
+    }
+
+    let _span = timing_profiler::span(SpanCategory::Graph, "masked fill", &[result_tensor]);
+
+    let mut node =
+        MaskedFillBackward::new(next_node_id(), vec![], result_tensor.__get_tensor_impl());
+
+    match lhs_tensor {
+        Some(l) => {
+            if l.does_require_grad() {
+                node.add_to_edge_list(Edge::maybe_create_connect(l, 0));
+            }
+
+            node.save_input_shapes(vec![l.get_shape().clone()]);
+            node.save_mask(mask);
+        }
+        None => {
+            panic!(
+                "Error, No input found, input is needed to calculate gradient of a masked fill operation."
+            );
+        }
+    }
+
+    let node = Rc::new(RefCell::new(node));
+    result_tensor.set_grad_fn(node);
+}
@@ -5,11 +5,14 @@ use std::ops::Add;
 use std::rc::Rc;
 
 use crate::graph::backward::Backward;
+use crate::graph::backward::backward_utils::next_node_id;
 use crate::graph::backward::matmul_backward::MatmulBackward;
 use crate::graph::edge::Edge;
+use crate::profiler::timing_profiler::{self, SpanCategory};
 use crate::tensor_core::dtypes::DTComp;
 use crate::tensor_core::tensor::Tensor;
 
+#[track_caller]
 pub fn matmul_impl<T>(
     lhs_tensor: Option<&Tensor<T>>,
     rhs_tensor: Option<&Tensor<T>>,
@@ -21,22 +24,22 @@ pub fn matmul_impl<T>(
         return;
     }
 
-    let mut node = MatmulBackward::new(0, vec![], result_tensor.__get_tensor_impl());
+    let _span = timing_profiler::span(SpanCategory::Graph, "matmul", &[result_tensor]);
+
+    let mut node = MatmulBackward::new(next_node_id(), vec![], result_tensor.__get_tensor_impl());
 
     match (lhs_tensor, rhs_tensor) {
         (Some(l), Some(r)) => {
+            // each edge only needs the other operand
             if l.does_require_grad() {
                 node.add_to_edge_list(Edge::maybe_create_connect(l, 0));
+                node.save_input(1, r.__clone_ptr_to_tensor_impl());
             }
 
             if r.does_require_grad() {
                 node.add_to_edge_list(Edge::maybe_create_connect(r, 1));
+                node.save_input(0, l.__clone_ptr_to_tensor_impl());
             }
-
-            node.save_input_refs(vec![
-                l.__clone_ptr_to_tensor_impl(),
-                r.__clone_ptr_to_tensor_impl(),
-            ]);
         }
         (Some(_), None) => {
             panic!("Error, matmul backward operation requires 2 tensors to calculate");
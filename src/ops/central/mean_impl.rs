@@ -7,11 +7,14 @@ use ndarray::{Axis, ScalarOperand};
 use num_traits::NumCast;
 
 use crate::graph::backward::Backward;
+use crate::graph::backward::backward_utils::next_node_id;
 use crate::graph::backward::mean_backward::MeanBackward;
 use crate::graph::edge::Edge;
+use crate::profiler::timing_profiler::{self, SpanCategory};
 use crate::tensor_core::dtypes::DTComp;
 use crate::tensor_core::tensor::Tensor;
 
+#[track_caller]
 pub fn mean_impl<T>(lhs_tensor: Option<&Tensor<T>>, result_tensor: &Tensor<T>, reduced_dim: Axis)
 where
     T: Clone
@@ -27,7 +30,9 @@ where
         return;
     }
 
-    let mut node = MeanBackward::new(0, vec![], result_tensor.__get_tensor_impl());
+    let _span = timing_profiler::span(SpanCategory::Graph, "mean", &[result_tensor]);
+
+    let mut node = MeanBackward::new(next_node_id(), vec![], result_tensor.__get_tensor_impl());
 
     match lhs_tensor {
         Some(l) => {
@@ -35,7 +40,7 @@ where
                 node.add_to_edge_list(Edge::maybe_create_connect(l, 0));
             }
 
-            node.save_input_refs(vec![l.__clone_ptr_to_tensor_impl()]);
+            node.save_input_shapes(vec![l.get_shape().clone()]);
             node.save_reduced_dim(reduced_dim);
         }
         None => {
@@ -7,11 +7,14 @@ use ndarray::Axis;
 use num_traits::Zero;
 
 use crate::graph::backward::Backward;
+use crate::graph::backward::backward_utils::next_node_id;
 use crate::graph::backward::min_backward::MinBackward;
 use crate::graph::edge::Edge;
+use crate::profiler::timing_profiler::{self, SpanCategory};
 use crate::tensor_core::dtypes::DTComp;
 use crate::tensor_core::tensor::Tensor;
 
+#[track_caller]
 pub fn min_impl<T>(
     lhs_tensor: Option<&Tensor<T>>,
     result_tensor: &Tensor<T>,
@@ -24,7 +27,9 @@ pub fn min_impl<T>(
         return;
     }
 
-    let mut node = MinBackward::new(0, vec![], result_tensor.__get_tensor_impl());
+    let _span = timing_profiler::span(SpanCategory::Graph, "min", &[result_tensor]);
+
+    let mut node = MinBackward::new(next_node_id(), vec![], result_tensor.__get_tensor_impl());
 
     match lhs_tensor {
         Some(l) => {
@@ -32,7 +37,7 @@ pub fn min_impl<T>(
                 node.add_to_edge_list(Edge::maybe_create_connect(l, 0));
             }
 
-            node.save_input_refs(vec![l.__clone_ptr_to_tensor_impl()]);
+            node.save_input_shapes(vec![l.get_shape().clone()]);
             node.save_indices(indices);
             node.save_reduced_dim(reduced_dim);
         }
@@ -5,11 +5,14 @@ use std::ops::{Add, Mul};
 use std::rc::Rc;
 
 use crate::graph::backward::Backward;
+use crate::graph::backward::backward_utils::next_node_id;
 use crate::graph::backward::mul_backward::MulBackward;
 use crate::graph::edge::Edge;
+use crate::profiler::timing_profiler::{self, SpanCategory};
 use crate::tensor_core::dtypes::DTComp;
 use crate::tensor_core::tensor::Tensor;
 
+#[track_caller]
 pub fn mul_impl<T, S>(
     lhs_tensor: Option<&Tensor<T>>,
     rhs_tensor: Option<&Tensor<T>>,
@@ -23,28 +26,30 @@ pub fn mul_impl<T, S>(
         return;
     }
 
-    let mut node = MulBackward::new(0, vec![], result_tensor.__get_tensor_impl());
+    let _span = timing_profiler::span(SpanCategory::Graph, "mul", &[result_tensor]);
+
+    let mut node = MulBackward::new(next_node_id(), vec![], result_tensor.__get_tensor_impl());
 
     match (lhs_tensor, rhs_tensor) {
         (Some(l), Some(r)) => {
+            // each edge only needs the other operand
             if l.does_require_grad() {
                 node.add_to_edge_list(Edge::maybe_create_connect(l, 0));
+                node.save_input(1, r.__clone_ptr_to_tensor_impl());
             }
 
             if r.does_require_grad() {
                 node.add_to_edge_list(Edge::maybe_create_connect(r, 1));
+                node.save_input(0, l.__clone_ptr_to_tensor_impl());
             }
 
-            node.save_input_refs(vec![
-                l.__clone_ptr_to_tensor_impl(),
-                r.__clone_ptr_to_tensor_impl(),
-            ]);
+            node.save_input_shapes(vec![l.get_shape().clone(), r.get_shape().clone()]);
         }
         (Some(l), None) => {
             if l.does_require_grad() {
                 node.add_to_edge_list(Edge::maybe_create_connect(l, 0));
 
-                node.save_input_refs(vec![l.__clone_ptr_to_tensor_impl()]);
+                node.save_input_shapes(vec![l.get_shape().clone()]);
                 node.save_scalar(scalar.expect("Error, trying to set a MulBackward node on multiplication of tensor and scalar, but scalar is not found."));
             }
         }
@@ -52,7 +57,7 @@ pub fn mul_impl<T, S>(
             if r.does_require_grad() {
                 node.add_to_edge_list(Edge::maybe_create_connect(r, 0));
 
-                node.save_input_refs(vec![r.__clone_ptr_to_tensor_impl()]);
+                node.save_input_shapes(vec![r.get_shape().clone()]);
                 node.save_scalar(scalar.expect("Error, trying to set a MulBackward node on multiplication of tensor and scalar, but scalar is not found."));
             }
         }
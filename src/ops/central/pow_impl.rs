@@ -4,23 +4,34 @@ use std::ops::{Add, Mul};
 use std::rc::Rc;
 
 use ndarray::ScalarOperand;
-use num_traits::Float;
 
 use crate::graph::backward::Backward;
+use crate::graph::backward::backward_utils::next_node_id;
 use crate::graph::backward::pow_backward::PowBackward;
 use crate::graph::edge::Edge;
-use crate::tensor_core::dtypes::DTComp;
+use crate::profiler::timing_profiler::{self, SpanCategory};
+use crate::tensor_core::dtypes::{DTComp, DTFloat};
 use crate::tensor_core::tensor::Tensor;
 
+#[track_caller]
 pub fn pow_impl<T>(lhs_tensor: Option<&Tensor<T>>, result_tensor: &Tensor<T>, scalar: Option<T>)
 where
-    T: Clone + DTComp + Debug + 'static + Add<Output = T> + Mul<Output = T> + Float + ScalarOperand,
+    T: Clone
+        + DTComp
+        + Debug
+        + 'static
+        + Add<Output = T>
+        + Mul<Output = T>
+        + DTFloat
+        + ScalarOperand,
 {
     if !result_tensor.does_require_grad() {
         return;
     }
 
-    let mut node = PowBackward::new(0, vec![], result_tensor.__get_tensor_impl());
+    let _span = timing_profiler::span(SpanCategory::Graph, "pow", &[result_tensor]);
+
+    let mut node = PowBackward::new(next_node_id(), vec![], result_tensor.__get_tensor_impl());
 
     match lhs_tensor {
         Some(l) => {
@@ -4,11 +4,14 @@ use std::ops::Add;
 use std::rc::Rc;
 
 use crate::graph::backward::Backward;
+use crate::graph::backward::backward_utils::next_node_id;
 use crate::graph::backward::reshape_backward::ReshapeBackward;
 use crate::graph::edge::Edge;
+use crate::profiler::timing_profiler::{self, SpanCategory};
 use crate::tensor_core::dtypes::DTComp;
 use crate::tensor_core::tensor::Tensor;
 
+#[track_caller]
 pub fn reshape_impl<T>(
     lhs_tensor: Option<&Tensor<T>>,
     result_tensor: &Tensor<T>,
@@ -20,7 +23,9 @@ pub fn reshape_impl<T>(
         return;
     }
 
-    let mut node = ReshapeBackward::new(0, vec![], result_tensor.__get_tensor_impl());
+    let _span = timing_profiler::span(SpanCategory::Graph, "reshape", &[result_tensor]);
+
+    let mut node = ReshapeBackward::new(next_node_id(), vec![], result_tensor.__get_tensor_impl());
 
     match lhs_tensor {
         Some(l) => {
@@ -0,0 +1,52 @@
+use std::cell::RefCell;
+use std::fmt::Debug;
+use std::ops::{Add, Div};
+use std::rc::Rc;
+
+use num_traits::{NumCast, Zero};
+
+use crate::graph::backward::Backward;
+use crate::graph::backward::backward_utils::next_node_id;
+use crate::graph::backward::segment_mean_backward::SegmentMeanBackward;
+use crate::graph::edge::Edge;
+use crate::profiler::timing_profiler::{self, SpanCategory};
+use crate::tensor_core::dtypes::DTComp;
+use crate::tensor_core::tensor::Tensor;
+
+#[track_caller]
+pub fn segment_mean_impl<T>(
+    lhs_tensor: Option<&Tensor<T>>,
+    result_tensor: &Tensor<T>,
+    segment_ids: Rc<Tensor<usize>>,
+    num_segments: usize,
+) where
+    T: Clone + DTComp + Debug + 'static + Add<Output = T> + Zero + Div<Output = T> + NumCast,
+{
+    if !result_tensor.does_require_grad() {
+        return;
+    }
+
+    let _span = timing_profiler::span(SpanCategory::Graph, "segment mean", &[result_tensor]);
+
+    let mut node =
+        SegmentMeanBackward::new(next_node_id(), vec![], result_tensor.__get_tensor_impl());
+
+    match lhs_tensor {
+        Some(l) => {
+            if l.does_require_grad() {
+                node.add_to_edge_list(Edge::maybe_create_connect(l, 0));
+            }
+
+            node.save_input_shapes(vec![l.get_shape().clone()]);
+            node.save_segment_ids(segment_ids, num_segments);
+        }
+        None => {
+            panic!(
+                "Error, No input found, input is needed to calculate gradient of a segment mean operation."
+            );
+        }
+    }
+
+    let node = Rc::new(RefCell::new(node));
+    result_tensor.set_grad_fn(node);
+}
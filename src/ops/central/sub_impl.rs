@@ -1,22 +1,27 @@
-use num_traits::Signed;
 use std::cell::RefCell;
 use std::fmt::Debug;
+use std::ops::{Add, Neg};
 use std::rc::Rc;
 
 use crate::graph::backward::Backward;
+use crate::graph::backward::backward_utils::next_node_id;
 use crate::graph::backward::sub_backward::SubBackward;
 use crate::graph::edge::Edge;
+use crate::profiler::timing_profiler::{self, SpanCategory};
 use crate::tensor_core::dtypes::DTComp;
 use crate::tensor_core::tensor::Tensor;
 
+#[track_caller]
 pub fn sub_impl<T>(
     lhs_tensor: Option<&Tensor<T>>,
     rhs_tensor: Option<&Tensor<T>>,
     result_tensor: &Tensor<T>,
 ) where
-    T: DTComp + Clone + Debug + 'static + Signed,
+    T: DTComp + Clone + Debug + 'static + Add<Output = T> + Neg<Output = T>,
 {
-    let mut node = SubBackward::new(0, vec![], result_tensor.__get_tensor_impl());
+    let _span = timing_profiler::span(SpanCategory::Graph, "sub", &[result_tensor]);
+
+    let mut node = SubBackward::new(next_node_id(), vec![], result_tensor.__get_tensor_impl());
 
     match (lhs_tensor, rhs_tensor) {
         (Some(l), Some(r)) => {
@@ -28,24 +33,21 @@ pub fn sub_impl<T>(
                 node.add_to_edge_list(Edge::maybe_create_connect(r, 1));
             }
 
-            node.save_input_refs(vec![
-                l.__clone_ptr_to_tensor_impl(),
-                r.__clone_ptr_to_tensor_impl(),
-            ]);
+            node.save_input_shapes(vec![l.get_shape().clone(), r.get_shape().clone()]);
         }
         (Some(l), None) => {
             if l.does_require_grad() {
                 node.add_to_edge_list(Edge::maybe_create_connect(l, 0));
             }
 
-            node.save_input_refs(vec![l.__clone_ptr_to_tensor_impl()]);
+            node.save_input_shapes(vec![l.get_shape().clone()]);
         }
         (None, Some(r)) => {
             if r.does_require_grad() {
                 node.add_to_edge_list(Edge::maybe_create_connect(r, 0));
             }
 
-            node.save_input_refs(vec![r.__clone_ptr_to_tensor_impl()]);
+            node.save_input_shapes(vec![r.get_shape().clone()]);
         }
         (None, None) => {
             return;
@@ -6,11 +6,14 @@ use std::rc::Rc;
 use ndarray::Axis;
 
 use crate::graph::backward::Backward;
+use crate::graph::backward::backward_utils::next_node_id;
 use crate::graph::backward::sum_backward::SumBackward;
 use crate::graph::edge::Edge;
+use crate::profiler::timing_profiler::{self, SpanCategory};
 use crate::tensor_core::dtypes::DTComp;
 use crate::tensor_core::tensor::Tensor;
 
+#[track_caller]
 pub fn sum_impl<T>(lhs_tensor: Option<&Tensor<T>>, result_tensor: &Tensor<T>, reduced_dim: Axis)
 where
     T: Clone + DTComp + Debug + 'static + Add<Output = T>,
@@ -19,7 +22,9 @@ where
         return;
     }
 
-    let mut node = SumBackward::new(0, vec![], result_tensor.__get_tensor_impl());
+    let _span = timing_profiler::span(SpanCategory::Graph, "sum", &[result_tensor]);
+
+    let mut node = SumBackward::new(next_node_id(), vec![], result_tensor.__get_tensor_impl());
 
     match lhs_tensor {
         Some(l) => {
@@ -27,7 +32,7 @@ where
                 node.add_to_edge_list(Edge::maybe_create_connect(l, 0));
             }
 
-            node.save_input_refs(vec![l.__clone_ptr_to_tensor_impl()]);
+            node.save_input_shapes(vec![l.get_shape().clone()]);
             node.save_reduced_dim(reduced_dim);
         }
         None => {
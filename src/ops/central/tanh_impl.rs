@@ -4,23 +4,34 @@ use std::ops::{Add, Mul};
 use std::rc::Rc;
 
 use ndarray::ScalarOperand;
-use num_traits::Float;
 
 use crate::graph::backward::Backward;
+use crate::graph::backward::backward_utils::next_node_id;
 use crate::graph::backward::tanh_backward::TanhBackward;
 use crate::graph::edge::Edge;
-use crate::tensor_core::dtypes::DTComp;
+use crate::profiler::timing_profiler::{self, SpanCategory};
+use crate::tensor_core::dtypes::{DTComp, DTFloat};
 use crate::tensor_core::tensor::Tensor;
 
+#[track_caller]
 pub fn tanh_impl<T>(lhs_tensor: Option<&Tensor<T>>, result_tensor: &Tensor<T>)
 where
-    T: Clone + DTComp + Debug + 'static + Add<Output = T> + Mul<Output = T> + Float + ScalarOperand,
+    T: Clone
+        + DTComp
+        + Debug
+        + 'static
+        + Add<Output = T>
+        + Mul<Output = T>
+        + DTFloat
+        + ScalarOperand,
 {
     if !result_tensor.does_require_grad() {
         return;
     }
 
-    let mut node = TanhBackward::new(0, vec![], result_tensor.__get_tensor_impl());
+    let _span = timing_profiler::span(SpanCategory::Graph, "tanh", &[result_tensor]);
+
+    let mut node = TanhBackward::new(next_node_id(), vec![], result_tensor.__get_tensor_impl());
 
     match lhs_tensor {
         Some(l) => {
@@ -28,7 +39,8 @@ where
                 node.add_to_edge_list(Edge::maybe_create_connect(l, 0));
             }
 
-            node.save_input_refs(vec![l.__clone_ptr_to_tensor_impl()]);
+            // the gradient only needs the output, so the input can be freed
+            node.save_input_refs(vec![result_tensor.detach().__clone_ptr_to_tensor_impl()]);
         }
         None => {
             panic!(
@@ -4,11 +4,14 @@ use std::ops::Add;
 use std::rc::Rc;
 
 use crate::graph::backward::Backward;
+use crate::graph::backward::backward_utils::next_node_id;
 use crate::graph::backward::transpose_backward::TransposeBackward;
 use crate::graph::edge::Edge;
+use crate::profiler::timing_profiler::{self, SpanCategory};
 use crate::tensor_core::dtypes::DTComp;
 use crate::tensor_core::tensor::Tensor;
 
+#[track_caller]
 pub fn transpose_impl<T>(
     lhs_tensor: Option<&Tensor<T>>,
     result_tensor: &Tensor<T>,
@@ -20,7 +23,10 @@ pub fn transpose_impl<T>(
         return;
     }
 
-    let mut node = TransposeBackward::new(0, vec![], result_tensor.__get_tensor_impl());
+    let _span = timing_profiler::span(SpanCategory::Graph, "transpose", &[result_tensor]);
+
+    let mut node =
+        TransposeBackward::new(next_node_id(), vec![], result_tensor.__get_tensor_impl());
 
     match lhs_tensor {
         Some(l) => {
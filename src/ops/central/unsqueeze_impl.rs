@@ -6,11 +6,14 @@ use std::rc::Rc;
 use ndarray::Axis;
 
 use crate::graph::backward::Backward;
+use crate::graph::backward::backward_utils::next_node_id;
 use crate::graph::backward::unsqueeze_backward::UnsqueezeBackward;
 use crate::graph::edge::Edge;
+use crate::profiler::timing_profiler::{self, SpanCategory};
 use crate::tensor_core::dtypes::DTComp;
 use crate::tensor_core::tensor::Tensor;
 
+#[track_caller]
 pub fn unsqueeze_impl<T>(
     lhs_tensor: Option<&Tensor<T>>,
     result_tensor: &Tensor<T>,
@@ -22,7 +25,10 @@ pub fn unsqueeze_impl<T>(
         return;
     }
 
-    let mut node = UnsqueezeBackward::new(0, vec![], result_tensor.__get_tensor_impl());
+    let _span = timing_profiler::span(SpanCategory::Graph, "unsqueeze", &[result_tensor]);
+
+    let mut node =
+        UnsqueezeBackward::new(next_node_id(), vec![], result_tensor.__get_tensor_impl());
 
     match lhs_tensor {
         Some(l) => {
@@ -0,0 +1,55 @@
+use std::cell::RefCell;
+use std::fmt::Debug;
+use std::ops::Add;
+use std::rc::Rc;
+
+use num_traits::Zero;
+
+use crate::graph::backward::Backward;
+use crate::graph::backward::backward_utils::next_node_id;
+use crate::graph::backward::where_backward::WhereBackward;
+use crate::graph::edge::Edge;
+use crate::profiler::timing_profiler::{self, SpanCategory};
+use crate::tensor_core::dtypes::DTComp;
+use crate::tensor_core::tensor::Tensor;
+
+#[track_caller]
+pub fn where_impl<T>(
+    lhs_tensor: Option<&Tensor<T>>,
+    rhs_tensor: Option<&Tensor<T>>,
+    result_tensor: &Tensor<T>,
+    condition: Rc<Tensor<bool>>,
+) where
+    T: Clone + DTComp + Debug + 'static + Add<Output = T> + Zero,
+{
+    if !result_tensor.does_require_grad() {
+        return;
+    }
+
+    let _span = timing_profiler::span(SpanCategory::Graph, "where", &[result_tensor]);
+
+    let mut node = WhereBackward::new(next_node_id(), vec![], result_tensor.__get_tensor_impl());
+
+    match (lhs_tensor, rhs_tensor) {
+        (Some(l), Some(r)) => {
+            if l.does_require_grad() {
+                node.add_to_edge_list(Edge::maybe_create_connect(l, 0));
+            }
+
+            if r.does_require_grad() {
+                node.add_to_edge_list(Edge::maybe_create_connect(r, 1));
+            }
+
+            node.save_input_shapes(vec![l.get_shape().clone(), r.get_shape().clone()]);
+            node.save_condition(condition);
+        }
+        _ => {
+            panic!(
+                "Error, No input found, both tensors are needed to calculate gradient of a where operation."
+            );
+        }
+    }
+
+    let node = Rc::new(RefCell::new(node));
+    result_tensor.set_grad_fn(node);
+}
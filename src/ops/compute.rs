@@ -1,14 +1,21 @@
 pub mod add_compute;
+pub mod cast_compute;
+pub mod complex_compute;
 pub mod div_compute;
 pub mod dot_compute;
 pub mod exp_compute;
+pub mod fused_compute;
 pub mod hyperbolic_compute;
+pub mod indexing_compute;
 pub mod log_compute;
+pub mod logical_compute;
 pub mod matmul_compute;
 pub mod max_min_compute;
+pub mod meta_compute;
 pub mod mul_compute;
 pub mod neg_compute;
 pub mod pow_compute;
+pub mod scatter_compute;
 pub mod shape_compute;
 pub mod stack_concat_compute;
 pub mod sub_compute;
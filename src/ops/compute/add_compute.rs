@@ -1,7 +1,8 @@
 use ndarray::ScalarOperand;
 use std::fmt::Debug;
-use std::ops::{Add, Deref};
+use std::ops::Add;
 
+use crate::tensor_core::buffer_pool;
 use crate::tensor_core::dtypes::DTComp;
 use crate::tensor_core::tensor::Tensor;
 
@@ -12,7 +13,8 @@ where
     let lhs_raw = lhs_tensor.get_raw_data();
     let rhs_raw = rhs_tensor.get_raw_data();
 
-    let new_raw = lhs_raw.deref() + rhs_raw.deref();
+    let new_raw =
+        buffer_pool::zip_map(lhs_raw.view(), rhs_raw.view(), |a, b| a.clone() + b.clone());
     let tensor = Tensor::from_raw_array(new_raw, false);
 
     return tensor;
@@ -25,7 +27,7 @@ where
 {
     let x_raw = tensor.get_raw_data();
 
-    let new_raw = x_raw.deref() + scalar;
+    let new_raw = &x_raw.view() + scalar;
     let tensor = Tensor::from_raw_array(new_raw, false);
 
     return tensor;
@@ -0,0 +1,15 @@
+use num_traits::AsPrimitive;
+
+use crate::tensor_core::{dtypes::DTComp, tensor::Tensor};
+use std::fmt::Debug;
+
+/// Converts every element with `as`, with the same rounding, saturation and wrapping rules
+pub fn compute_cast<T, U>(tensor: &Tensor<T>) -> Tensor<U>
+where
+    T: DTComp + Debug + AsPrimitive<U>,
+    U: DTComp + Debug + Copy,
+{
+    let res_array = tensor.get_raw_data().mapv(|x| x.as_());
+
+    return Tensor::from_raw_array(res_array, false);
+}
@@ -0,0 +1,258 @@
+use num_complex::Complex;
+use num_traits::{Float, Zero};
+
+use crate::tensor_core::dtypes::{DTComp, DTypes};
+use crate::tensor_core::tensor::Tensor;
+use std::any::Any;
+use std::fmt::Debug;
+
+// Parts of complex tensors. Gradients of complex tensors follow the conjugate Wirtinger
+// convention: the gradient of a real loss `L` with respect to `z = x + iy` is `dL/dx + i dL/dy`,
+// so stepping against it moves `z` the same way as stepping against the gradients of `x` and `y`.
+
+/// Real valued functions of a complex tensor
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComplexPart {
+    Real,
+    Imag,
+    Abs,
+    Angle,
+}
+
+pub fn compute_complex_part<F>(tensor: &Tensor<Complex<F>>, part: ComplexPart) -> Tensor<F>
+where
+    F: DTComp + Debug + Float,
+    Complex<F>: DTComp,
+{
+    let res_array = tensor.get_raw_data().mapv(|z| match part {
+        ComplexPart::Real => z.re,
+        ComplexPart::Imag => z.im,
+        ComplexPart::Abs => z.norm(),
+        ComplexPart::Angle => z.arg(),
+    });
+
+    return Tensor::from_raw_array(res_array, false);
+}
+
+/// The gradient of the input of a complex part is this factor scaled by the real upstream
+/// gradient. `abs` and `angle` are not differentiable at zero, where the factor is zero.
+pub fn compute_complex_part_grad_factor<F>(
+    tensor: &Tensor<Complex<F>>,
+    part: ComplexPart,
+) -> Tensor<Complex<F>>
+where
+    F: DTComp + Debug + Float,
+    Complex<F>: DTComp,
+{
+    let res_array = tensor.get_raw_data().mapv(|z| match part {
+        ComplexPart::Real => Complex::new(F::one(), F::zero()),
+        ComplexPart::Imag => Complex::new(F::zero(), F::one()),
+        ComplexPart::Abs => {
+            if z.is_zero() {
+                Complex::zero()
+            } else {
+                z / z.norm()
+            }
+        }
+        ComplexPart::Angle => {
+            if z.is_zero() {
+                Complex::zero()
+            } else {
+                Complex::<F>::i() * z / z.norm_sqr()
+            }
+        }
+    });
+
+    return Tensor::from_raw_array(res_array, false);
+}
+
+/// Element wise product of a complex tensor with a real one of the same shape
+pub fn compute_scale_by_real<F>(
+    tensor: &Tensor<Complex<F>>,
+    scale: &Tensor<F>,
+) -> Tensor<Complex<F>>
+where
+    F: DTComp + Debug + Float,
+    Complex<F>: DTComp,
+{
+    let mut res_array = tensor.get_raw_data().to_owned();
+    res_array.zip_mut_with(&scale.get_raw_data().view(), |z, x| {
+        *z = z.scale(*x);
+    });
+
+    return Tensor::from_raw_array(res_array, false);
+}
+
+pub fn compute_conj<F>(tensor: &Tensor<Complex<F>>) -> Tensor<Complex<F>>
+where
+    F: DTComp + Debug + Float,
+    Complex<F>: DTComp,
+{
+    let res_array = tensor.get_raw_data().mapv(|z| z.conj());
+
+    return Tensor::from_raw_array(res_array, false);
+}
+
+fn conj_as_complex<T, F>(tensor: &Tensor<T>) -> Tensor<T>
+where
+    T: DTComp + Debug,
+    F: DTComp + Debug + Float,
+    Complex<F>: DTComp,
+{
+    let tensor = (tensor as &dyn Any)
+        .downcast_ref::<Tensor<Complex<F>>>()
+        .expect("Error: Internal error, complex tensor has a mismatching dtype");
+
+    return *(Box::new(compute_conj(tensor)) as Box<dyn Any>)
+        .downcast::<Tensor<T>>()
+        .expect("Error: Internal error, complex tensor has a mismatching dtype");
+}
+
+/// Conjugates a complex tensor, and shares the data of a tensor of any other dtype. The gradient
+/// of a holomorphic `f(z)` is `conj(f'(z)) * upstream`, so backward nodes of holomorphic
+/// operations get it by conjugating the upstream gradient, applying the real formula, and
+/// conjugating the result.
+pub fn compute_conj_if_complex<T>(tensor: &Tensor<T>) -> Tensor<T>
+where
+    T: DTComp + Debug,
+{
+    match T::dtype() {
+        DTypes::Complex64 => {
+            return conj_as_complex::<T, f32>(tensor);
+        }
+        DTypes::Complex128 => {
+            return conj_as_complex::<T, f64>(tensor);
+        }
+        _ => {
+            return tensor.detach();
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    #[allow(unused)]
+    use super::*;
+    use crate::ops::public_ops::matmul::matmul;
+    use ndarray::Axis;
+
+    fn grad_of<F>(tensor: &Tensor<Complex<F>>) -> Vec<Complex<F>>
+    where
+        F: DTComp + Debug + Float,
+        Complex<F>: DTComp,
+    {
+        return tensor
+            .grad()
+            .unwrap()
+            .get_raw_data()
+            .iter()
+            .copied()
+            .collect::<Vec<_>>();
+    }
+
+    #[test]
+    fn complex_gradients_follow_wirtinger_convention() {
+        let a = Tensor::new(
+            vec![Complex::new(1.0_f64, 2.0), Complex::new(3.0, -1.0)],
+            vec![2],
+            true,
+        );
+        let b = Tensor::new(
+            vec![Complex::new(2.0_f64, -1.0), Complex::new(0.5, 1.0)],
+            vec![2],
+            true,
+        );
+
+        // the gradient of Im(w) with respect to w is i, and a product passes it on conjugated
+        let z = (&(&a * &b) - &a).sum(Axis(0)).imag();
+        z.backward(Tensor::new(vec![1.0], vec![], false), false);
+
+        assert_eq!(
+            grad_of(&a),
+            vec![Complex::new(-1.0, 1.0), Complex::new(1.0, -0.5)]
+        );
+        assert_eq!(
+            grad_of(&b),
+            vec![Complex::new(2.0, 1.0), Complex::new(-1.0, 3.0)]
+        );
+
+        let x1 = Tensor::new(
+            vec![Complex::new(1.0_f32, 1.0), Complex::new(2.0, 0.0)],
+            vec![1, 2],
+            true,
+        );
+        let x2 = Tensor::new(
+            vec![Complex::new(0.0_f32, 1.0), Complex::new(1.0, -1.0)],
+            vec![2, 1],
+            true,
+        );
+
+        let z = matmul(&x1, &x2).real();
+        z.backward(Tensor::new(vec![1.0], vec![1, 1], false), false);
+
+        assert_eq!(
+            grad_of(&x1),
+            vec![Complex::new(0.0, -1.0), Complex::new(1.0, 1.0)]
+        );
+        assert_eq!(
+            grad_of(&x2),
+            vec![Complex::new(1.0, -1.0), Complex::new(2.0, 0.0)]
+        );
+
+        let x3 = Tensor::new(vec![Complex::new(1.0_f64, 0.0)], vec![1], true);
+        let x4 = Tensor::new(vec![Complex::new(2.0_f64, -1.0)], vec![1], false);
+
+        // the gradient of Re(x3 / x4) with respect to x3 is conj(1 / x4)
+        let z = (&x3 / &x4).real();
+        z.backward(Tensor::new(vec![1.0], vec![1], false), false);
+
+        assert!((grad_of(&x3)[0] - Complex::new(0.4, -0.2)).norm() < 1e-12);
+    }
+
+    #[test]
+    fn complex_elementwise_functions_use_conjugated_gradients() {
+        let w = Complex::new(0.5_f64, -0.75);
+        let derivatives: Vec<(
+            &str,
+            fn(&Tensor<Complex<f64>>) -> Tensor<Complex<f64>>,
+            Complex<f64>,
+            Complex<f64>,
+        )> = vec![
+            ("exp", |x| x.exp(), w.exp(), w.exp()),
+            ("ln", |x| x.ln(), w.ln(), w.inv()),
+            (
+                "tanh",
+                |x| x.tanh(),
+                w.tanh(),
+                Complex::new(1.0, 0.0) - w.tanh() * w.tanh(),
+            ),
+            (
+                "pow",
+                |x| x.pow(Complex::new(3.0, 0.0)),
+                w.powi(3),
+                w * w * 3.0,
+            ),
+        ];
+
+        for (name, function, value, derivative) in derivatives {
+            let x = Tensor::new(vec![w], vec![1], true);
+            let y = function(&x);
+
+            assert!(
+                (y.get_raw_data()[0] - value).norm() < 1e-12,
+                "{} forward",
+                name
+            );
+
+            // the gradient of Re(f(w)) with respect to w is conj(f'(w))
+            y.real()
+                .backward(Tensor::new(vec![1.0], vec![1], false), false);
+
+            assert!(
+                (grad_of(&x)[0] - derivative.conj()).norm() < 1e-12,
+                "{} gradient",
+                name
+            );
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use ndarray::ScalarOperand;
-use std::cell::{Ref, RefCell};
+use std::cell::RefCell;
 use std::fmt::Debug;
-use std::ops::{Deref, Div};
+use std::ops::Div;
 
 use crate::tensor_core::dtypes::DTComp;
 use crate::tensor_core::tensor::Tensor;
@@ -14,7 +14,7 @@ where
     let x_raw = lhs_scalar.get_raw_data();
     let y_raw = rhs_tensor.get_raw_data();
 
-    let new_raw = x_raw.deref() / y_raw.deref();
+    let new_raw = &x_raw.view() / &y_raw.view();
     let tensor = Tensor::from_raw_array(new_raw, false);
 
     return tensor;
@@ -27,10 +27,12 @@ pub fn div_compute_tensorimpl_tensorimpl<T>(
 where
     T: DTComp + Clone + Debug + Div<Output = T>,
 {
-    let lhs_raw = Ref::map(lhs_tensorimpl.borrow(), |x| x.get_raw_data_());
-    let rhs_raw = Ref::map(rhs_tensorimpl.borrow(), |x| x.get_raw_data_());
+    let lhs_impl = lhs_tensorimpl.borrow();
+    let lhs_raw = lhs_impl.get_raw_data_();
+    let rhs_impl = rhs_tensorimpl.borrow();
+    let rhs_raw = rhs_impl.get_raw_data_();
 
-    let new_raw = lhs_raw.deref() / rhs_raw.deref();
+    let new_raw = &lhs_raw.view() / &rhs_raw.view();
     let tensor = Tensor::from_raw_array(new_raw, false);
 
     return tensor;
@@ -47,7 +49,7 @@ where
     let binding = tensor_impl.borrow();
     let x_raw = binding.get_raw_data_();
 
-    let new_raw = x_raw / scalar;
+    let new_raw = &x_raw / scalar;
     let tensor = Tensor::from_raw_array(new_raw, false);
 
     return tensor;
@@ -60,7 +62,7 @@ where
 {
     let x_raw = tensor.get_raw_data();
 
-    let new_raw = x_raw.deref() / scalar;
+    let new_raw = &x_raw.view() / scalar;
     let tensor = Tensor::from_raw_array(new_raw, false);
 
     return tensor;
@@ -1,19 +1,17 @@
-use num_traits::Float;
-use std::cell::{Ref, RefCell};
+use std::cell::RefCell;
 use std::fmt::Debug;
-use std::ops::Deref;
 
-use crate::tensor_core::dtypes::DTComp;
+use crate::tensor_core::dtypes::{DTComp, DTFloat};
 use crate::tensor_core::tensor::Tensor;
 use crate::tensor_core::tensor_impl::TensorImpl;
 
 /// Compute the exponential of the tensor with base e
 pub fn exp_compute_tensor<T>(tensor: &Tensor<T>) -> Tensor<T>
 where
-    T: DTComp + Clone + Debug + Float,
+    T: DTComp + Clone + Debug + DTFloat,
 {
     let x_raw = tensor.get_raw_data();
-    let raw_array = x_raw.mapv(|elem| elem.exp());
+    let raw_array = x_raw.mapv(|elem| elem.elem_exp());
 
     let tensor = Tensor::from_raw_array(raw_array, false);
 
@@ -23,10 +21,11 @@ where
 /// Compute the exponential of the tensorimpl with base e
 pub fn exp_compute_tensorimpl<T>(tensorimpl: &RefCell<TensorImpl<T>>) -> Tensor<T>
 where
-    T: DTComp + Clone + Debug + Float,
+    T: DTComp + Clone + Debug + DTFloat,
 {
-    let x_raw = Ref::map(tensorimpl.borrow(), |x| x.get_raw_data_());
-    let raw_array = x_raw.deref().mapv(|elem| elem.exp());
+    let x_impl = tensorimpl.borrow();
+    let x_raw = x_impl.get_raw_data_();
+    let raw_array = x_raw.view().mapv(|elem| elem.elem_exp());
 
     let tensor = Tensor::from_raw_array(raw_array, false);
 
@@ -36,10 +35,10 @@ where
 /// Compute the exponential of the tensor with base 2
 pub fn exp2_compute_tensor<T>(tensor: &Tensor<T>) -> Tensor<T>
 where
-    T: DTComp + Clone + Debug + Float,
+    T: DTComp + Clone + Debug + DTFloat,
 {
     let x_raw = tensor.get_raw_data();
-    let raw_array = x_raw.mapv(|elem| elem.exp2());
+    let raw_array = x_raw.mapv(|elem| elem.elem_exp2());
 
     let tensor = Tensor::from_raw_array(raw_array, false);
 
@@ -49,10 +48,11 @@ where
 /// Compute the exponential of the tensorimpl with base e
 pub fn exp2_compute_tensorimpl<T>(tensorimpl: &RefCell<TensorImpl<T>>) -> Tensor<T>
 where
-    T: DTComp + Clone + Debug + Float,
+    T: DTComp + Clone + Debug + DTFloat,
 {
-    let x_raw = Ref::map(tensorimpl.borrow(), |x| x.get_raw_data_());
-    let raw_array = x_raw.deref().mapv(|elem| elem.exp2());
+    let x_impl = tensorimpl.borrow();
+    let x_raw = x_impl.get_raw_data_();
+    let raw_array = x_raw.view().mapv(|elem| elem.elem_exp2());
 
     let tensor = Tensor::from_raw_array(raw_array, false);
 
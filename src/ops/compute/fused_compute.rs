@@ -0,0 +1,128 @@
+use ndarray::{ArrayBase, ArrayViewD, IxDyn};
+use num_traits::Float;
+use std::cell::{Ref, RefCell};
+use std::fmt::Debug;
+use std::rc::Rc;
+
+use crate::ops::lazy::fused_program::FusedProgram;
+use crate::tensor_core::dtypes::DTComp;
+use crate::tensor_core::tensor::Tensor;
+use crate::tensor_core::tensor_impl::TensorImpl;
+
+fn broadcast_input_views<'a, T>(
+    borrowed_inputs: &'a [Ref<'a, TensorImpl<T>>],
+    output_shape: &[usize],
+) -> Vec<ArrayViewD<'a, T>>
+where
+    T: DTComp + Clone + Debug,
+{
+    return borrowed_inputs
+        .iter()
+        .map(|input| {
+            // broadcasting the storage directly also covers inputs that are already broadcast
+            input
+                .get_storage_()
+                .get_data()
+                .broadcast(output_shape.to_vec())
+                .expect("Error: Input of fused kernel cannot be broadcasted to the output shape")
+        })
+        .collect();
+}
+
+/// Evaluate the fused program over every element of the broadcasted inputs in a single pass
+pub fn fused_compute_forward<T>(
+    program: &FusedProgram<T>,
+    inputs: &[Rc<RefCell<TensorImpl<T>>>],
+) -> Tensor<T>
+where
+    T: DTComp + Debug + Float,
+{
+    let output_shape = program.get_output_shape();
+    let numel: usize = output_shape.iter().product();
+
+    let borrowed_inputs: Vec<Ref<TensorImpl<T>>> = inputs.iter().map(|x| x.borrow()).collect();
+    let views = broadcast_input_views(&borrowed_inputs, output_shape);
+    let mut input_iters: Vec<_> = views.iter().map(|view| view.iter()).collect();
+
+    let mut loaded = vec![T::zero(); program.get_num_inputs()];
+    let mut registers = vec![T::zero(); program.get_num_registers()];
+    let mut output = Vec::with_capacity(numel);
+
+    for _ in 0..numel {
+        for (slot, iter) in loaded.iter_mut().zip(input_iters.iter_mut()) {
+            *slot = *iter.next().unwrap();
+        }
+
+        output.push(program.eval_element(&loaded, &mut registers));
+    }
+
+    let raw_array = ArrayBase::from_shape_vec(IxDyn(output_shape), output)
+        .expect("Error: Output of fused kernel does not match its inferred shape");
+
+    return Tensor::from_raw_array(raw_array, false);
+}
+
+/// Compute the gradient of the fused program w.r.t. every input that needs one, in a single pass.
+/// Gradients are returned in the output shape, reducing them back to each input's shape is left to
+/// the caller.
+pub fn fused_compute_backward<T>(
+    program: &FusedProgram<T>,
+    inputs: &[Rc<RefCell<TensorImpl<T>>>],
+    upstream_gradient: &Tensor<T>,
+    needs_grad: &[bool],
+) -> Vec<Option<Tensor<T>>>
+where
+    T: DTComp + Debug + Float,
+{
+    let output_shape = program.get_output_shape();
+    let numel: usize = output_shape.iter().product();
+
+    let borrowed_inputs: Vec<Ref<TensorImpl<T>>> = inputs.iter().map(|x| x.borrow()).collect();
+    let views = broadcast_input_views(&borrowed_inputs, output_shape);
+    let mut input_iters: Vec<_> = views.iter().map(|view| view.iter()).collect();
+
+    let upstream_raw = upstream_gradient.get_raw_data();
+    let upstream_view = upstream_raw
+        .broadcast(output_shape.to_vec())
+        .expect("Error: Upstream gradient does not match the output shape of the fused kernel");
+
+    let mut loaded = vec![T::zero(); program.get_num_inputs()];
+    let mut registers = vec![T::zero(); program.get_num_registers()];
+    let mut adjoints = vec![T::zero(); program.get_num_registers()];
+    let mut input_grads = vec![T::zero(); program.get_num_inputs()];
+
+    let mut grads: Vec<Option<Vec<T>>> = needs_grad
+        .iter()
+        .map(|needed| match needed {
+            true => Some(Vec::with_capacity(numel)),
+            false => None,
+        })
+        .collect();
+
+    for upstream_elem in upstream_view.iter() {
+        for (slot, iter) in loaded.iter_mut().zip(input_iters.iter_mut()) {
+            *slot = *iter.next().unwrap();
+        }
+
+        program.eval_element(&loaded, &mut registers);
+        program.backward_element(&registers, *upstream_elem, &mut adjoints, &mut input_grads);
+
+        for (grad, input_grad) in grads.iter_mut().zip(input_grads.iter()) {
+            if let Some(grad) = grad {
+                grad.push(*input_grad);
+            }
+        }
+    }
+
+    return grads
+        .into_iter()
+        .map(|grad| {
+            grad.map(|grad| {
+                let raw_array = ArrayBase::from_shape_vec(IxDyn(output_shape), grad)
+                    .expect("Error: Gradient of fused kernel does not match its inferred shape");
+
+                Tensor::from_raw_array(raw_array, false)
+            })
+        })
+        .collect();
+}
@@ -1,18 +1,17 @@
 use num_traits::Float;
-use std::cell::{Ref, RefCell};
+use std::cell::RefCell;
 use std::fmt::Debug;
-use std::ops::Deref;
 
-use crate::tensor_core::dtypes::DTComp;
+use crate::tensor_core::dtypes::{DTComp, DTFloat};
 use crate::tensor_core::tensor::Tensor;
 use crate::tensor_core::tensor_impl::TensorImpl;
 
 pub fn tanh_compute_tensor<T>(tensor: &Tensor<T>) -> Tensor<T>
 where
-    T: DTComp + Clone + Debug + Float + 'static,
+    T: DTComp + Clone + Debug + DTFloat + 'static,
 {
     let x_raw = tensor.get_raw_data();
-    let raw_array = x_raw.mapv(|elem| elem.tanh());
+    let raw_array = x_raw.mapv(|elem| elem.elem_tanh());
 
     let tensor = Tensor::from_raw_array(raw_array, false);
 
@@ -21,10 +20,11 @@ where
 
 pub fn tanh_compute_tensorimpl<T>(tensorimpl: &RefCell<TensorImpl<T>>) -> Tensor<T>
 where
-    T: DTComp + Clone + Debug + Float + 'static,
+    T: DTComp + Clone + Debug + DTFloat + 'static,
 {
-    let x_raw = Ref::map(tensorimpl.borrow(), |x| x.get_raw_data_());
-    let raw_array = x_raw.deref().mapv(|elem| elem.tanh());
+    let x_impl = tensorimpl.borrow();
+    let x_raw = x_impl.get_raw_data_();
+    let raw_array = x_raw.view().mapv(|elem| elem.elem_tanh());
 
     let tensor = Tensor::from_raw_array(raw_array, false);
 
@@ -47,8 +47,9 @@ pub fn sinh_compute_tensorimpl<T>(tensorimpl: &RefCell<TensorImpl<T>>) -> Tensor
 where
     T: DTComp + Clone + Debug + Float + 'static,
 {
-    let x_raw = Ref::map(tensorimpl.borrow(), |x| x.get_raw_data_());
-    let raw_array = x_raw.deref().mapv(|elem| elem.sinh());
+    let x_impl = tensorimpl.borrow();
+    let x_raw = x_impl.get_raw_data_();
+    let raw_array = x_raw.view().mapv(|elem| elem.sinh());
 
     let tensor = Tensor::from_raw_array(raw_array, false);
 
@@ -71,8 +72,9 @@ pub fn cosh_compute_tensorimpl<T>(tensorimpl: &RefCell<TensorImpl<T>>) -> Tensor
 where
     T: DTComp + Clone + Debug + Float + 'static,
 {
-    let x_raw = Ref::map(tensorimpl.borrow(), |x| x.get_raw_data_());
-    let raw_array = x_raw.deref().mapv(|elem| elem.cosh());
+    let x_impl = tensorimpl.borrow();
+    let x_raw = x_impl.get_raw_data_();
+    let raw_array = x_raw.view().mapv(|elem| elem.cosh());
 
     let tensor = Tensor::from_raw_array(raw_array, false);
 
@@ -95,8 +97,9 @@ pub fn sech_compute_tensorimpl<T>(tensorimpl: &RefCell<TensorImpl<T>>) -> Tensor
 where
     T: DTComp + Clone + Debug + Float + 'static,
 {
-    let x_raw = Ref::map(tensorimpl.borrow(), |x| x.get_raw_data_());
-    let raw_array = x_raw.deref().mapv(|elem| T::one() / elem.cosh());
+    let x_impl = tensorimpl.borrow();
+    let x_raw = x_impl.get_raw_data_();
+    let raw_array = x_raw.view().mapv(|elem| T::one() / elem.cosh());
 
     let tensor = Tensor::from_raw_array(raw_array, false);
 
@@ -119,8 +122,9 @@ pub fn csch_compute_tensorimpl<T>(tensorimpl: &RefCell<TensorImpl<T>>) -> Tensor
 where
     T: DTComp + Clone + Debug + Float + 'static,
 {
-    let x_raw = Ref::map(tensorimpl.borrow(), |x| x.get_raw_data_());
-    let raw_array = x_raw.deref().mapv(|elem| T::one() / elem.sinh());
+    let x_impl = tensorimpl.borrow();
+    let x_raw = x_impl.get_raw_data_();
+    let raw_array = x_raw.view().mapv(|elem| T::one() / elem.sinh());
 
     let tensor = Tensor::from_raw_array(raw_array, false);
 
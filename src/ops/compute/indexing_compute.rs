@@ -0,0 +1,161 @@
+use ndarray::{ArrayD, Axis, Dimension, IxDyn, SliceInfoElem};
+
+use crate::tensor_core::{dtypes::DTComp, tensor::Tensor};
+use std::fmt::Debug;
+
+use super::shape_compute::{compute_broadcast, compute_slice};
+
+/// Slice info selecting a single index along a dimension and every element of the others
+pub fn select_slice_info(ndim: usize, dim: Axis, index: usize) -> Vec<SliceInfoElem> {
+    return (0..ndim)
+        .map(|axis| {
+            if axis == dim.index() {
+                SliceInfoElem::Index(index as isize)
+            } else {
+                SliceInfoElem::Slice {
+                    start: 0,
+                    end: None,
+                    step: 1,
+                }
+            }
+        })
+        .collect();
+}
+
+pub fn compute_select<T>(tensor: &Tensor<T>, dim: Axis, index: usize) -> Tensor<T>
+where
+    T: DTComp + Debug + Clone,
+{
+    let shape = tensor.get_shape().clone();
+
+    if dim.index() >= shape.len() || index >= shape[dim.index()] {
+        panic!(
+            "Error: Cannot select index {} along dimension {} of a tensor with shape {:?}",
+            index,
+            dim.index(),
+            shape
+        );
+    }
+
+    return compute_slice(tensor, &select_slice_info(shape.len(), dim, index));
+}
+
+/// Checks that an index tensor is one dimensional and every index is in range of `dim`
+fn validate_indices(shape: &[usize], dim: Axis, indices: &Tensor<usize>) -> Vec<usize> {
+    if indices.get_shape().len() != 1 {
+        panic!(
+            "Error: Expected a 1D tensor of indices, got a tensor with shape {:?}",
+            indices.get_shape()
+        );
+    }
+
+    if dim.index() >= shape.len() {
+        panic!(
+            "Error: Cannot index along dimension {} of a tensor with shape {:?}",
+            dim.index(),
+            shape
+        );
+    }
+
+    let indices = indices
+        .get_raw_data()
+        .iter()
+        .copied()
+        .collect::<Vec<usize>>();
+
+    if let Some(index) = indices.iter().find(|index| **index >= shape[dim.index()]) {
+        panic!(
+            "Error: Index {} is out of range for dimension {} of a tensor with shape {:?}",
+            index,
+            dim.index(),
+            shape
+        );
+    }
+
+    return indices;
+}
+
+pub fn compute_index_select<T>(tensor: &Tensor<T>, dim: Axis, indices: &Tensor<usize>) -> Tensor<T>
+where
+    T: DTComp + Debug + Clone,
+{
+    let indices = validate_indices(&tensor.get_shape(), dim, indices);
+    let res_array = tensor.get_raw_data().select(dim, &indices);
+
+    return Tensor::from_raw_array(res_array, false);
+}
+
+pub fn compute_gather<T>(tensor: &Tensor<T>, dim: Axis, indices: &Tensor<usize>) -> Tensor<T>
+where
+    T: DTComp + Debug + Clone,
+{
+    let shape = tensor.get_shape().clone();
+    let index_shape = indices.get_shape().clone();
+
+    // every dimension of the index other than `dim` must fit inside the tensor
+    let gatherable = dim.index() < shape.len()
+        && index_shape.len() == shape.len()
+        && index_shape
+            .iter()
+            .zip(shape.iter())
+            .enumerate()
+            .all(|(axis, (index_dim, dim_len))| axis == dim.index() || index_dim <= dim_len);
+
+    if !gatherable {
+        panic!(
+            "Error: Cannot gather along dimension {} of a tensor with shape {:?} using indices of shape {:?}",
+            dim.index(),
+            shape,
+            index_shape
+        );
+    }
+
+    let raw_data = tensor.get_raw_data();
+    let indices_raw_data = indices.get_raw_data();
+
+    let res_array = ArrayD::from_shape_fn(IxDyn(&index_shape), |position| {
+        let mut full_index = position.as_array_view().to_vec();
+        let index = indices_raw_data[position.clone()];
+
+        if index >= shape[dim.index()] {
+            panic!(
+                "Error: Index {} is out of range for dimension {} of a tensor with shape {:?}",
+                index,
+                dim.index(),
+                shape
+            );
+        }
+
+        full_index[dim.index()] = index;
+        return raw_data[IxDyn(&full_index)].clone();
+    });
+
+    return Tensor::from_raw_array(res_array, false);
+}
+
+/// Broadcasts a mask to the shape of the tensor it selects from
+pub fn compute_mask_like<T>(tensor: &Tensor<T>, mask: &Tensor<bool>) -> Tensor<bool>
+where
+    T: DTComp + Debug,
+{
+    return compute_broadcast(mask, tensor.get_shape().clone());
+}
+
+pub fn compute_masked_select<T>(tensor: &Tensor<T>, mask: &Tensor<bool>) -> Tensor<T>
+where
+    T: DTComp + Debug + Clone,
+{
+    let mask = compute_mask_like(tensor, mask);
+    let mask_raw_data = mask.get_raw_data();
+
+    let res_vec = tensor
+        .get_raw_data()
+        .iter()
+        .zip(mask_raw_data.iter())
+        .filter(|(_, selected)| **selected)
+        .map(|(x, _)| x.clone())
+        .collect::<Vec<T>>();
+
+    let length = res_vec.len();
+    return Tensor::new(res_vec, vec![length], false);
+}
@@ -1,9 +1,8 @@
 use num_traits::Float;
-use std::cell::{Ref, RefCell};
+use std::cell::RefCell;
 use std::fmt::Debug;
-use std::ops::Deref;
 
-use crate::tensor_core::dtypes::DTComp;
+use crate::tensor_core::dtypes::{DTComp, DTFloat};
 use crate::tensor_core::tensor::Tensor;
 use crate::tensor_core::tensor_impl::TensorImpl;
 
@@ -13,7 +12,7 @@ where
 {
     let x_raw = lhs_scalar.get_raw_data();
 
-    let raw_array = x_raw.deref().log(base);
+    let raw_array = x_raw.view().log(base);
 
     let tensor = Tensor::from_raw_array(raw_array, false);
 
@@ -24,8 +23,9 @@ pub fn log_compute_tensorimpl<T>(tensorimpl: &RefCell<TensorImpl<T>>, base: T) -
 where
     T: DTComp + Clone + Debug + Float + 'static,
 {
-    let x_raw = Ref::map(tensorimpl.borrow(), |x| x.get_raw_data_());
-    let raw_array = x_raw.deref().log(base);
+    let x_impl = tensorimpl.borrow();
+    let x_raw = x_impl.get_raw_data_();
+    let raw_array = x_raw.view().log(base);
 
     let tensor = Tensor::from_raw_array(raw_array, false);
 
@@ -34,11 +34,11 @@ where
 
 pub fn ln_compute_tensor<T>(tensor: &Tensor<T>) -> Tensor<T>
 where
-    T: DTComp + Clone + Debug + Float + 'static,
+    T: DTComp + Clone + Debug + DTFloat + 'static,
 {
     let x_raw = tensor.get_raw_data();
 
-    let raw_array = x_raw.deref().ln();
+    let raw_array = x_raw.view().mapv(|elem| elem.elem_ln());
 
     let tensor = Tensor::from_raw_array(raw_array, false);
 
@@ -47,10 +47,11 @@ where
 
 pub fn ln_compute_tensorimpl<T>(tensorimpl: &RefCell<TensorImpl<T>>) -> Tensor<T>
 where
-    T: DTComp + Clone + Debug + Float + 'static,
+    T: DTComp + Clone + Debug + DTFloat + 'static,
 {
-    let x_raw = Ref::map(tensorimpl.borrow(), |x| x.get_raw_data_());
-    let raw_array = x_raw.deref().ln();
+    let x_impl = tensorimpl.borrow();
+    let x_raw = x_impl.get_raw_data_();
+    let raw_array = x_raw.view().mapv(|elem| elem.elem_ln());
 
     let tensor = Tensor::from_raw_array(raw_array, false);
 
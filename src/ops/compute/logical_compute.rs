@@ -0,0 +1,227 @@
+use ndarray::Axis;
+use num_traits::{One, Zero};
+
+use crate::tensor_core::{dtypes::DTComp, tensor::Tensor};
+use crate::utils::shaping_utils::broadcast_shapes;
+use std::fmt::Debug;
+
+use super::indexing_compute::compute_mask_like;
+
+// Comparisons, logical operations and selections by a boolean mask. Every binary operation here
+// broadcasts its operands together, the same way arithmetic operations do.
+
+pub fn broadcast_shape_or_panic(shapes: &[Vec<usize>]) -> Vec<usize> {
+    match broadcast_shapes(shapes) {
+        Some(shape) => {
+            return shape;
+        }
+        None => {
+            panic!(
+                "Error: Cannot broadcast tensors of shapes {:?} together.",
+                shapes
+            );
+        }
+    }
+}
+
+/// Element wise combination of two tensors with broadcasting, into a tensor of any element type
+pub fn compute_zip_with<T, U, F>(lhs_tensor: &Tensor<T>, rhs_tensor: &Tensor<T>, f: F) -> Tensor<U>
+where
+    T: DTComp + Debug + Clone,
+    U: DTComp + Debug,
+    F: Fn(&T, &T) -> U,
+{
+    let lhs_raw = lhs_tensor.get_raw_data();
+    let rhs_raw = rhs_tensor.get_raw_data();
+
+    let shape = broadcast_shape_or_panic(&[lhs_raw.shape().to_vec(), rhs_raw.shape().to_vec()]);
+
+    let lhs_raw = lhs_raw.broadcast(shape.clone()).unwrap();
+    let rhs_raw = rhs_raw.broadcast(shape.clone()).unwrap();
+
+    let res_vec = lhs_raw
+        .iter()
+        .zip(rhs_raw.iter())
+        .map(|(a, b)| f(a, b))
+        .collect::<Vec<U>>();
+
+    return Tensor::new(res_vec, shape, false);
+}
+
+pub fn compute_compare_scalar<T, F>(tensor: &Tensor<T>, scalar: T, f: F) -> Tensor<bool>
+where
+    T: DTComp + Debug + Clone,
+    F: Fn(&T, &T) -> bool,
+{
+    let res_array = tensor.get_raw_data().map(|x| f(x, &scalar));
+
+    return Tensor::from_raw_array(res_array, false);
+}
+
+pub fn compute_logical_not(tensor: &Tensor<bool>) -> Tensor<bool> {
+    let res_array = tensor.get_raw_data().map(|x| !*x);
+
+    return Tensor::from_raw_array(res_array, false);
+}
+
+pub fn compute_any(tensor: &Tensor<bool>, dim: Axis) -> Tensor<bool> {
+    let res_array = tensor
+        .get_raw_data()
+        .map_axis(dim, |lane| lane.iter().any(|x| *x));
+
+    return Tensor::from_raw_array(res_array, false);
+}
+
+pub fn compute_all(tensor: &Tensor<bool>, dim: Axis) -> Tensor<bool> {
+    let res_array = tensor
+        .get_raw_data()
+        .map_axis(dim, |lane| lane.iter().all(|x| *x));
+
+    return Tensor::from_raw_array(res_array, false);
+}
+
+/// Ones where the mask is true and zeros elsewhere
+pub fn compute_mask_as_numeric<U>(tensor: &Tensor<bool>) -> Tensor<U>
+where
+    U: DTComp + Debug + Clone + Zero + One,
+{
+    let res_array = tensor
+        .get_raw_data()
+        .map(|x| if *x { U::one() } else { U::zero() });
+
+    return Tensor::from_raw_array(res_array, false);
+}
+
+pub fn where_shape<T>(
+    condition: &Tensor<bool>,
+    lhs_tensor: &Tensor<T>,
+    rhs_tensor: &Tensor<T>,
+) -> Vec<usize>
+where
+    T: DTComp + Debug,
+{
+    return broadcast_shape_or_panic(&[
+        condition.get_shape().clone(),
+        lhs_tensor.get_shape().clone(),
+        rhs_tensor.get_shape().clone(),
+    ]);
+}
+
+/// Takes elements of `lhs_tensor` where the condition is true and of `rhs_tensor` elsewhere
+pub fn compute_where<T>(
+    condition: &Tensor<bool>,
+    lhs_tensor: &Tensor<T>,
+    rhs_tensor: &Tensor<T>,
+) -> Tensor<T>
+where
+    T: DTComp + Debug + Clone,
+{
+    let shape = where_shape(condition, lhs_tensor, rhs_tensor);
+
+    let condition_raw = condition.get_raw_data();
+    let lhs_raw = lhs_tensor.get_raw_data();
+    let rhs_raw = rhs_tensor.get_raw_data();
+
+    let condition_raw = condition_raw.broadcast(shape.clone()).unwrap();
+    let lhs_raw = lhs_raw.broadcast(shape.clone()).unwrap();
+    let rhs_raw = rhs_raw.broadcast(shape.clone()).unwrap();
+
+    let res_vec = condition_raw
+        .iter()
+        .zip(lhs_raw.iter().zip(rhs_raw.iter()))
+        .map(|(selected, (a, b))| if *selected { a.clone() } else { b.clone() })
+        .collect::<Vec<T>>();
+
+    return Tensor::new(res_vec, shape, false);
+}
+
+pub fn compute_masked_fill<T>(tensor: &Tensor<T>, mask: &Tensor<bool>, value: T) -> Tensor<T>
+where
+    T: DTComp + Debug + Clone,
+{
+    let mask = compute_mask_like(tensor, mask);
+    let mask_raw = mask.get_raw_data();
+
+    let mut res_array = tensor.get_raw_data().to_owned();
+    res_array.zip_mut_with(&mask_raw.view(), |x, selected| {
+        if *selected {
+            *x = value.clone();
+        }
+    });
+
+    return Tensor::from_raw_array(res_array, false);
+}
+
+/// Keeps the elements where the mask equals `keep` and zeroes the others. The mask must already
+/// have the shape of the tensor.
+pub fn compute_keep_by_mask<T>(tensor: &Tensor<T>, mask: &Tensor<bool>, keep: bool) -> Tensor<T>
+where
+    T: DTComp + Debug + Clone + Zero,
+{
+    let mut res_array = tensor.get_raw_data().to_owned();
+    res_array.zip_mut_with(&mask.get_raw_data().view(), |x, selected| {
+        if *selected != keep {
+            *x = T::zero();
+        }
+    });
+
+    return Tensor::from_raw_array(res_array, false);
+}
+
+#[cfg(test)]
+pub mod test {
+    #[allow(unused)]
+    use super::*;
+
+    #[test]
+    fn comparison_and_logical_ops() {
+        let x1 = Tensor::new(vec![1.0, 5.0, 3.0, 0.0], vec![2, 2], false);
+        let x2 = Tensor::new(vec![2.0, 3.0], vec![2], false);
+
+        let lt = x1.lt(&x2);
+        assert_eq!(*lt.get_shape(), vec![2, 2]);
+        assert_eq!(
+            lt.get_raw_data().iter().copied().collect::<Vec<_>>(),
+            vec![true, false, false, true]
+        );
+
+        let ge = x1.ge_scalar(3.0);
+        let both = lt.logical_or(&ge);
+        assert_eq!(
+            both.get_raw_data().iter().copied().collect::<Vec<_>>(),
+            vec![true, true, true, true]
+        );
+        assert_eq!(
+            lt.logical_xor(&ge)
+                .logical_not()
+                .get_raw_data()
+                .iter()
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![false, false, false, false]
+        );
+
+        assert_eq!(
+            lt.any(Axis(1))
+                .get_raw_data()
+                .iter()
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![true, true]
+        );
+        assert_eq!(
+            both.all(Axis(0))
+                .get_raw_data()
+                .iter()
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![true, true]
+        );
+
+        let correct = lt.as_numeric::<f32>();
+        assert_eq!(
+            correct.get_raw_data().iter().copied().collect::<Vec<_>>(),
+            vec![1.0, 0.0, 0.0, 1.0]
+        );
+    }
+}
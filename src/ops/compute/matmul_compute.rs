@@ -1,10 +1,13 @@
 use ndarray::{ArrayD, Ix2, LinalgScalar, s};
 
 use crate::{
-    tensor_core::{dtypes::DTComp, tensor::Tensor},
+    ops::compute::shape_compute::compute_reshape,
+    tensor_core::{
+        dtypes::DTComp, half::accumulate_in_f32, tensor::Tensor, tensor_impl::TensorImpl,
+    },
     utils::shaping_utils::get_last_2_dim,
 };
-use std::{fmt::Debug, ops::Deref};
+use std::{cell::RefCell, fmt::Debug, ops::Deref};
 
 /// Matrix multiplication. If dimension is 2 or less, it acts as a normal dot product. For
 /// multi-dimensional array, compute a batched matrix multiplication. **Caution**: For any matrix multiplication with differing batch dimension, you need to reshape / broadcast
@@ -13,6 +16,13 @@ pub fn matmul_compute_tensor_tensor<T>(lhs_tensor: &Tensor<T>, rhs_tensor: &Tens
 where
     T: DTComp + Clone + Debug + LinalgScalar,
 {
+    // half precision products are summed in f32
+    if let Some(result_tensor) = accumulate_in_f32(&[lhs_tensor, rhs_tensor], |widened| {
+        matmul_compute_tensor_tensor(&widened[0], &widened[1])
+    }) {
+        return result_tensor;
+    }
+
     if lhs_tensor.get_shape().len() <= 2 && rhs_tensor.get_shape().len() <= 2 {
         return dot_compute_tensor_tensor(lhs_tensor, rhs_tensor);
     }
@@ -37,6 +47,19 @@ where
         );
     }
 
+    let batch_dims = lhs_shape.len() - 2;
+    let batch_matches = lhs_shape[..batch_dims]
+        .iter()
+        .zip(rhs_shape[..batch_dims].iter())
+        .all(|(lhs_dim, rhs_dim)| lhs_dim == rhs_dim || *rhs_dim == 1);
+
+    if !batch_matches {
+        panic!(
+            "Error: Mismatching batch dimensions in batch matmul, the batch dimensions of rhs must match lhs or be 1. Got {:?} vs {:?}",
+            lhs_shape, rhs_shape
+        );
+    }
+
     let lhs_raw_array = lhs_tensor.get_raw_data();
     let rhs_raw_array = rhs_tensor.get_raw_data();
 
@@ -118,9 +141,7 @@ where
         (1, 1) => {
             let lhs = lhs_tensor.get_raw_data_as_ix1();
             let rhs = rhs_tensor.get_raw_data_as_ix1();
-            let scalar = lhs.dot(&rhs);
-            ArrayD::from_shape_vec(vec![1], vec![scalar])
-                .expect("Error: Failed to wrap scalar dot product in shape [1]")
+            ArrayD::from_elem(vec![], lhs.dot(&rhs))
         }
         _ => panic!("Error: dot_compute_tensor_tensor called with tensors of rank > 2"),
     };
@@ -129,6 +150,89 @@ where
     return tensor;
 }
 
+/// Gradient of a dot product with respect to input `input_nr`, given the other input. Inputs have
+/// at most 2 dimensions, a 1D lhs is promoted to a row and a 1D rhs to a column, so the gradient is
+/// computed as for matrices and reshaped back to the shape of the input.
+pub fn dot_grad_compute<T>(
+    upstream_gradient: &Tensor<T>,
+    other_ref: &RefCell<TensorImpl<T>>,
+    input_nr: usize,
+) -> Tensor<T>
+where
+    T: DTComp + Clone + Debug + LinalgScalar,
+{
+    let other_ref = other_ref.borrow();
+    let other_shape = other_ref.get_raw_shape();
+    let upstream_shape = upstream_gradient.get_shape().clone();
+
+    // every 1D input removes a dimension from the output
+    let input_rank = upstream_shape.len() + 2 - other_shape.len();
+    if other_shape.is_empty() || !(1..=2).contains(&input_rank) {
+        panic!(
+            "Error: Cannot compute the gradient of a dot product with output of shape {:?} and other input of shape {:?}",
+            upstream_shape, other_shape
+        );
+    }
+
+    let as_matrix = |data: &ArrayD<T>, shape: [usize; 2]| {
+        let matrix = data
+            .as_standard_layout()
+            .into_owned()
+            .into_shape_with_order(shape.to_vec())
+            .expect("Error: Failed to promote a dot product operand to a matrix");
+        return Tensor::from_raw_array(matrix, false);
+    };
+
+    let upstream_data = upstream_gradient.get_raw_data().to_owned();
+    let other_data = other_ref.get_raw_data_().to_owned();
+
+    let (grad_matrix, input_shape) = if input_nr == 0 {
+        let n = other_shape[0];
+        let p = if other_shape.len() == 2 {
+            other_shape[1]
+        } else {
+            1
+        };
+        let m = if input_rank == 2 {
+            upstream_shape[0]
+        } else {
+            1
+        };
+
+        let other_transposed = as_matrix(&other_data.reversed_axes(), [p, n]);
+        let upstream_matrix = as_matrix(&upstream_data, [m, p]);
+        let input_shape = if input_rank == 2 { vec![m, n] } else { vec![n] };
+
+        (
+            matmul_compute_tensor_tensor(&upstream_matrix, &other_transposed),
+            input_shape,
+        )
+    } else {
+        let n = other_shape[other_shape.len() - 1];
+        let m = if other_shape.len() == 2 {
+            other_shape[0]
+        } else {
+            1
+        };
+        let p = if input_rank == 2 {
+            upstream_shape[upstream_shape.len() - 1]
+        } else {
+            1
+        };
+
+        let other_transposed = as_matrix(&other_data.reversed_axes(), [n, m]);
+        let upstream_matrix = as_matrix(&upstream_data, [m, p]);
+        let input_shape = if input_rank == 2 { vec![n, p] } else { vec![n] };
+
+        (
+            matmul_compute_tensor_tensor(&other_transposed, &upstream_matrix),
+            input_shape,
+        )
+    };
+
+    return compute_reshape(&grad_matrix, input_shape);
+}
+
 #[cfg(test)]
 pub mod test {
     #[allow(unused)]
@@ -143,7 +247,7 @@ pub mod test {
 
         assert_eq!(
             z.get_shape().len(),
-            1usize,
+            0usize,
             "Error: Output shape from tensor with 1 dimension has to result in a scalar"
         );
 
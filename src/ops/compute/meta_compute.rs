@@ -0,0 +1,573 @@
+use ndarray::{ArrayD, Axis, SliceInfoElem};
+use std::fmt::Debug;
+use std::ops::Deref;
+
+use crate::ops::compute::logical_compute::{broadcast_shape_or_panic, where_shape};
+use crate::ops::compute::scatter_compute::{
+    segment_shape, validate_index_add, validate_scatter, validate_segment_ids,
+};
+use crate::ops::compute::shape_compute::compute_broadcast;
+use crate::tensor_core::dtypes::DTComp;
+use crate::tensor_core::tensor::Tensor;
+use crate::utils::shaping_utils::{broadcast_shapes, get_last_2_dim};
+
+// Shape inference for meta tensors. Every function here mirrors the shape rules and validation of
+// its compute counterpart, but only produces a meta tensor of the resulting shape.
+
+pub fn any_meta<T>(tensor_list: &[&Tensor<T>]) -> bool
+where
+    T: DTComp + Debug,
+{
+    return tensor_list.iter().any(|tensor| tensor.is_meta());
+}
+
+/// Result of any element wise operation on a single tensor
+pub fn meta_compute_like<T>(tensor: &Tensor<T>) -> Tensor<T>
+where
+    T: DTComp + Debug,
+{
+    return Tensor::meta(tensor.get_shape().clone(), false);
+}
+
+pub fn meta_compute_broadcast_binary<T>(lhs_tensor: &Tensor<T>, rhs_tensor: &Tensor<T>) -> Tensor<T>
+where
+    T: DTComp + Debug,
+{
+    let lhs_shape = lhs_tensor.get_shape().clone();
+    let rhs_shape = rhs_tensor.get_shape().clone();
+
+    match broadcast_shapes(&[lhs_shape.clone(), rhs_shape.clone()]) {
+        Some(shape) => {
+            return Tensor::meta(shape, false);
+        }
+        None => {
+            panic!(
+                "Error: Cannot broadcast tensors of shape {:?} and {:?} together.",
+                lhs_shape, rhs_shape
+            );
+        }
+    }
+}
+
+pub fn meta_compute_broadcast<T>(tensor: &Tensor<T>, shape: Vec<usize>) -> Tensor<T>
+where
+    T: DTComp + Debug,
+{
+    let tensor_shape = tensor.get_shape();
+
+    let broadcastable = tensor_shape.len() <= shape.len()
+        && tensor_shape
+            .iter()
+            .rev()
+            .zip(shape.iter().rev())
+            .all(|(dim, target_dim)| *dim == *target_dim || *dim == 1);
+
+    if !broadcastable {
+        panic!("Error: Cannot broadcast tensor to intended shape.");
+    }
+
+    return Tensor::meta(shape, false);
+}
+
+/// Result of reducing a tensor along a dimension, as in sum, mean, max and min
+pub fn meta_compute_reduce<T, U>(tensor: &Tensor<T>, dim: Axis) -> Tensor<U>
+where
+    T: DTComp + Debug,
+    U: DTComp + Debug,
+{
+    let mut shape = tensor.get_shape().clone();
+
+    if dim.index() >= shape.len() {
+        panic!(
+            "Error: Cannot reduce along dimension {} of a tensor with shape {:?}",
+            dim.index(),
+            shape
+        );
+    }
+
+    shape.remove(dim.index());
+
+    return Tensor::meta(shape, false);
+}
+
+pub fn meta_compute_matmul<T>(lhs_tensor: &Tensor<T>, rhs_tensor: &Tensor<T>) -> Tensor<T>
+where
+    T: DTComp + Debug,
+{
+    let lhs_shape = lhs_tensor.get_shape();
+    let rhs_shape = rhs_tensor.get_shape();
+
+    if lhs_shape.len() <= 2 && rhs_shape.len() <= 2 {
+        return meta_compute_dot(lhs_tensor, rhs_tensor);
+    }
+
+    if lhs_shape.len() != rhs_shape.len() {
+        panic!(
+            "Error: Batch matmul only supports tensors with the same number of dimensions. Got {:?} vs {:?}",
+            lhs_shape, rhs_shape
+        );
+    }
+
+    let lhs_last_2_dim = get_last_2_dim(lhs_shape.deref());
+    let rhs_last_2_dim = get_last_2_dim(rhs_shape.deref());
+
+    if lhs_last_2_dim.1 != rhs_last_2_dim.0 {
+        panic!(
+            "Error: Mismatching shape in dot product, lhs receive last 2 dim of shape: {:?} yet rhs receive last 2 dim of shape: {:?}",
+            lhs_last_2_dim, rhs_last_2_dim
+        );
+    }
+
+    let batch_dims = lhs_shape.len() - 2;
+    let batch_matches = lhs_shape[..batch_dims]
+        .iter()
+        .zip(rhs_shape[..batch_dims].iter())
+        .all(|(lhs_dim, rhs_dim)| lhs_dim == rhs_dim || *rhs_dim == 1);
+
+    if !batch_matches {
+        panic!(
+            "Error: Mismatching batch dimensions in batch matmul, the batch dimensions of rhs must match lhs or be 1. Got {:?} vs {:?}",
+            lhs_shape, rhs_shape
+        );
+    }
+
+    let mut shape = lhs_shape[..batch_dims].to_vec();
+    shape.extend(vec![lhs_last_2_dim.0, rhs_last_2_dim.1]);
+
+    return Tensor::meta(shape, false);
+}
+
+pub fn meta_compute_dot<T>(lhs_tensor: &Tensor<T>, rhs_tensor: &Tensor<T>) -> Tensor<T>
+where
+    T: DTComp + Debug,
+{
+    let lhs_shape = lhs_tensor.get_shape().clone();
+    let rhs_shape = rhs_tensor.get_shape().clone();
+
+    let (inner_dims, shape) = match (lhs_shape.len(), rhs_shape.len()) {
+        (2, 2) => (
+            (lhs_shape[1], rhs_shape[0]),
+            vec![lhs_shape[0], rhs_shape[1]],
+        ),
+        (2, 1) => ((lhs_shape[1], rhs_shape[0]), vec![lhs_shape[0]]),
+        (1, 2) => ((lhs_shape[0], rhs_shape[0]), vec![rhs_shape[1]]),
+        (1, 1) => ((lhs_shape[0], rhs_shape[0]), vec![]),
+        _ => panic!("Error: dot product called with tensors of rank > 2"),
+    };
+
+    if inner_dims.0 != inner_dims.1 {
+        panic!(
+            "Error: Mismatching shape in dot product, lhs has shape {:?} yet rhs has shape {:?}",
+            lhs_shape, rhs_shape
+        );
+    }
+
+    return Tensor::meta(shape, false);
+}
+
+pub fn meta_compute_concat<T>(tensor_list: &[&Tensor<T>], dim: Axis) -> Tensor<T>
+where
+    T: DTComp + Debug,
+{
+    if tensor_list.is_empty() {
+        panic!("Tensor Error: Cannot concatenate an empty list of tensors.");
+    }
+
+    let mut shape = tensor_list[0].get_shape().clone();
+
+    if dim.index() >= shape.len() {
+        panic!(
+            "Tensor Error: Cannot concatenate along dimension {} of tensors with shape {:?}",
+            dim.index(),
+            shape
+        );
+    }
+
+    for tensor in &tensor_list[1..] {
+        let other_shape = tensor.get_shape();
+
+        let compatible = other_shape.len() == shape.len()
+            && other_shape
+                .iter()
+                .zip(shape.iter())
+                .enumerate()
+                .all(|(i, (a, b))| i == dim.index() || a == b);
+
+        if !compatible {
+            panic!(
+                "Tensor Error: Cannot concatenate tensors of shape {:?} and {:?} along dimension {}",
+                shape,
+                other_shape,
+                dim.index()
+            );
+        }
+
+        shape[dim.index()] += other_shape[dim.index()];
+    }
+
+    return Tensor::meta(shape, false);
+}
+
+pub fn meta_compute_stack<T>(tensor_list: &[&Tensor<T>], dim: Axis) -> Tensor<T>
+where
+    T: DTComp + Debug,
+{
+    if tensor_list.is_empty() {
+        panic!("Tensor Error: Cannot stack an empty list of tensors.");
+    }
+
+    let mut shape = tensor_list[0].get_shape().clone();
+
+    for tensor in &tensor_list[1..] {
+        if *tensor.get_shape() != shape {
+            panic!(
+                "Tensor Error: Cannot stack tensors of shape {:?} and {:?}",
+                shape,
+                tensor.get_shape()
+            );
+        }
+    }
+
+    if dim.index() > shape.len() {
+        panic!(
+            "Tensor Error: Cannot stack along dimension {} of tensors with shape {:?}",
+            dim.index(),
+            shape
+        );
+    }
+
+    shape.insert(dim.index(), tensor_list.len());
+
+    return Tensor::meta(shape, false);
+}
+
+pub fn meta_compute_reshape<T>(tensor: &Tensor<T>, shape: Vec<usize>) -> Tensor<T>
+where
+    T: DTComp + Debug,
+{
+    let numel: usize = shape.iter().product();
+
+    if numel != tensor.get_numel() {
+        panic!(
+            "Error: Cannot reshape a tensor of shape {:?} into shape {:?}",
+            tensor.get_shape(),
+            shape
+        );
+    }
+
+    return Tensor::meta(shape, false);
+}
+
+pub fn meta_compute_transpose<T>(tensor: &Tensor<T>, axes_option: Option<Vec<usize>>) -> Tensor<T>
+where
+    T: DTComp + Debug,
+{
+    let shape = tensor.get_shape().clone();
+
+    if shape.len() == 1 {
+        panic!("Error, cannot transpose a 1D tensor");
+    } else if shape.len() == 2 {
+        return Tensor::meta(vec![shape[1], shape[0]], false);
+    } else {
+        match axes_option {
+            Some(axes) => {
+                let mut sorted_axes = axes.clone();
+                sorted_axes.sort();
+
+                if sorted_axes != (0..shape.len()).collect::<Vec<usize>>() {
+                    panic!(
+                        "Error: Axes {:?} are not a permutation of the dimensions of a tensor with shape {:?}",
+                        axes, shape
+                    );
+                }
+
+                let new_shape = axes.iter().map(|axis| shape[*axis]).collect();
+                return Tensor::meta(new_shape, false);
+            }
+            None => {
+                panic!(
+                    "Error: Trying to transpose a multi-dimensional tensor with no axes order provided. Try passing in the argument for axes_option."
+                );
+            }
+        }
+    }
+}
+
+pub fn meta_compute_squeeze<T>(tensor: &Tensor<T>, dim: Axis) -> Tensor<T>
+where
+    T: DTComp + Debug,
+{
+    let mut shape = tensor.get_shape().clone();
+
+    if dim.index() >= shape.len() {
+        panic!(
+            "Error: Cannot squeeze dimension {} of a tensor with shape {:?}",
+            dim.index(),
+            shape
+        );
+    }
+
+    shape.remove(dim.index());
+
+    return Tensor::meta(shape, false);
+}
+
+pub fn meta_compute_unsqueeze<T>(tensor: &Tensor<T>, dim: Axis) -> Tensor<T>
+where
+    T: DTComp + Debug,
+{
+    let mut shape = tensor.get_shape().clone();
+
+    if dim.index() > shape.len() {
+        panic!(
+            "Error: Cannot unsqueeze dimension {} of a tensor with shape {:?}",
+            dim.index(),
+            shape
+        );
+    }
+
+    shape.insert(dim.index(), 1);
+
+    return Tensor::meta(shape, false);
+}
+
+pub fn meta_compute_slice<T>(tensor: &Tensor<T>, info: &[SliceInfoElem]) -> Tensor<T>
+where
+    T: DTComp + Debug,
+{
+    // slicing an array of zero sized elements validates the info without allocating anything
+    let shape = ArrayD::from_elem(tensor.get_shape().clone(), ())
+        .slice(info)
+        .shape()
+        .to_vec();
+
+    return Tensor::meta(shape, false);
+}
+
+pub fn meta_compute_index_select<T>(
+    tensor: &Tensor<T>,
+    dim: Axis,
+    indices: &Tensor<usize>,
+) -> Tensor<T>
+where
+    T: DTComp + Debug,
+{
+    let mut shape = tensor.get_shape().clone();
+    let index_shape = indices.get_shape().clone();
+
+    if index_shape.len() != 1 || dim.index() >= shape.len() {
+        panic!(
+            "Error: Cannot index select along dimension {} of a tensor with shape {:?} using indices of shape {:?}",
+            dim.index(),
+            shape,
+            index_shape
+        );
+    }
+
+    shape[dim.index()] = index_shape[0];
+
+    return Tensor::meta(shape, false);
+}
+
+pub fn meta_compute_gather<T>(tensor: &Tensor<T>, dim: Axis, indices: &Tensor<usize>) -> Tensor<T>
+where
+    T: DTComp + Debug,
+{
+    let shape = tensor.get_shape().clone();
+    let index_shape = indices.get_shape().clone();
+
+    let gatherable = dim.index() < shape.len()
+        && index_shape.len() == shape.len()
+        && index_shape
+            .iter()
+            .zip(shape.iter())
+            .enumerate()
+            .all(|(axis, (index_dim, dim_len))| axis == dim.index() || index_dim <= dim_len);
+
+    if !gatherable {
+        panic!(
+            "Error: Cannot gather along dimension {} of a tensor with shape {:?} using indices of shape {:?}",
+            dim.index(),
+            shape,
+            index_shape
+        );
+    }
+
+    return Tensor::meta(index_shape, false);
+}
+
+/// The length of a masked selection depends on the values of the mask, so only a mask with data
+/// can be used on a meta tensor
+pub fn meta_compute_masked_select<T>(tensor: &Tensor<T>, mask: &Tensor<bool>) -> Tensor<T>
+where
+    T: DTComp + Debug,
+{
+    if mask.is_meta() {
+        panic!(
+            "Error: Cannot infer the shape of a masked select with a meta mask, the result depends on the values of the mask."
+        );
+    }
+
+    let mask = compute_broadcast(mask, tensor.get_shape().clone());
+    let length = mask
+        .get_raw_data()
+        .iter()
+        .filter(|selected| **selected)
+        .count();
+
+    return Tensor::meta(vec![length], false);
+}
+
+pub fn meta_compute_scatter_add<T>(
+    tensor: &Tensor<T>,
+    dim: Axis,
+    indices: &Tensor<usize>,
+    src: &Tensor<T>,
+) -> Tensor<T>
+where
+    T: DTComp + Debug,
+{
+    validate_scatter(&tensor.get_shape(), dim, indices, &src.get_shape());
+
+    return Tensor::meta(tensor.get_shape().clone(), false);
+}
+
+pub fn meta_compute_index_add<T>(
+    tensor: &Tensor<T>,
+    dim: Axis,
+    indices: &Tensor<usize>,
+    src: &Tensor<T>,
+) -> Tensor<T>
+where
+    T: DTComp + Debug,
+{
+    validate_index_add(&tensor.get_shape(), dim, indices, &src.get_shape());
+
+    return Tensor::meta(tensor.get_shape().clone(), false);
+}
+
+/// Result of reducing the rows of a tensor into segments, as in segment sum, mean and max
+pub fn meta_compute_segment<T, U>(
+    tensor: &Tensor<T>,
+    segment_ids: &Tensor<usize>,
+    num_segments: usize,
+) -> Tensor<U>
+where
+    T: DTComp + Debug,
+    U: DTComp + Debug,
+{
+    let shape = tensor.get_shape().clone();
+    validate_segment_ids(&shape, segment_ids, num_segments);
+
+    return Tensor::meta(segment_shape(&shape, num_segments), false);
+}
+
+/// Result of comparing two tensors or combining two boolean tensors element wise
+pub fn meta_compute_compare<T>(lhs_tensor: &Tensor<T>, rhs_tensor: &Tensor<T>) -> Tensor<bool>
+where
+    T: DTComp + Debug,
+{
+    let shape = broadcast_shape_or_panic(&[
+        lhs_tensor.get_shape().clone(),
+        rhs_tensor.get_shape().clone(),
+    ]);
+
+    return Tensor::meta(shape, false);
+}
+
+pub fn meta_compute_where<T>(
+    condition: &Tensor<bool>,
+    lhs_tensor: &Tensor<T>,
+    rhs_tensor: &Tensor<T>,
+) -> Tensor<T>
+where
+    T: DTComp + Debug,
+{
+    return Tensor::meta(where_shape(condition, lhs_tensor, rhs_tensor), false);
+}
+
+pub fn meta_compute_masked_fill<T>(tensor: &Tensor<T>, mask: &Tensor<bool>) -> Tensor<T>
+where
+    T: DTComp + Debug,
+{
+    meta_compute_broadcast(mask, tensor.get_shape().clone());
+
+    return Tensor::meta(tensor.get_shape().clone(), false);
+}
+
+#[cfg(test)]
+pub mod test {
+    #[allow(unused)]
+    use super::*;
+    use crate::ops::public_ops::concat::concat;
+    use crate::ops::public_ops::matmul::matmul;
+
+    #[test]
+    fn meta_ops_infer_shapes_and_build_graph() {
+        let x = Tensor::<f32>::meta(vec![64, 1, 512], true);
+        let w = Tensor::<f32>::meta(vec![64, 512, 1024], true);
+        let b = Tensor::<f32>::meta(vec![1024], false);
+
+        let h = (&matmul(&x, &w) + &b).tanh();
+        assert!(h.is_meta());
+        assert_eq!(*h.get_shape(), vec![64, 1, 1024]);
+        assert_eq!(h.get_grad_fn().borrow().get_name(), "TanhBackward");
+
+        let h = h.squeeze(Axis(1)).transpose(None).reshape(vec![1024, 8, 8]);
+        assert_eq!(*h.get_shape(), vec![1024, 8, 8]);
+
+        let h = concat(&[&h, &h], Axis(2)).sum(Axis(0)).max(Axis(1));
+        assert_eq!(*h.get_shape(), vec![8]);
+        assert_eq!(h.get_storage().get_nbytes(), 8 * 4);
+
+        // the graph of a meta tensor can still be planned, only shapes are needed for it
+        let report = h.plan_backward().memory_report();
+        assert!(report.gradient_bytes > 0);
+    }
+
+    #[test]
+    fn meta_indexing_ops_infer_shapes() {
+        let x = Tensor::<f32>::meta(vec![6, 5, 4], true);
+        let indices = Tensor::<usize>::meta(vec![3], false);
+        let mask = Tensor::new(vec![true, false, true, true], vec![4], false);
+
+        assert_eq!(*x.slice(crate::ts![1..;2, .., 2]).get_shape(), vec![3, 5]);
+        assert_eq!(*x.select(Axis(1), 4).get_shape(), vec![6, 4]);
+        assert_eq!(
+            *x.index_select(Axis(2), &indices).get_shape(),
+            vec![6, 5, 3]
+        );
+        assert_eq!(*x.masked_select(&mask).get_shape(), vec![90]);
+
+        let gather_indices = Tensor::<usize>::meta(vec![6, 2, 4], false);
+        let h = x.gather(Axis(1), &gather_indices);
+        assert_eq!(*h.get_shape(), vec![6, 2, 4]);
+        assert_eq!(h.get_grad_fn().borrow().get_name(), "GatherBackward");
+    }
+
+    #[test]
+    #[should_panic]
+    fn meta_ops_validate_shapes() {
+        let x = Tensor::<f32>::meta(vec![4, 3], false);
+        let y = Tensor::<f32>::meta(vec![4, 3], false);
+
+        matmul(&x, &y);
+    }
+
+    #[test]
+    fn meta_batch_matmul_broadcasts_a_batch_of_one() {
+        let x = Tensor::<f32>::meta(vec![2, 3, 4], false);
+        let w = Tensor::<f32>::meta(vec![1, 4, 6], false);
+
+        assert_eq!(*matmul(&x, &w).get_shape(), vec![2, 3, 6]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Mismatching batch dimensions")]
+    fn meta_batch_matmul_validates_batch_dims() {
+        let x = Tensor::<f32>::meta(vec![2, 3, 4], false);
+        let y = Tensor::<f32>::meta(vec![5, 4, 6], false);
+
+        matmul(&x, &y);
+    }
+}
@@ -1,8 +1,9 @@
 use ndarray::ScalarOperand;
-use std::cell::{Ref, RefCell};
+use std::cell::RefCell;
 use std::fmt::Debug;
-use std::ops::{Deref, Mul};
+use std::ops::Mul;
 
+use crate::tensor_core::buffer_pool;
 use crate::tensor_core::dtypes::DTComp;
 use crate::tensor_core::tensor::Tensor;
 use crate::tensor_core::tensor_impl::TensorImpl;
@@ -14,7 +15,7 @@ where
     let x_raw = lhs_scalar.get_raw_data();
     let y_raw = rhs_tensor.get_raw_data();
 
-    let new_raw = x_raw.deref() * y_raw.deref();
+    let new_raw = buffer_pool::zip_map(x_raw.view(), y_raw.view(), |a, b| a.clone() * b.clone());
     let tensor = Tensor::from_raw_array(new_raw, false);
 
     return tensor;
@@ -27,10 +28,13 @@ pub fn mul_compute_tensorimpl_tensorimpl<T>(
 where
     T: DTComp + Clone + Debug + Mul<Output = T>,
 {
-    let lhs_raw = Ref::map(lhs_tensorimpl.borrow(), |x| x.get_raw_data_());
-    let rhs_raw = Ref::map(rhs_tensorimpl.borrow(), |x| x.get_raw_data_());
+    let lhs_impl = lhs_tensorimpl.borrow();
+    let lhs_raw = lhs_impl.get_raw_data_();
+    let rhs_impl = rhs_tensorimpl.borrow();
+    let rhs_raw = rhs_impl.get_raw_data_();
 
-    let new_raw = rhs_raw.deref() * lhs_raw.deref();
+    let new_raw =
+        buffer_pool::zip_map(rhs_raw.view(), lhs_raw.view(), |a, b| a.clone() * b.clone());
     let tensor = Tensor::from_raw_array(new_raw, false);
 
     return tensor;
@@ -47,7 +51,7 @@ where
     let binding = tensor_impl.borrow();
     let x_raw = binding.get_raw_data_();
 
-    let new_raw = x_raw * scalar;
+    let new_raw = &x_raw * scalar;
     let tensor = Tensor::from_raw_array(new_raw, false);
 
     return tensor;
@@ -60,7 +64,7 @@ where
 {
     let x_raw = tensor.get_raw_data();
 
-    let new_raw = x_raw.deref() * scalar;
+    let new_raw = &x_raw.view() * scalar;
     let tensor = Tensor::from_raw_array(new_raw, false);
 
     return tensor;
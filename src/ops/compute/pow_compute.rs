@@ -1,18 +1,16 @@
-use num_traits::Float;
-use std::cell::{Ref, RefCell};
+use std::cell::RefCell;
 use std::fmt::Debug;
-use std::ops::Deref;
 
-use crate::tensor_core::dtypes::DTComp;
+use crate::tensor_core::dtypes::{DTComp, DTFloat};
 use crate::tensor_core::tensor::Tensor;
 use crate::tensor_core::tensor_impl::TensorImpl;
 
 pub fn pow_compute_tensor<T>(tensor: &Tensor<T>, base: T) -> Tensor<T>
 where
-    T: DTComp + Clone + Debug + Float + 'static,
+    T: DTComp + Clone + Debug + DTFloat + 'static,
 {
     let x_raw = tensor.get_raw_data();
-    let raw_array = x_raw.mapv(|elem| elem.powf(base));
+    let raw_array = x_raw.mapv(|elem| elem.elem_pow(base));
 
     let tensor = Tensor::from_raw_array(raw_array, false);
 
@@ -21,10 +19,11 @@ where
 
 pub fn pow_compute_tensorimpl<T>(tensorimpl: &RefCell<TensorImpl<T>>, base: T) -> Tensor<T>
 where
-    T: DTComp + Clone + Debug + Float + 'static,
+    T: DTComp + Clone + Debug + DTFloat + 'static,
 {
-    let x_raw = Ref::map(tensorimpl.borrow(), |x| x.get_raw_data_());
-    let raw_array = x_raw.deref().mapv(|elem| elem.powf(base));
+    let x_impl = tensorimpl.borrow();
+    let x_raw = x_impl.get_raw_data_();
+    let raw_array = x_raw.view().mapv(|elem| elem.elem_pow(base));
 
     let tensor = Tensor::from_raw_array(raw_array, false);
 
@@ -0,0 +1,292 @@
+use ndarray::{ArrayD, Axis, Dimension, IxDyn};
+use num_traits::{NumCast, Zero};
+
+use crate::tensor_core::{dtypes::DTComp, tensor::Tensor};
+use std::fmt::Debug;
+use std::ops::{Add, Div};
+
+// Scatter operations write the elements of a source tensor into the positions given by an index,
+// summing the elements that land on the same position. Segment reductions are the special case
+// of reducing the rows of a tensor into `num_segments` rows, where row `i` goes to segment
+// `segment_ids[i]`.
+
+pub fn validate_scatter(shape: &[usize], dim: Axis, indices: &Tensor<usize>, src_shape: &[usize]) {
+    let index_shape = indices.get_shape().clone();
+
+    let scatterable = dim.index() < shape.len()
+        && index_shape == src_shape
+        && index_shape.len() == shape.len()
+        && index_shape
+            .iter()
+            .zip(shape.iter())
+            .enumerate()
+            .all(|(axis, (index_dim, dim_len))| axis == dim.index() || index_dim <= dim_len);
+
+    if !scatterable {
+        panic!(
+            "Error: Cannot scatter a source of shape {:?} with indices of shape {:?} along dimension {} of a tensor with shape {:?}",
+            src_shape,
+            index_shape,
+            dim.index(),
+            shape
+        );
+    }
+}
+
+pub fn validate_index_add(
+    shape: &[usize],
+    dim: Axis,
+    indices: &Tensor<usize>,
+    src_shape: &[usize],
+) {
+    let index_shape = indices.get_shape().clone();
+
+    let addable = dim.index() < shape.len()
+        && index_shape.len() == 1
+        && src_shape.len() == shape.len()
+        && src_shape
+            .iter()
+            .zip(shape.iter())
+            .enumerate()
+            .all(|(axis, (src_dim, dim_len))| {
+                if axis == dim.index() {
+                    *src_dim == index_shape[0]
+                } else {
+                    src_dim == dim_len
+                }
+            });
+
+    if !addable {
+        panic!(
+            "Error: Cannot index add a source of shape {:?} with indices of shape {:?} along dimension {} of a tensor with shape {:?}",
+            src_shape,
+            index_shape,
+            dim.index(),
+            shape
+        );
+    }
+}
+
+/// Checks that there is one segment id for every row of the data, and returns the ids
+pub fn validate_segment_ids(
+    shape: &[usize],
+    segment_ids: &Tensor<usize>,
+    num_segments: usize,
+) -> Vec<usize> {
+    if shape.is_empty() || *segment_ids.get_shape() != vec![shape[0]] {
+        panic!(
+            "Error: Expected one segment id for every row of a tensor with shape {:?}, got segment ids of shape {:?}",
+            shape,
+            segment_ids.get_shape()
+        );
+    }
+
+    if segment_ids.is_meta() {
+        return vec![];
+    }
+
+    let segment_ids = segment_ids
+        .get_raw_data()
+        .iter()
+        .copied()
+        .collect::<Vec<usize>>();
+
+    if let Some(segment_id) = segment_ids.iter().find(|id| **id >= num_segments) {
+        panic!(
+            "Error: Segment id {} is out of range for {} segments",
+            segment_id, num_segments
+        );
+    }
+
+    return segment_ids;
+}
+
+/// Shape of the result of a segment reduction
+pub fn segment_shape(shape: &[usize], num_segments: usize) -> Vec<usize> {
+    let mut res_shape = shape.to_vec();
+    res_shape[0] = num_segments;
+
+    return res_shape;
+}
+
+/// Number of rows in every segment
+pub fn segment_counts(segment_ids: &[usize], num_segments: usize) -> Vec<usize> {
+    let mut counts = vec![0; num_segments];
+
+    for segment_id in segment_ids.iter() {
+        counts[*segment_id] += 1;
+    }
+
+    return counts;
+}
+
+pub fn compute_scatter_add<T>(
+    tensor: &Tensor<T>,
+    dim: Axis,
+    indices: &Tensor<usize>,
+    src: &Tensor<T>,
+) -> Tensor<T>
+where
+    T: DTComp + Debug + Clone + Add<Output = T>,
+{
+    let shape = tensor.get_shape().clone();
+    validate_scatter(&shape, dim, indices, &src.get_shape());
+
+    let mut res_array = tensor.get_raw_data().to_owned();
+    let indices_raw_data = indices.get_raw_data();
+
+    for ((position, &index), x) in indices_raw_data
+        .indexed_iter()
+        .zip(src.get_raw_data().iter())
+    {
+        if index >= shape[dim.index()] {
+            panic!(
+                "Error: Index {} is out of range for dimension {} of a tensor with shape {:?}",
+                index,
+                dim.index(),
+                shape
+            );
+        }
+
+        let mut full_index = position.as_array_view().to_vec();
+        full_index[dim.index()] = index;
+
+        let res = &mut res_array[IxDyn(&full_index)];
+        *res = res.clone() + x.clone();
+    }
+
+    return Tensor::from_raw_array(res_array, false);
+}
+
+pub fn compute_index_add<T>(
+    tensor: &Tensor<T>,
+    dim: Axis,
+    indices: &Tensor<usize>,
+    src: &Tensor<T>,
+) -> Tensor<T>
+where
+    T: DTComp + Debug + Clone + Add<Output = T>,
+{
+    let shape = tensor.get_shape().clone();
+    validate_index_add(&shape, dim, indices, &src.get_shape());
+
+    let mut res_array = tensor.get_raw_data().to_owned();
+    let src_raw_data = src.get_raw_data();
+
+    for (i, &index) in indices.get_raw_data().iter().enumerate() {
+        if index >= shape[dim.index()] {
+            panic!(
+                "Error: Index {} is out of range for dimension {} of a tensor with shape {:?}",
+                index,
+                dim.index(),
+                shape
+            );
+        }
+
+        res_array.index_axis_mut(dim, index).zip_mut_with(
+            &src_raw_data.index_axis(dim, i),
+            |res, x| {
+                *res = res.clone() + x.clone();
+            },
+        );
+    }
+
+    return Tensor::from_raw_array(res_array, false);
+}
+
+pub fn compute_segment_sum<T>(
+    tensor: &Tensor<T>,
+    segment_ids: &Tensor<usize>,
+    num_segments: usize,
+) -> Tensor<T>
+where
+    T: DTComp + Debug + Clone + Add<Output = T> + Zero,
+{
+    let shape = tensor.get_shape().clone();
+    let segment_ids = validate_segment_ids(&shape, segment_ids, num_segments);
+
+    let mut res_array = ArrayD::<T>::zeros(segment_shape(&shape, num_segments));
+
+    for (row, segment_id) in tensor.get_raw_data().axis_iter(Axis(0)).zip(segment_ids) {
+        res_array
+            .index_axis_mut(Axis(0), segment_id)
+            .zip_mut_with(&row, |res, x| {
+                *res = res.clone() + x.clone();
+            });
+    }
+
+    return Tensor::from_raw_array(res_array, false);
+}
+
+/// Segments without any row have a mean of zero
+pub fn compute_segment_mean<T>(
+    tensor: &Tensor<T>,
+    segment_ids: &Tensor<usize>,
+    num_segments: usize,
+) -> Tensor<T>
+where
+    T: DTComp + Debug + Clone + Add<Output = T> + Zero + Div<Output = T> + NumCast,
+{
+    let sum_tensor = compute_segment_sum(tensor, segment_ids, num_segments);
+    let counts = segment_counts(
+        &validate_segment_ids(&tensor.get_shape(), segment_ids, num_segments),
+        num_segments,
+    );
+
+    let mut res_array = sum_tensor.get_raw_data().to_owned();
+
+    for (mut segment, count) in res_array.axis_iter_mut(Axis(0)).zip(counts) {
+        if count > 0 {
+            let count = T::from(count)
+                .expect("Error: Could not convert segment length to scalar type for segment mean");
+            segment.mapv_inplace(|x| x / count.clone());
+        }
+    }
+
+    return Tensor::from_raw_array(res_array, false);
+}
+
+/// Returns the row of the maximum of every element in each segment, along with the maximums.
+/// Segments without any row have a maximum of zero and an index equal to the number of rows.
+pub fn compute_segment_max<T>(
+    tensor: &Tensor<T>,
+    segment_ids: &Tensor<usize>,
+    num_segments: usize,
+) -> (Tensor<usize>, Tensor<T>)
+where
+    T: DTComp + Debug + Clone + PartialOrd + Zero,
+{
+    let shape = tensor.get_shape().clone();
+    let segment_ids = validate_segment_ids(&shape, segment_ids, num_segments);
+    let res_shape = segment_shape(&shape, num_segments);
+
+    let mut res_array = ArrayD::<T>::zeros(res_shape.clone());
+    let mut indices_array = ArrayD::<usize>::from_elem(res_shape, shape[0]);
+
+    for (i, (row, segment_id)) in tensor
+        .get_raw_data()
+        .axis_iter(Axis(0))
+        .zip(segment_ids)
+        .enumerate()
+    {
+        let mut res_segment = res_array.index_axis_mut(Axis(0), segment_id);
+        let mut indices_segment = indices_array.index_axis_mut(Axis(0), segment_id);
+
+        for ((res, index), x) in res_segment
+            .iter_mut()
+            .zip(indices_segment.iter_mut())
+            .zip(row.iter())
+        {
+            // the first row of a segment always replaces the placeholder zero
+            if *index == shape[0] || *x > *res {
+                *res = x.clone();
+                *index = i;
+            }
+        }
+    }
+
+    return (
+        Tensor::from_raw_array(indices_array, false),
+        Tensor::from_raw_array(res_array, false),
+    );
+}
@@ -1,119 +1,176 @@
-use ndarray::{Axis, ShapeArg};
+use ndarray::{Axis, SliceInfoElem};
 
 use crate::tensor_core::{dtypes::DTComp, tensor::Tensor, tensor_impl::TensorImpl};
 use std::{cell::RefCell, fmt::Debug};
 
+// Shape operations return views sharing the buffer of their input whenever the new layout can be
+// expressed with strides, and only copy when it cannot, as in reshaping a non contiguous tensor.
+
 pub fn compute_broadcast<T>(tensor: &Tensor<T>, shape: Vec<usize>) -> Tensor<T>
 where
     T: DTComp + Debug + Clone,
 {
-    let raw_array = tensor.get_raw_data();
-    let new_array_option = raw_array.broadcast(shape);
+    let tensor_shape = tensor.get_shape().clone();
 
-    match new_array_option {
-        Some(new_array) => {
-            let new_tensor = Tensor::from_raw_array(new_array.to_owned(), false);
+    let broadcastable = tensor_shape.len() <= shape.len()
+        && tensor_shape
+            .iter()
+            .rev()
+            .zip(shape.iter().rev())
+            .all(|(dim, target_dim)| *dim == *target_dim || *dim == 1);
 
-            return new_tensor;
-        }
-        None => {
-            panic!("Error: Cannot broadcast the tensor to the intended shape.");
-        }
+    if !broadcastable {
+        panic!("Error: Cannot broadcast the tensor to the intended shape.");
     }
+
+    // the storage gets a leading axis of length 1 for each new dimension, which the tensor then
+    // broadcasts along with every other dimension of length 1
+    let new_dims = shape.len() - tensor_shape.len();
+    let tensor_impl = tensor
+        .__get_tensor_impl()
+        .borrow()
+        .view_(shape, |mut data| {
+            for _ in 0..new_dims {
+                data.insert_axis_inplace(Axis(0));
+            }
+
+            data
+        });
+
+    return Tensor::from_tensor_impl(tensor_impl);
 }
 
 pub fn compute_unsqueeze<T>(tensor: &Tensor<T>, dim: Axis) -> Tensor<T>
 where
     T: DTComp + Debug + Clone,
 {
-    let raw_array = tensor.get_raw_data().to_owned();
-    let res_array = raw_array.insert_axis(dim);
+    let mut shape = tensor.get_shape().clone();
+    shape.insert(dim.index(), 1);
 
-    let tensor = Tensor::from_raw_array(res_array, false);
+    let tensor_impl = tensor
+        .__get_tensor_impl()
+        .borrow()
+        .view_(shape, |data| data.insert_axis(dim));
 
-    return tensor;
+    return Tensor::from_tensor_impl(tensor_impl);
 }
 
 pub fn compute_squeeze<T>(tensor: &Tensor<T>, dim: Axis) -> Tensor<T>
 where
     T: DTComp + Debug + Clone,
 {
-    let raw_array = tensor.get_raw_data().to_owned();
-    let res_array = raw_array.remove_axis(dim);
+    let mut shape = tensor.get_shape().clone();
+    shape.remove(dim.index());
 
-    let tensor = Tensor::from_raw_array(res_array, false);
+    let tensor_impl = tensor
+        .__get_tensor_impl()
+        .borrow()
+        .view_(shape, |data| data.remove_axis(dim));
 
-    return tensor;
+    return Tensor::from_tensor_impl(tensor_impl);
 }
 
-pub fn compute_reshape<T, E>(tensor: &Tensor<T>, shape: E) -> Tensor<T>
+pub fn compute_reshape<T>(tensor: &Tensor<T>, shape: Vec<usize>) -> Tensor<T>
 where
     T: DTComp + Debug + Clone,
-    E: ShapeArg + Debug + Clone,
 {
-    let raw_array = tensor.get_raw_data().to_owned();
-    let res_array_result = raw_array.into_shape_with_order(shape);
+    return compute_reshape_tensorimpl(tensor.__get_tensor_impl(), shape);
+}
 
-    match res_array_result {
-        Ok(res_array) => {
-            let tensor = Tensor::from_raw_array(res_array.into_dyn(), false);
-            return tensor;
-        }
-        Err(e) => {
-            panic!("{:?}", e);
-        }
+/// Reshapes a contiguous tensor into a view, and copies any other tensor into a new contiguous one
+pub fn compute_reshape_tensorimpl<T>(
+    tensorimpl: &RefCell<TensorImpl<T>>,
+    shape: Vec<usize>,
+) -> Tensor<T>
+where
+    T: DTComp + Debug + Clone,
+{
+    let tensorimpl = tensorimpl.borrow();
+    let numel: usize = shape.iter().product();
+
+    if numel != tensorimpl.numel {
+        panic!(
+            "Error: Cannot reshape a tensor of shape {:?} into shape {:?}",
+            tensorimpl.shape, shape
+        );
+    }
+
+    if tensorimpl.is_contiguous_() {
+        let tensor_impl = tensorimpl.view_(shape.clone(), |data| {
+            data.into_shape_with_order(shape)
+                .expect("Internal error, reshaping a contiguous tensor failed")
+        });
+
+        return Tensor::from_tensor_impl(tensor_impl);
     }
+
+    let res_array = tensorimpl
+        .get_raw_data_()
+        .as_standard_layout()
+        .into_owned()
+        .into_shape_with_order(shape)
+        .expect("Internal error, reshaping a contiguous copy of a tensor failed");
+
+    return Tensor::from_raw_array(res_array, false);
 }
 
-pub fn compute_reshape_tensorimpl<T, E>(tensorimpl: &RefCell<TensorImpl<T>>, shape: E) -> Tensor<T>
+/// Returns a view of a tensor that is already contiguous and a contiguous copy of any other
+pub fn compute_contiguous<T>(tensor: &Tensor<T>) -> Tensor<T>
 where
     T: DTComp + Debug + Clone,
-    E: ShapeArg + Debug + Clone,
 {
-    let raw_array = tensorimpl.borrow().get_raw_data_().to_owned();
-    let res_array_result = raw_array.into_shape_with_order(shape);
+    let tensorimpl = tensor.__get_tensor_impl().borrow();
 
-    match res_array_result {
-        Ok(res_array) => {
-            let tensor = Tensor::from_raw_array(res_array.into_dyn(), false);
-            return tensor;
-        }
-        Err(e) => {
-            panic!("{:?}", e);
-        }
+    if tensorimpl.is_contiguous_() {
+        let tensor_impl = tensorimpl.view_(tensorimpl.shape.clone(), |data| data);
+        return Tensor::from_tensor_impl(tensor_impl);
     }
+
+    let res_array = tensorimpl.get_raw_data_().as_standard_layout().into_owned();
+    return Tensor::from_raw_array(res_array, false);
 }
 
-pub fn compute_transpose<T>(tensor: &Tensor<T>, axes_option: Option<Vec<usize>>) -> Tensor<T>
+/// Slices a tensor into a view. Broadcast dimensions stay broadcast, their single element in the
+/// storage is kept whatever part of the dimension is sliced.
+pub fn compute_slice<T>(tensor: &Tensor<T>, info: &[SliceInfoElem]) -> Tensor<T>
 where
     T: DTComp + Debug + Clone,
 {
-    let shape = tensor.get_shape();
-
-    if shape.len() == 1 {
-        panic!("Error, cannot transpose a 1D tensor");
-    } else if shape.len() == 2 {
-        let raw_array = tensor.get_raw_data_as_ix2();
-        let res_array_result = raw_array.permuted_axes((1, 0)).into_dyn();
+    let tensorimpl = tensor.__get_tensor_impl().borrow();
 
-        let tensor = Tensor::from_raw_array(res_array_result, false);
-        return tensor;
-    } else {
-        match axes_option {
-            Some(axes) => {
-                let raw_array = tensor.get_raw_data().to_owned();
-                let res_array_result = raw_array.permuted_axes(axes).into_dyn();
+    // slicing the tensor as it is read checks the slice against the broadcast shape
+    let shape = tensorimpl.get_raw_data_().slice(info).shape().to_vec();
 
-                let tensor = Tensor::from_raw_array(res_array_result, false);
-                return tensor;
+    let storage_shape = tensorimpl.get_storage_().get_raw_shape();
+    let mut dim = 0;
+    let storage_info: Vec<SliceInfoElem> = info
+        .iter()
+        .map(|elem| {
+            if let SliceInfoElem::NewAxis = elem {
+                return *elem;
             }
-            None => {
-                panic!(
-                    "Error: Trying to transpose a multi-dimensional tensor with no axes order provided. Try passing in the argument for axes_option."
-                );
-            }
-        }
-    }
+
+            let is_broadcast_dim = storage_shape[dim] != tensorimpl.shape[dim];
+            dim += 1;
+
+            return match elem {
+                SliceInfoElem::Index(_) if is_broadcast_dim => SliceInfoElem::Index(0),
+                SliceInfoElem::Slice { .. } if is_broadcast_dim => SliceInfoElem::from(..),
+                _ => *elem,
+            };
+        })
+        .collect();
+
+    let tensor_impl = tensorimpl.view_(shape, |data| data.slice_move(storage_info.as_slice()));
+
+    return Tensor::from_tensor_impl(tensor_impl);
+}
+
+pub fn compute_transpose<T>(tensor: &Tensor<T>, axes_option: Option<Vec<usize>>) -> Tensor<T>
+where
+    T: DTComp + Debug + Clone,
+{
+    return compute_transpose_tensorimpl::<T, Vec<usize>>(tensor.__get_tensor_impl(), axes_option);
 }
 
 pub fn compute_transpose_tensorimpl<T, E>(
@@ -123,30 +180,118 @@ pub fn compute_transpose_tensorimpl<T, E>(
 where
     T: DTComp + Debug + Clone,
 {
-    let shape = tensorimpl.borrow().get_raw_shape();
+    let tensorimpl = tensorimpl.borrow();
+    let shape = tensorimpl.get_raw_shape();
 
-    if shape.len() == 1 {
+    let axes = if shape.len() == 1 {
         panic!("Error, cannot transpose a 1D tensor");
     } else if shape.len() == 2 {
-        let raw_array = tensorimpl.borrow().get_raw_data_as_ix2_();
-        let res_array_result = raw_array.permuted_axes((1, 0)).into_dyn();
-
-        let tensor = Tensor::from_raw_array(res_array_result, false);
-        return tensor;
+        vec![1, 0]
     } else {
         match axes_option {
-            Some(axes) => {
-                let raw_array = tensorimpl.borrow().get_raw_data_().to_owned();
-                let res_array_result = raw_array.permuted_axes(axes).into_dyn();
-
-                let tensor = Tensor::from_raw_array(res_array_result, false);
-                return tensor;
-            }
+            Some(axes) => axes,
             None => {
                 panic!(
                     "Error: Trying to transpose a multi-dimensional tensor with no axes order provided. Try passing in the argument for axes_option."
                 );
             }
         }
+    };
+
+    let new_shape = axes.iter().map(|axis| shape[*axis]).collect();
+    let tensor_impl = tensorimpl.view_(new_shape, |data| data.permuted_axes(axes));
+
+    return Tensor::from_tensor_impl(tensor_impl);
+}
+
+#[cfg(test)]
+pub mod test {
+    #[allow(unused)]
+    use super::*;
+    use ndarray::s;
+
+    #[test]
+    fn shape_ops_are_views_of_the_same_buffer() {
+        let x = Tensor::new((0..24).map(|x| x as f32).collect(), vec![2, 3, 4], false);
+        let allocation_id = x.get_storage().get_allocation_id();
+
+        let t = x.transpose(Some(vec![2, 0, 1]));
+        assert_eq!(*t.get_shape(), vec![4, 2, 3]);
+        assert_eq!(*t.get_strides(), vec![1, 12, 4]);
+        assert!(!t.is_contiguous());
+
+        let u = t.unsqueeze(Axis(0)).squeeze(Axis(0));
+        let b = x.unsqueeze(Axis(0)).broadcast(vec![5, 2, 3, 4]);
+        assert_eq!(*b.get_strides(), vec![0, 12, 4, 1]);
+
+        let v = compute_slice(&x, s![1, 1.., ..;2].as_ref());
+        assert_eq!(*v.get_shape(), vec![2, 2]);
+        assert_eq!(v.get_offset(), 16);
+        assert_eq!(
+            v.get_raw_data().iter().copied().collect::<Vec<f32>>(),
+            vec![16.0, 18.0, 20.0, 22.0]
+        );
+
+        for view in [&t, &u, &b, &v, &x.reshape(vec![6, 4])] {
+            assert_eq!(view.get_storage().get_allocation_id(), allocation_id);
+        }
+
+        // a non contiguous tensor is copied when reshaped or made contiguous
+        let r = t.reshape(vec![24]);
+        assert_ne!(r.get_storage().get_allocation_id(), allocation_id);
+        assert_eq!(r.get_raw_data()[[1]], 4.0);
+
+        let c = t.contiguous();
+        assert!(c.is_contiguous());
+        assert!(*c.get_raw_data() == *t.get_raw_data());
+        assert_eq!(
+            c.contiguous().get_storage().get_allocation_id(),
+            c.get_storage().get_allocation_id()
+        );
+    }
+
+    #[test]
+    fn broadcast_tensors_are_read_and_sliced_without_copying() {
+        let x = Tensor::new(vec![1.0_f32, 2.0, 3.0], vec![3, 1], false);
+        let allocation_id = x.get_storage().get_allocation_id();
+
+        let b = x.broadcast(vec![2, 3, 4]);
+        assert_eq!(b.get_raw_data().view().strides(), &[0, 1, 0]);
+        assert_eq!(b.get_raw_data().as_ptr(), x.get_raw_data().as_ptr());
+
+        let v = compute_slice(&b, s![1, 1.., 1..3].as_ref());
+        assert_eq!(*v.get_shape(), vec![2, 2]);
+        assert_eq!(*v.get_strides(), vec![1, 0]);
+        assert_eq!(v.get_storage().get_allocation_id(), allocation_id);
+        assert_eq!(
+            v.get_raw_data().iter().copied().collect::<Vec<f32>>(),
+            vec![2.0, 2.0, 3.0, 3.0]
+        );
+
+        let y = &(&b * 2.0_f32) + &b.exp();
+        assert_eq!(y.get_raw_data()[[1, 2, 3]], 6.0 + 3.0_f32.exp());
+    }
+
+    #[test]
+    fn gradients_flow_through_views() {
+        let x = Tensor::new(vec![1.0_f32, 2.0, 3.0, 4.0, 5.0, 6.0], vec![2, 3], true);
+        let w1 = Tensor::new(vec![1.0_f32, 2.0, 3.0, 4.0, 5.0, 6.0], vec![3, 2], false);
+        let w2 = Tensor::new(
+            vec![10.0_f32, 20.0, 30.0, 40.0, 50.0, 60.0],
+            vec![3, 2],
+            false,
+        );
+
+        // both gradients reaching x are transposed views, the second is accumulated into the first
+        let z = &(&x.transpose(None) * &w1) + &(&x.transpose(None).contiguous() * &w2);
+        let z = z.broadcast(vec![4, 3, 2]).sum(Axis(0));
+        z.backward(Tensor::<f32>::ones_as_f32(vec![3, 2]), false);
+
+        let grad = x.grad().unwrap();
+        assert_eq!(*grad.get_shape(), vec![2, 3]);
+        assert_eq!(
+            grad.get_raw_data().iter().copied().collect::<Vec<f32>>(),
+            vec![44.0, 132.0, 220.0, 88.0, 176.0, 264.0]
+        );
     }
 }
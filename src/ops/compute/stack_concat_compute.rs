@@ -1,4 +1,4 @@
-use std::{fmt::Debug, ops::Deref};
+use std::fmt::Debug;
 
 use ndarray::{Axis, concatenate, stack};
 
@@ -17,7 +17,7 @@ where
     }
 
     for array_ref in &ref_array {
-        array_view_array.push(array_ref.deref().view());
+        array_view_array.push(array_ref.view());
     }
 
     let stacked_res = stack(dim, &array_view_array);
@@ -46,7 +46,7 @@ where
     }
 
     for array_ref in &ref_array {
-        array_view_array.push(array_ref.deref().view());
+        array_view_array.push(array_ref.view());
     }
 
     let stacked_res = concatenate(dim, &array_view_array);
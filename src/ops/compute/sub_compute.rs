@@ -1,5 +1,5 @@
 use ndarray::ScalarOperand;
-use std::ops::{Deref, Sub};
+use std::ops::Sub;
 
 use std::fmt::Debug;
 
@@ -13,7 +13,7 @@ where
     let lhs_raw = tensor_lhs.get_raw_data();
     let rhs_raw = tensor_rhs.get_raw_data();
 
-    let new_raw = lhs_raw.deref() - rhs_raw.deref();
+    let new_raw = &lhs_raw.view() - &rhs_raw.view();
     let tensor = Tensor::from_raw_array(new_raw, false);
 
     return tensor;
@@ -26,7 +26,7 @@ where
 {
     let x_raw = tensor.get_raw_data();
 
-    let new_raw = x_raw.deref() - scalar;
+    let new_raw = &x_raw.view() - scalar;
     let tensor = Tensor::from_raw_array(new_raw, false);
 
     return tensor;
@@ -7,13 +7,19 @@ use std::{
 
 use crate::{
     ops::compute::div_compute::div_compute_tensor_scalar,
-    tensor_core::{dtypes::DTComp, tensor::Tensor},
+    tensor_core::{dtypes::DTComp, half::accumulate_in_f32, tensor::Tensor},
 };
 
 pub fn sum_compute_tensor<T>(tensor: &Tensor<T>, dim: Axis) -> Tensor<T>
 where
     T: DTComp + Clone + Debug + Add<Output = T>,
 {
+    if let Some(result_tensor) =
+        accumulate_in_f32(&[tensor], |widened| sum_compute_tensor(&widened[0], dim))
+    {
+        return result_tensor;
+    }
+
     let raw_array = tensor.get_raw_data();
     let raw_array = raw_array.map_axis(dim, |view| {
         view.iter()
@@ -65,6 +71,12 @@ pub fn mean_compute_tensor<T>(tensor: &Tensor<T>, dim: Axis) -> Tensor<T>
 where
     T: DTComp + Clone + Debug + Add<Output = T> + Div<Output = T> + ScalarOperand + NumCast,
 {
+    if let Some(result_tensor) =
+        accumulate_in_f32(&[tensor], |widened| mean_compute_tensor(&widened[0], dim))
+    {
+        return result_tensor;
+    }
+
     let num_elem = tensor.get_shape()[dim.index()];
     let num_elem = T::from(num_elem).expect("Failed to cast usize to target type");
 
@@ -0,0 +1,3 @@
+pub mod fused_program;
+pub mod lazy_expr;
+pub mod lazy_tensor;
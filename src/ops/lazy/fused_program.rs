@@ -0,0 +1,240 @@
+use num_traits::Float;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::rc::Rc;
+
+use crate::ops::lazy::lazy_expr::{BinaryKind, LazyExpr, UnaryKind};
+use crate::tensor_core::dtypes::DTComp;
+use crate::tensor_core::tensor_impl::TensorImpl;
+
+/// A single step of a fused kernel. Every instruction writes exactly one register, whose index is
+/// the position of the instruction in the program. Operands refer to earlier registers.
+#[derive(Debug, Clone, Copy)]
+pub enum FusedInstr<T> {
+    Load(usize),
+    Const(T),
+    Unary(UnaryKind<T>, usize),
+    Binary(BinaryKind, usize, usize),
+}
+
+/// A lazy expression tree flattened into a list of register instructions. Evaluating the program
+/// once per element lets a whole chain of element wise operations run in a single pass over
+/// memory instead of materializing one array per operation.
+#[derive(Debug)]
+pub struct FusedProgram<T> {
+    instructions: Vec<FusedInstr<T>>,
+    num_inputs: usize,
+    output_shape: Vec<usize>,
+}
+
+impl<T> FusedProgram<T>
+where
+    T: DTComp + Debug + Copy,
+{
+    /// Flatten the expression into a program. Sub-expressions and input tensors that are shared
+    /// inside the tree are only emitted once. Returns the program together with the tensors it
+    /// loads from, in the order of their `Load` index.
+    pub fn compile(expr: &Rc<LazyExpr<T>>) -> (Self, Vec<Rc<RefCell<TensorImpl<T>>>>) {
+        let mut instructions = vec![];
+        let mut inputs = vec![];
+        let mut input_registry: HashMap<*const RefCell<TensorImpl<T>>, usize> = HashMap::new();
+        let mut node_registry: HashMap<*const LazyExpr<T>, usize> = HashMap::new();
+
+        FusedProgram::compile_node(
+            expr,
+            &mut instructions,
+            &mut inputs,
+            &mut input_registry,
+            &mut node_registry,
+        );
+
+        let program = FusedProgram {
+            instructions,
+            num_inputs: inputs.len(),
+            output_shape: expr.infer_shape(),
+        };
+
+        return (program, inputs);
+    }
+
+    fn compile_node(
+        expr: &Rc<LazyExpr<T>>,
+        instructions: &mut Vec<FusedInstr<T>>,
+        inputs: &mut Vec<Rc<RefCell<TensorImpl<T>>>>,
+        input_registry: &mut HashMap<*const RefCell<TensorImpl<T>>, usize>,
+        node_registry: &mut HashMap<*const LazyExpr<T>, usize>,
+    ) -> usize {
+        let node_ptr = Rc::as_ptr(expr);
+        if let Some(register) = node_registry.get(&node_ptr) {
+            return *register;
+        }
+
+        let instruction = match expr.as_ref() {
+            LazyExpr::Input(tensor_impl) => {
+                let input_ptr = Rc::as_ptr(tensor_impl);
+                let input_index = match input_registry.get(&input_ptr) {
+                    Some(index) => *index,
+                    None => {
+                        inputs.push(Rc::clone(tensor_impl));
+                        input_registry.insert(input_ptr, inputs.len() - 1);
+                        inputs.len() - 1
+                    }
+                };
+
+                FusedInstr::Load(input_index)
+            }
+            LazyExpr::Scalar(scalar) => FusedInstr::Const(*scalar),
+            LazyExpr::Unary(kind, operand) => {
+                let operand_register = FusedProgram::compile_node(
+                    operand,
+                    instructions,
+                    inputs,
+                    input_registry,
+                    node_registry,
+                );
+
+                FusedInstr::Unary(*kind, operand_register)
+            }
+            LazyExpr::Binary(kind, lhs, rhs) => {
+                let lhs_register = FusedProgram::compile_node(
+                    lhs,
+                    instructions,
+                    inputs,
+                    input_registry,
+                    node_registry,
+                );
+                let rhs_register = FusedProgram::compile_node(
+                    rhs,
+                    instructions,
+                    inputs,
+                    input_registry,
+                    node_registry,
+                );
+
+                FusedInstr::Binary(*kind, lhs_register, rhs_register)
+            }
+        };
+
+        instructions.push(instruction);
+        node_registry.insert(node_ptr, instructions.len() - 1);
+
+        return instructions.len() - 1;
+    }
+
+    pub fn get_instructions(&self) -> &[FusedInstr<T>] {
+        return &self.instructions;
+    }
+
+    pub fn get_num_inputs(&self) -> usize {
+        return self.num_inputs;
+    }
+
+    pub fn get_num_registers(&self) -> usize {
+        return self.instructions.len();
+    }
+
+    pub fn get_output_shape(&self) -> &[usize] {
+        return &self.output_shape;
+    }
+}
+
+impl<T> FusedProgram<T>
+where
+    T: DTComp + Debug + Float,
+{
+    /// Run the program on one element. `loaded` holds the current element of every input and
+    /// `registers` receives the value of every instruction, which the backward pass reuses.
+    pub fn eval_element(&self, loaded: &[T], registers: &mut [T]) -> T {
+        for (i, instruction) in self.instructions.iter().enumerate() {
+            registers[i] = match *instruction {
+                FusedInstr::Load(input_index) => loaded[input_index],
+                FusedInstr::Const(scalar) => scalar,
+                FusedInstr::Unary(kind, operand) => {
+                    let x = registers[operand];
+
+                    match kind {
+                        UnaryKind::Neg => -x,
+                        UnaryKind::Exp => x.exp(),
+                        UnaryKind::Exp2 => x.exp2(),
+                        UnaryKind::Ln => x.ln(),
+                        UnaryKind::Log(base) => x.log(base),
+                        UnaryKind::Tanh => x.tanh(),
+                        UnaryKind::Pow(exponent) => x.powf(exponent),
+                    }
+                }
+                FusedInstr::Binary(kind, lhs, rhs) => {
+                    let (a, b) = (registers[lhs], registers[rhs]);
+
+                    match kind {
+                        BinaryKind::Add => a + b,
+                        BinaryKind::Sub => a - b,
+                        BinaryKind::Mul => a * b,
+                        BinaryKind::Div => a / b,
+                    }
+                }
+            };
+        }
+
+        return registers[self.instructions.len() - 1];
+    }
+
+    /// Reverse mode pass over one element. Must be called right after `eval_element` on the same
+    /// element so `registers` holds the forward values. Gradients w.r.t. every input are written
+    /// into `input_grads`.
+    pub fn backward_element(
+        &self,
+        registers: &[T],
+        upstream_gradient: T,
+        adjoints: &mut [T],
+        input_grads: &mut [T],
+    ) {
+        adjoints.fill(T::zero());
+        input_grads.fill(T::zero());
+
+        adjoints[self.instructions.len() - 1] = upstream_gradient;
+
+        for (i, instruction) in self.instructions.iter().enumerate().rev() {
+            let adjoint = adjoints[i];
+            if adjoint == T::zero() {
+                continue;
+            }
+
+            match *instruction {
+                FusedInstr::Load(input_index) => {
+                    input_grads[input_index] = input_grads[input_index] + adjoint;
+                }
+                FusedInstr::Const(_) => {}
+                FusedInstr::Unary(kind, operand) => {
+                    let x = registers[operand];
+                    let y = registers[i];
+
+                    let local_grad = match kind {
+                        UnaryKind::Neg => -T::one(),
+                        UnaryKind::Exp => y,
+                        UnaryKind::Exp2 => y * T::from(2.0).unwrap().ln(),
+                        UnaryKind::Ln => T::one() / x,
+                        UnaryKind::Log(base) => T::one() / (x * base.ln()),
+                        UnaryKind::Tanh => T::one() - y * y,
+                        UnaryKind::Pow(exponent) => exponent * x.powf(exponent - T::one()),
+                    };
+
+                    adjoints[operand] = adjoints[operand] + adjoint * local_grad;
+                }
+                FusedInstr::Binary(kind, lhs, rhs) => {
+                    let (a, b) = (registers[lhs], registers[rhs]);
+
+                    let (lhs_grad, rhs_grad) = match kind {
+                        BinaryKind::Add => (adjoint, adjoint),
+                        BinaryKind::Sub => (adjoint, -adjoint),
+                        BinaryKind::Mul => (adjoint * b, adjoint * a),
+                        BinaryKind::Div => (adjoint / b, -adjoint * registers[i] / b),
+                    };
+
+                    adjoints[lhs] = adjoints[lhs] + lhs_grad;
+                    adjoints[rhs] = adjoints[rhs] + rhs_grad;
+                }
+            }
+        }
+    }
+}
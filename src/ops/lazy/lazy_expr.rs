@@ -0,0 +1,85 @@
+use std::cell::RefCell;
+use std::fmt::Debug;
+use std::rc::Rc;
+
+use crate::tensor_core::dtypes::DTComp;
+use crate::tensor_core::tensor_impl::TensorImpl;
+use crate::utils::shaping_utils::broadcast_shapes;
+
+/// Element wise operations with a single operand that can be fused into a kernel
+#[derive(Debug, Clone, Copy)]
+pub enum UnaryKind<T> {
+    Neg,
+    Exp,
+    Exp2,
+    Ln,
+    Log(T),
+    Tanh,
+    Pow(T),
+}
+
+/// Element wise operations with two operands that can be fused into a kernel
+#[derive(Debug, Clone, Copy)]
+pub enum BinaryKind {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+/// Expression tree recorded by a `LazyTensor`. Leaves are either materialized tensors or
+/// scalars, and no data is computed until the tree is compiled and evaluated
+#[derive(Debug)]
+pub enum LazyExpr<T>
+where
+    T: DTComp + Debug,
+{
+    Input(Rc<RefCell<TensorImpl<T>>>),
+    Scalar(T),
+    Unary(UnaryKind<T>, Rc<LazyExpr<T>>),
+    Binary(BinaryKind, Rc<LazyExpr<T>>, Rc<LazyExpr<T>>),
+}
+
+impl<T> LazyExpr<T>
+where
+    T: DTComp + Debug,
+{
+    /// Collect the shapes of every tensor input of the expression
+    pub fn collect_input_shapes(&self, shapes: &mut Vec<Vec<usize>>) {
+        match self {
+            LazyExpr::Input(tensor_impl) => {
+                shapes.push(tensor_impl.borrow().shape.clone());
+            }
+            LazyExpr::Scalar(_) => {}
+            LazyExpr::Unary(_, operand) => {
+                operand.collect_input_shapes(shapes);
+            }
+            LazyExpr::Binary(_, lhs, rhs) => {
+                lhs.collect_input_shapes(shapes);
+                rhs.collect_input_shapes(shapes);
+            }
+        }
+    }
+
+    /// Shape of the tensor the expression evaluates to, without evaluating it
+    pub fn infer_shape(&self) -> Vec<usize> {
+        let mut shapes = vec![];
+        self.collect_input_shapes(&mut shapes);
+
+        return broadcast_shapes(&shapes).unwrap_or_else(|| {
+            panic!(
+                "Error: Cannot broadcast the inputs of a lazy expression together. Got shapes: {:?}",
+                shapes
+            )
+        });
+    }
+
+    pub fn does_require_grad(&self) -> bool {
+        match self {
+            LazyExpr::Input(tensor_impl) => tensor_impl.borrow().autograd_meta.is_some(),
+            LazyExpr::Scalar(_) => false,
+            LazyExpr::Unary(_, operand) => operand.does_require_grad(),
+            LazyExpr::Binary(_, lhs, rhs) => lhs.does_require_grad() || rhs.does_require_grad(),
+        }
+    }
+}
@@ -0,0 +1,73 @@
+use std::fmt::Debug;
+use std::rc::Rc;
+
+use crate::ops::lazy::lazy_expr::{BinaryKind, LazyExpr, UnaryKind};
+use crate::tensor_core::dtypes::DTComp;
+use crate::tensor_core::tensor::Tensor;
+
+/// A tensor whose value has not been computed yet. Element wise operations on a `LazyTensor`
+/// only extend its expression tree; the whole chain is evaluated in a single fused pass over
+/// memory when `eval()` is called.
+#[derive(Debug)]
+pub struct LazyTensor<T>
+where
+    T: DTComp + Debug,
+{
+    pub(crate) expr: Rc<LazyExpr<T>>,
+}
+
+impl<T> Clone for LazyTensor<T>
+where
+    T: DTComp + Debug,
+{
+    fn clone(&self) -> Self {
+        return LazyTensor {
+            expr: Rc::clone(&self.expr),
+        };
+    }
+}
+
+impl<T> LazyTensor<T>
+where
+    T: DTComp + Debug,
+{
+    pub fn from_tensor(tensor: &Tensor<T>) -> Self {
+        return LazyTensor {
+            expr: Rc::new(LazyExpr::Input(tensor.__clone_ptr_to_tensor_impl())),
+        };
+    }
+
+    pub fn from_scalar(scalar: T) -> Self {
+        return LazyTensor {
+            expr: Rc::new(LazyExpr::Scalar(scalar)),
+        };
+    }
+
+    pub fn get_expr(&self) -> &Rc<LazyExpr<T>> {
+        return &self.expr;
+    }
+
+    pub fn get_shape(&self) -> Vec<usize> {
+        return self.expr.infer_shape();
+    }
+
+    pub fn does_require_grad(&self) -> bool {
+        return self.expr.does_require_grad();
+    }
+
+    pub(crate) fn unary(&self, kind: UnaryKind<T>) -> Self {
+        return LazyTensor {
+            expr: Rc::new(LazyExpr::Unary(kind, Rc::clone(&self.expr))),
+        };
+    }
+
+    pub(crate) fn binary(&self, kind: BinaryKind, other: &LazyTensor<T>) -> Self {
+        return LazyTensor {
+            expr: Rc::new(LazyExpr::Binary(
+                kind,
+                Rc::clone(&self.expr),
+                Rc::clone(&other.expr),
+            )),
+        };
+    }
+}
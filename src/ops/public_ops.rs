@@ -55,6 +55,30 @@ pub mod transpose_public;
 pub mod reshape;
 pub mod reshape_public;
 
+pub mod contiguous;
+pub mod contiguous_public;
+
+pub mod slice;
+pub mod slice_public;
+
+pub mod index_select;
+pub mod index_select_public;
+
+pub mod gather;
+pub mod gather_public;
+
+pub mod masked_select;
+pub mod masked_select_public;
+
+pub mod scatter_add;
+pub mod scatter_add_public;
+
+pub mod index_add;
+pub mod index_add_public;
+
+pub mod segment;
+pub mod segment_public;
+
 pub mod exp;
 pub mod exp_public;
 
@@ -63,3 +87,26 @@ pub mod stack_public;
 
 pub mod concat;
 pub mod concat_public;
+
+pub mod compare;
+pub mod compare_public;
+
+pub mod logical;
+pub mod logical_public;
+
+pub mod where_;
+pub mod where_public;
+
+pub mod masked_fill;
+pub mod masked_fill_public;
+
+pub mod cast;
+pub mod cast_public;
+
+pub mod complex;
+pub mod complex_public;
+
+pub mod promote;
+
+pub mod lazy;
+pub mod lazy_public;
@@ -15,6 +15,7 @@ where
 {
     type Output = Tensor<T>;
 
+    #[track_caller]
     fn add(self, rhs: S) -> Tensor<T> {
         return add_tensor_scalar(self, rhs);
     }
@@ -27,6 +28,7 @@ where
 {
     type Output = Tensor<T>;
 
+    #[track_caller]
     fn add(self, rhs: S) -> Tensor<T> {
         return &self + rhs;
     }
@@ -38,6 +40,7 @@ where
 {
     type Output = Tensor<T>;
 
+    #[track_caller]
     fn add(self, rhs: &'tl_out Tensor<T>) -> Self::Output {
         return add_tensor_tensor(self, rhs);
     }
@@ -49,6 +52,7 @@ where
 {
     type Output = Tensor<T>;
 
+    #[track_caller]
     fn add(self, rhs: &'tl Tensor<T>) -> Self::Output {
         return add_tensor_tensor(&self, rhs);
     }
@@ -60,6 +64,7 @@ where
 {
     type Output = Tensor<T>;
 
+    #[track_caller]
     fn add(self, rhs: Tensor<T>) -> Self::Output {
         return &self + &rhs;
     }
@@ -4,17 +4,28 @@ use std::ops::Add;
 
 use crate::ops::central::add_impl::add_impl;
 use crate::ops::compute::add_compute;
+use crate::ops::compute::meta_compute;
+use crate::profiler::timing_profiler::{self, SpanCategory};
 use crate::tensor_core::dtypes::DTComp;
 use crate::tensor_core::tensor::Tensor;
 
+#[track_caller]
 pub fn add_tensor_tensor<T>(lhs_tensor: &Tensor<T>, rhs_tensor: &Tensor<T>) -> Tensor<T>
 where
     T: DTComp + Clone + Debug + Add<Output = T> + 'static,
 {
-    let result_tensor = add_compute::add_compute_tensor_tensor(lhs_tensor, rhs_tensor);
+    let _span = timing_profiler::span(SpanCategory::Forward, "add", &[lhs_tensor, rhs_tensor]);
+
+    let result_tensor;
+
+    if meta_compute::any_meta(&[lhs_tensor, rhs_tensor]) {
+        result_tensor = meta_compute::meta_compute_broadcast_binary(lhs_tensor, rhs_tensor);
+    } else {
+        result_tensor = add_compute::add_compute_tensor_tensor(lhs_tensor, rhs_tensor);
+    }
 
     if lhs_tensor.does_require_grad() || rhs_tensor.does_require_grad() {
-        result_tensor.requires_grad_intermediate("Intermediate tensor from add");
+        result_tensor.requires_grad_intermediate("add");
     }
 
     add_impl(Some(lhs_tensor), Some(rhs_tensor), &result_tensor);
@@ -22,15 +33,24 @@ where
     return result_tensor;
 }
 
+#[track_caller]
 pub fn add_tensor_scalar<T, S>(tensor: &Tensor<T>, scalar: S) -> Tensor<T>
 where
     T: DTComp + Clone + Add<S, Output = T> + Add<Output = T> + ScalarOperand + 'static + Debug,
     S: ScalarOperand,
 {
-    let result_tensor = add_compute::add_compute_tensor_scalar(tensor, scalar);
+    let _span = timing_profiler::span(SpanCategory::Forward, "add", &[tensor]);
+
+    let result_tensor;
+
+    if tensor.is_meta() {
+        result_tensor = meta_compute::meta_compute_like(tensor);
+    } else {
+        result_tensor = add_compute::add_compute_tensor_scalar(tensor, scalar);
+    }
 
     if tensor.does_require_grad() {
-        result_tensor.requires_grad_intermediate("Intermediate tensor from add");
+        result_tensor.requires_grad_intermediate("add");
     }
 
     add_impl(Some(tensor), None, &result_tensor);
@@ -8,6 +8,7 @@ impl<T> Tensor<T>
 where
     T: Debug + DTComp + Clone + Add<Output = T> + 'static,
 {
+    #[track_caller]
     pub fn broadcast(&self, shape: Vec<usize>) -> Self {
         return broadcast_tensor(self, shape);
     }
@@ -2,26 +2,29 @@ use std::fmt::Debug;
 use std::ops::Add;
 
 use crate::ops::central::broadcast_impl::broadcast_impl;
+use crate::ops::compute::meta_compute;
+use crate::ops::compute::shape_compute::compute_broadcast;
+use crate::profiler::timing_profiler::{self, SpanCategory};
 use crate::tensor_core::dtypes::DTComp;
 use crate::tensor_core::tensor::Tensor;
 
+#[track_caller]
 pub fn broadcast_tensor<T>(tensor: &Tensor<T>, shape: Vec<usize>) -> Tensor<T>
 where
     T: Debug + DTComp + Clone + Add<Output = T> + 'static,
 {
-    let raw_array = tensor.get_raw_data().to_owned();
-    let new_array_option = raw_array.broadcast(shape);
+    let _span = timing_profiler::span(SpanCategory::Forward, "broadcast", &[tensor]);
 
-    let result_tensor: Tensor<T> = match new_array_option {
-        Some(new_array) => Tensor::from_raw_array(new_array.to_owned(), false),
-        None => {
-            panic!("Error: Cannot broadcast tensor to intended shape.");
-        }
-    };
+    let result_tensor: Tensor<T>;
+
+    if tensor.is_meta() {
+        result_tensor = meta_compute::meta_compute_broadcast(tensor, shape);
+    } else {
+        result_tensor = compute_broadcast(tensor, shape);
+    }
 
     if tensor.does_require_grad() {
-        result_tensor
-            .requires_grad_intermediate("Intermediate tensor from broadcast with arbitrary base");
+        result_tensor.requires_grad_intermediate("broadcast");
 
         broadcast_impl(Some(tensor), &result_tensor);
     }
@@ -0,0 +1,23 @@
+use num_traits::AsPrimitive;
+use std::fmt::Debug;
+use std::ops::Add;
+
+use crate::ops::public_ops::cast_public::cast_tensor;
+use crate::tensor_core::dtypes::DTComp;
+use crate::tensor_core::tensor::Tensor;
+
+impl<T> Tensor<T>
+where
+    T: Debug + DTComp + Clone + 'static + Add<Output = T>,
+{
+    /// Casts every element to `U`. Unlike `as_float_32` and `as_float_64`, the result stays in the
+    /// graph and its gradient is cast back to `T`.
+    #[track_caller]
+    pub fn to<U>(&self) -> Tensor<U>
+    where
+        T: AsPrimitive<U>,
+        U: Debug + DTComp + Copy + Add<Output = U> + AsPrimitive<T>,
+    {
+        return cast_tensor(self);
+    }
+}
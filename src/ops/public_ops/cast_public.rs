@@ -0,0 +1,33 @@
+use num_traits::AsPrimitive;
+use std::fmt::Debug;
+use std::ops::Add;
+
+use crate::ops::central::cast_impl::cast_impl;
+use crate::ops::compute::cast_compute::compute_cast;
+use crate::profiler::timing_profiler::{self, SpanCategory};
+use crate::tensor_core::dtypes::DTComp;
+use crate::tensor_core::tensor::Tensor;
+
+#[track_caller]
+pub fn cast_tensor<T, U>(tensor: &Tensor<T>) -> Tensor<U>
+where
+    T: Copy + DTComp + Debug + Add<Output = T> + AsPrimitive<U>,
+    U: Copy + DTComp + Debug + Add<Output = U> + AsPrimitive<T>,
+{
+    let _span = timing_profiler::span(SpanCategory::Forward, "cast", &[tensor]);
+
+    let result_tensor;
+
+    if tensor.is_meta() {
+        result_tensor = Tensor::meta(tensor.get_shape().clone(), false);
+    } else {
+        result_tensor = compute_cast(tensor);
+    }
+
+    if tensor.does_require_grad() {
+        result_tensor.requires_grad_intermediate("cast");
+        cast_impl(Some(tensor), &result_tensor);
+    }
+
+    return result_tensor;
+}
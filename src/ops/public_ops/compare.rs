@@ -0,0 +1,72 @@
+use std::fmt::Debug;
+
+use crate::ops::public_ops::compare_public::{compare_tensor_scalar, compare_tensor_tensor};
+use crate::tensor_core::dtypes::DTComp;
+use crate::tensor_core::tensor::Tensor;
+
+// Element wise comparisons. The results are boolean tensors, which never track gradients.
+
+impl<T> Tensor<T>
+where
+    T: Debug + DTComp + Clone + PartialOrd,
+{
+    #[track_caller]
+    pub fn eq(&self, other: &Tensor<T>) -> Tensor<bool> {
+        return compare_tensor_tensor(self, other, "eq", |a, b| a == b);
+    }
+
+    #[track_caller]
+    pub fn ne(&self, other: &Tensor<T>) -> Tensor<bool> {
+        return compare_tensor_tensor(self, other, "ne", |a, b| a != b);
+    }
+
+    #[track_caller]
+    pub fn lt(&self, other: &Tensor<T>) -> Tensor<bool> {
+        return compare_tensor_tensor(self, other, "lt", |a, b| a < b);
+    }
+
+    #[track_caller]
+    pub fn le(&self, other: &Tensor<T>) -> Tensor<bool> {
+        return compare_tensor_tensor(self, other, "le", |a, b| a <= b);
+    }
+
+    #[track_caller]
+    pub fn gt(&self, other: &Tensor<T>) -> Tensor<bool> {
+        return compare_tensor_tensor(self, other, "gt", |a, b| a > b);
+    }
+
+    #[track_caller]
+    pub fn ge(&self, other: &Tensor<T>) -> Tensor<bool> {
+        return compare_tensor_tensor(self, other, "ge", |a, b| a >= b);
+    }
+
+    #[track_caller]
+    pub fn eq_scalar(&self, scalar: T) -> Tensor<bool> {
+        return compare_tensor_scalar(self, scalar, "eq", |a, b| a == b);
+    }
+
+    #[track_caller]
+    pub fn ne_scalar(&self, scalar: T) -> Tensor<bool> {
+        return compare_tensor_scalar(self, scalar, "ne", |a, b| a != b);
+    }
+
+    #[track_caller]
+    pub fn lt_scalar(&self, scalar: T) -> Tensor<bool> {
+        return compare_tensor_scalar(self, scalar, "lt", |a, b| a < b);
+    }
+
+    #[track_caller]
+    pub fn le_scalar(&self, scalar: T) -> Tensor<bool> {
+        return compare_tensor_scalar(self, scalar, "le", |a, b| a <= b);
+    }
+
+    #[track_caller]
+    pub fn gt_scalar(&self, scalar: T) -> Tensor<bool> {
+        return compare_tensor_scalar(self, scalar, "gt", |a, b| a > b);
+    }
+
+    #[track_caller]
+    pub fn ge_scalar(&self, scalar: T) -> Tensor<bool> {
+        return compare_tensor_scalar(self, scalar, "ge", |a, b| a >= b);
+    }
+}
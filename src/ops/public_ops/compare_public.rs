@@ -0,0 +1,42 @@
+use std::fmt::Debug;
+
+use crate::ops::compute::logical_compute::{compute_compare_scalar, compute_zip_with};
+use crate::ops::compute::meta_compute;
+use crate::profiler::timing_profiler::{self, SpanCategory};
+use crate::tensor_core::dtypes::DTComp;
+use crate::tensor_core::tensor::Tensor;
+
+#[track_caller]
+pub fn compare_tensor_tensor<T, F>(
+    lhs_tensor: &Tensor<T>,
+    rhs_tensor: &Tensor<T>,
+    name: &str,
+    f: F,
+) -> Tensor<bool>
+where
+    T: Debug + DTComp + Clone,
+    F: Fn(&T, &T) -> bool,
+{
+    let _span = timing_profiler::span(SpanCategory::Forward, name, &[lhs_tensor, rhs_tensor]);
+
+    if meta_compute::any_meta(&[lhs_tensor, rhs_tensor]) {
+        return meta_compute::meta_compute_compare(lhs_tensor, rhs_tensor);
+    }
+
+    return compute_zip_with(lhs_tensor, rhs_tensor, f);
+}
+
+#[track_caller]
+pub fn compare_tensor_scalar<T, F>(tensor: &Tensor<T>, scalar: T, name: &str, f: F) -> Tensor<bool>
+where
+    T: Debug + DTComp + Clone,
+    F: Fn(&T, &T) -> bool,
+{
+    let _span = timing_profiler::span(SpanCategory::Forward, name, &[tensor]);
+
+    if tensor.is_meta() {
+        return Tensor::meta(tensor.get_shape().clone(), false);
+    }
+
+    return compute_compare_scalar(tensor, scalar, f);
+}
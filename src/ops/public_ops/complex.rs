@@ -0,0 +1,44 @@
+use num_complex::Complex;
+use num_traits::Float;
+use std::fmt::Debug;
+
+use crate::ops::compute::complex_compute::ComplexPart;
+use crate::ops::public_ops::complex_public::{complex_part_tensor, conj_tensor};
+use crate::tensor_core::dtypes::DTComp;
+use crate::tensor_core::tensor::Tensor;
+
+// Gradients through these follow the conjugate Wirtinger convention, so the gradient of a real
+// loss with respect to `z = x + iy` is `dL/dx + i dL/dy`.
+
+impl<F> Tensor<Complex<F>>
+where
+    F: DTComp + Debug + Float,
+    Complex<F>: DTComp,
+{
+    #[track_caller]
+    pub fn real(&self) -> Tensor<F> {
+        return complex_part_tensor(self, ComplexPart::Real, "real");
+    }
+
+    #[track_caller]
+    pub fn imag(&self) -> Tensor<F> {
+        return complex_part_tensor(self, ComplexPart::Imag, "imag");
+    }
+
+    /// Magnitude of every element
+    #[track_caller]
+    pub fn abs(&self) -> Tensor<F> {
+        return complex_part_tensor(self, ComplexPart::Abs, "abs");
+    }
+
+    /// Phase of every element in radians, in the range `(-pi, pi]`
+    #[track_caller]
+    pub fn angle(&self) -> Tensor<F> {
+        return complex_part_tensor(self, ComplexPart::Angle, "angle");
+    }
+
+    #[track_caller]
+    pub fn conj(&self) -> Self {
+        return conj_tensor(self);
+    }
+}
@@ -0,0 +1,73 @@
+use num_complex::Complex;
+use num_traits::Float;
+use std::fmt::Debug;
+use std::rc::Rc;
+
+use crate::ops::central::complex_impl::{complex_part_impl, conj_impl};
+use crate::ops::compute::complex_compute::{
+    ComplexPart, compute_complex_part, compute_complex_part_grad_factor, compute_conj,
+};
+use crate::profiler::timing_profiler::{self, SpanCategory};
+use crate::tensor_core::dtypes::DTComp;
+use crate::tensor_core::tensor::Tensor;
+
+#[track_caller]
+pub fn complex_part_tensor<F>(
+    tensor: &Tensor<Complex<F>>,
+    part: ComplexPart,
+    name: &str,
+) -> Tensor<F>
+where
+    F: DTComp + Debug + Float,
+    Complex<F>: DTComp,
+{
+    let _span = timing_profiler::span(SpanCategory::Forward, name, &[tensor]);
+
+    let result_tensor;
+
+    if tensor.is_meta() {
+        result_tensor = Tensor::meta(tensor.get_shape().clone(), false);
+    } else {
+        result_tensor = compute_complex_part(tensor, part);
+    }
+
+    if tensor.does_require_grad() {
+        result_tensor.requires_grad_intermediate(name);
+
+        let grad_factor;
+
+        if tensor.is_meta() {
+            grad_factor = Tensor::meta(tensor.get_shape().clone(), false);
+        } else {
+            grad_factor = compute_complex_part_grad_factor(tensor, part);
+        }
+
+        complex_part_impl(Some(tensor), &result_tensor, Rc::new(grad_factor));
+    }
+
+    return result_tensor;
+}
+
+#[track_caller]
+pub fn conj_tensor<F>(tensor: &Tensor<Complex<F>>) -> Tensor<Complex<F>>
+where
+    F: DTComp + Debug + Float,
+    Complex<F>: DTComp,
+{
+    let _span = timing_profiler::span(SpanCategory::Forward, "conj", &[tensor]);
+
+    let result_tensor;
+
+    if tensor.is_meta() {
+        result_tensor = Tensor::meta(tensor.get_shape().clone(), false);
+    } else {
+        result_tensor = compute_conj(tensor);
+    }
+
+    if tensor.does_require_grad() {
+        result_tensor.requires_grad_intermediate("conj");
+        conj_impl(Some(tensor), &result_tensor);
+    }
+
+    return result_tensor;
+}
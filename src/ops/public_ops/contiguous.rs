@@ -0,0 +1,18 @@
+use std::fmt::Debug;
+use std::ops::Add;
+
+use crate::ops::public_ops::contiguous_public::contiguous_tensor;
+use crate::tensor_core::dtypes::DTComp;
+use crate::tensor_core::tensor::Tensor;
+
+impl<T> Tensor<T>
+where
+    T: Debug + DTComp + Clone + 'static + Add<Output = T>,
+{
+    /// Returns a tensor laid out in row major order without gaps. A tensor that already is shares
+    /// its buffer with the result, any other is copied.
+    #[track_caller]
+    pub fn contiguous(&self) -> Self {
+        return contiguous_tensor(self);
+    }
+}
@@ -0,0 +1,32 @@
+use std::fmt::Debug;
+use std::ops::Add;
+
+use crate::ops::central::contiguous_impl::contiguous_impl;
+use crate::ops::compute::meta_compute;
+use crate::ops::compute::shape_compute::compute_contiguous;
+use crate::profiler::timing_profiler::{self, SpanCategory};
+use crate::tensor_core::dtypes::DTComp;
+use crate::tensor_core::tensor::Tensor;
+
+#[track_caller]
+pub fn contiguous_tensor<T>(tensor: &Tensor<T>) -> Tensor<T>
+where
+    T: Clone + DTComp + Debug + 'static + Add<Output = T>,
+{
+    let _span = timing_profiler::span(SpanCategory::Forward, "contiguous", &[tensor]);
+
+    let result_tensor;
+
+    if tensor.is_meta() {
+        result_tensor = meta_compute::meta_compute_like(tensor);
+    } else {
+        result_tensor = compute_contiguous(tensor);
+    }
+
+    if tensor.does_require_grad() {
+        result_tensor.requires_grad_intermediate("contiguous");
+        contiguous_impl(Some(tensor), &result_tensor);
+    }
+
+    return result_tensor;
+}
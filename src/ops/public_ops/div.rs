@@ -3,9 +3,8 @@ use crate::tensor_core::dtypes::DTComp;
 use crate::tensor_core::tensor::Tensor;
 
 use ndarray::ScalarOperand;
-use num_traits::Signed;
 use std::fmt::Debug;
-use std::ops::{Add, Div};
+use std::ops::{Add, Div, Mul, Neg};
 
 impl<'tl, T, S> Div<S> for &'tl Tensor<T>
 where
@@ -16,11 +15,13 @@ where
         + Div<S, Output = T>
         + Add<Output = T>
         + 'static
-        + Signed,
+        + Mul<Output = T>
+        + Neg<Output = T>,
     S: ScalarOperand + Debug + Clone,
 {
     type Output = Tensor<T>;
 
+    #[track_caller]
     fn div(self, rhs: S) -> Self::Output {
         return div_tensor_scalar(self, rhs);
     }
@@ -35,11 +36,13 @@ where
         + Div<S, Output = T>
         + Add<Output = T>
         + 'static
-        + Signed,
+        + Mul<Output = T>
+        + Neg<Output = T>,
     S: ScalarOperand + Debug + Clone,
 {
     type Output = Tensor<T>;
 
+    #[track_caller]
     fn div(self, rhs: S) -> Self::Output {
         return &self / rhs;
     }
@@ -54,10 +57,12 @@ where
         + Add<Output = T>
         + 'static
         + ScalarOperand
-        + Signed,
+        + Mul<Output = T>
+        + Neg<Output = T>,
 {
     type Output = Tensor<T>;
 
+    #[track_caller]
     fn div(self, rhs: &'tl_out Tensor<T>) -> Self::Output {
         return div_tensor_tensor(self, rhs);
     }
@@ -72,10 +77,12 @@ where
         + Add<Output = T>
         + 'static
         + ScalarOperand
-        + Signed,
+        + Mul<Output = T>
+        + Neg<Output = T>,
 {
     type Output = Tensor<T>;
 
+    #[track_caller]
     fn div(self, rhs: &'tl Tensor<T>) -> Self::Output {
         return div_tensor_tensor(&self, rhs);
     }
@@ -90,10 +97,12 @@ where
         + Add<Output = T>
         + 'static
         + ScalarOperand
-        + Signed,
+        + Mul<Output = T>
+        + Neg<Output = T>,
 {
     type Output = Tensor<T>;
 
+    #[track_caller]
     fn div(self, rhs: Tensor<T>) -> Self::Output {
         return &self / &rhs;
     }
@@ -1,13 +1,15 @@
 use ndarray::ScalarOperand;
-use num_traits::Signed;
 use std::fmt::Debug;
-use std::ops::{Add, Div};
+use std::ops::{Add, Div, Mul, Neg};
 
 use crate::ops::central::div_impl::div_impl;
 use crate::ops::compute::div_compute::{div_compute_tensor_scalar, div_compute_tensor_tensor};
+use crate::ops::compute::meta_compute;
+use crate::profiler::timing_profiler::{self, SpanCategory};
 use crate::tensor_core::dtypes::DTComp;
 use crate::tensor_core::tensor::Tensor;
 
+#[track_caller]
 pub fn div_tensor_tensor<T>(lhs_tensor: &Tensor<T>, rhs_tensor: &Tensor<T>) -> Tensor<T>
 where
     T: DTComp
@@ -17,12 +19,21 @@ where
         + Add<Output = T>
         + 'static
         + ScalarOperand
-        + Signed,
+        + Mul<Output = T>
+        + Neg<Output = T>,
 {
-    let result_tensor = div_compute_tensor_tensor(lhs_tensor, rhs_tensor);
+    let _span = timing_profiler::span(SpanCategory::Forward, "div", &[lhs_tensor, rhs_tensor]);
+
+    let result_tensor;
+
+    if meta_compute::any_meta(&[lhs_tensor, rhs_tensor]) {
+        result_tensor = meta_compute::meta_compute_broadcast_binary(lhs_tensor, rhs_tensor);
+    } else {
+        result_tensor = div_compute_tensor_tensor(lhs_tensor, rhs_tensor);
+    }
 
     if lhs_tensor.does_require_grad() || rhs_tensor.does_require_grad() {
-        result_tensor.requires_grad_intermediate("Intermediate tensor from div");
+        result_tensor.requires_grad_intermediate("div");
     }
 
     div_impl(Some(lhs_tensor), Some(rhs_tensor), &result_tensor, None);
@@ -30,6 +41,7 @@ where
     return result_tensor;
 }
 
+#[track_caller]
 pub fn div_tensor_scalar<T, S>(tensor: &Tensor<T>, scalar: S) -> Tensor<T>
 where
     T: DTComp
@@ -39,13 +51,22 @@ where
         + Div<S, Output = T>
         + Add<Output = T>
         + 'static
-        + Signed,
+        + Mul<Output = T>
+        + Neg<Output = T>,
     S: ScalarOperand + Debug + Clone,
 {
-    let result_tensor = div_compute_tensor_scalar(tensor, scalar.clone());
+    let _span = timing_profiler::span(SpanCategory::Forward, "div", &[tensor]);
+
+    let result_tensor;
+
+    if tensor.is_meta() {
+        result_tensor = meta_compute::meta_compute_like(tensor);
+    } else {
+        result_tensor = div_compute_tensor_scalar(tensor, scalar.clone());
+    }
 
     if tensor.does_require_grad() {
-        result_tensor.requires_grad_intermediate("Intermediate tensor from div");
+        result_tensor.requires_grad_intermediate("div");
     }
 
     div_impl(Some(tensor), None, &result_tensor, Some(scalar.clone()));
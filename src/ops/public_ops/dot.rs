@@ -1,3 +1,5 @@
+use crate::ops::compute::meta_compute;
+use crate::profiler::timing_profiler::{self, SpanCategory};
 use crate::tensor_core::dtypes::DTComp;
 use crate::tensor_core::tensor::Tensor;
 
@@ -11,7 +13,14 @@ impl<T> Tensor<T>
 where
     T: DTComp + Zero + Clone + LinalgScalar + Debug,
 {
+    #[track_caller]
     pub fn tensor_dot(&self, rhs: &Tensor<T>) -> Tensor<T> {
+        let _span = timing_profiler::span(SpanCategory::Forward, "dot", &[self, rhs]);
+
+        if meta_compute::any_meta(&[self, rhs]) {
+            return meta_compute::meta_compute_dot(self, rhs);
+        }
+
         let left_raw_array = self
             .get_raw_data_as_ix2()
             .into_dimensionality::<Ix2>()
@@ -1,35 +1,38 @@
 use ndarray::ScalarOperand;
-use num_traits::Float;
 use std::fmt::Debug;
 
 use crate::ops::central::exp_impl::exp_impl;
 use crate::ops::compute::exp_compute::{exp_compute_tensor, exp2_compute_tensor};
-use crate::tensor_core::dtypes::DTComp;
+use crate::ops::compute::meta_compute;
+use crate::profiler::timing_profiler::{self, SpanCategory};
+use crate::tensor_core::dtypes::{DTComp, DTFloat};
 use crate::tensor_core::tensor::Tensor;
 
+#[track_caller]
 pub fn exp_tensor<T>(tensor: &Tensor<T>, base: Option<usize>) -> Tensor<T>
 where
-    T: Debug + DTComp + Float + 'static + ScalarOperand,
+    T: Debug + DTComp + DTFloat + 'static + ScalarOperand,
 {
+    let _span = timing_profiler::span(SpanCategory::Forward, "exp", &[tensor]);
+
     let result_tensor;
-    let natural: bool;
 
     match base {
+        _ if tensor.is_meta() => {
+            result_tensor = meta_compute::meta_compute_like(tensor);
+        }
         Some(_) => {
             result_tensor = exp2_compute_tensor(tensor);
-            natural = false;
         }
         None => {
             result_tensor = exp_compute_tensor(tensor);
-            natural = true;
         }
     }
 
     if tensor.does_require_grad() {
-        result_tensor
-            .requires_grad_intermediate("Intermediate tensor from exponential with arbitrary base");
+        result_tensor.requires_grad_intermediate("exp");
 
-        exp_impl(Some(tensor), &result_tensor, natural)
+        exp_impl(Some(tensor), &result_tensor)
     }
 
     return result_tensor;
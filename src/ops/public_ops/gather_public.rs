@@ -0,0 +1,35 @@
+use ndarray::Axis;
+use num_traits::Zero;
+use std::fmt::Debug;
+use std::ops::Add;
+use std::rc::Rc;
+
+use crate::ops::central::gather_impl::gather_impl;
+use crate::ops::compute::indexing_compute::compute_gather;
+use crate::ops::compute::meta_compute;
+use crate::profiler::timing_profiler::{self, SpanCategory};
+use crate::tensor_core::dtypes::DTComp;
+use crate::tensor_core::tensor::Tensor;
+
+#[track_caller]
+pub fn gather_tensor<T>(tensor: &Tensor<T>, dim: Axis, indices: &Tensor<usize>) -> Tensor<T>
+where
+    T: Clone + DTComp + Debug + 'static + Add<Output = T> + Zero,
+{
+    let _span = timing_profiler::span(SpanCategory::Forward, "gather", &[tensor]);
+
+    let result_tensor;
+
+    if tensor.is_meta() {
+        result_tensor = meta_compute::meta_compute_gather(tensor, dim, indices);
+    } else {
+        result_tensor = compute_gather(tensor, dim, indices);
+    }
+
+    if tensor.does_require_grad() {
+        result_tensor.requires_grad_intermediate("gather");
+        gather_impl(Some(tensor), &result_tensor, Rc::new(indices.detach()), dim);
+    }
+
+    return result_tensor;
+}
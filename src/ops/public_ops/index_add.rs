@@ -0,0 +1,18 @@
+use ndarray::Axis;
+use num_traits::Zero;
+use std::fmt::Debug;
+use std::ops::Add;
+
+use crate::ops::public_ops::index_add_public::index_add_tensor;
+use crate::tensor_core::dtypes::DTComp;
+use crate::tensor_core::tensor::Tensor;
+
+impl<T> Tensor<T>
+where
+    T: Debug + DTComp + Clone + 'static + Add<Output = T> + Zero,
+{
+    #[track_caller]
+    pub fn index_add(&self, dim: Axis, indices: &Tensor<usize>, src: &Tensor<T>) -> Self {
+        return index_add_tensor(self, dim, indices, src);
+    }
+}
@@ -0,0 +1,46 @@
+use ndarray::Axis;
+use num_traits::Zero;
+use std::fmt::Debug;
+use std::ops::Add;
+use std::rc::Rc;
+
+use crate::ops::central::index_add_impl::index_add_impl;
+use crate::ops::compute::meta_compute;
+use crate::ops::compute::scatter_compute::compute_index_add;
+use crate::profiler::timing_profiler::{self, SpanCategory};
+use crate::tensor_core::dtypes::DTComp;
+use crate::tensor_core::tensor::Tensor;
+
+#[track_caller]
+pub fn index_add_tensor<T>(
+    tensor: &Tensor<T>,
+    dim: Axis,
+    indices: &Tensor<usize>,
+    src: &Tensor<T>,
+) -> Tensor<T>
+where
+    T: Clone + DTComp + Debug + 'static + Add<Output = T> + Zero,
+{
+    let _span = timing_profiler::span(SpanCategory::Forward, "index_add", &[tensor, src]);
+
+    let result_tensor;
+
+    if meta_compute::any_meta(&[tensor, src]) {
+        result_tensor = meta_compute::meta_compute_index_add(tensor, dim, indices, src);
+    } else {
+        result_tensor = compute_index_add(tensor, dim, indices, src);
+    }
+
+    if tensor.does_require_grad() || src.does_require_grad() {
+        result_tensor.requires_grad_intermediate("index_add");
+        index_add_impl(
+            Some(tensor),
+            Some(src),
+            &result_tensor,
+            Rc::new(indices.detach()),
+            dim,
+        );
+    }
+
+    return result_tensor;
+}
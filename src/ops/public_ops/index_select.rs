@@ -0,0 +1,18 @@
+use ndarray::Axis;
+use num_traits::Zero;
+use std::fmt::Debug;
+use std::ops::Add;
+
+use crate::ops::public_ops::index_select_public::index_select_tensor;
+use crate::tensor_core::dtypes::DTComp;
+use crate::tensor_core::tensor::Tensor;
+
+impl<T> Tensor<T>
+where
+    T: Debug + DTComp + Clone + 'static + Add<Output = T> + Zero,
+{
+    #[track_caller]
+    pub fn index_select(&self, dim: Axis, indices: &Tensor<usize>) -> Self {
+        return index_select_tensor(self, dim, indices);
+    }
+}
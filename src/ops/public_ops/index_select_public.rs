@@ -0,0 +1,42 @@
+use ndarray::Axis;
+use num_traits::Zero;
+use std::fmt::Debug;
+use std::ops::Add;
+
+use crate::ops::central::index_select_impl::index_select_impl;
+use crate::ops::compute::indexing_compute::compute_index_select;
+use crate::ops::compute::meta_compute;
+use crate::profiler::timing_profiler::{self, SpanCategory};
+use crate::tensor_core::dtypes::DTComp;
+use crate::tensor_core::tensor::Tensor;
+
+#[track_caller]
+pub fn index_select_tensor<T>(tensor: &Tensor<T>, dim: Axis, indices: &Tensor<usize>) -> Tensor<T>
+where
+    T: Clone + DTComp + Debug + 'static + Add<Output = T> + Zero,
+{
+    let _span = timing_profiler::span(SpanCategory::Forward, "index_select", &[tensor]);
+
+    let result_tensor;
+
+    if tensor.is_meta() {
+        result_tensor = meta_compute::meta_compute_index_select(tensor, dim, indices);
+    } else {
+        result_tensor = compute_index_select(tensor, dim, indices);
+    }
+
+    if tensor.does_require_grad() {
+        result_tensor.requires_grad_intermediate("index_select");
+
+        // the indices of a meta tensor have no values, and neither will its gradient
+        let indices = if indices.is_meta() {
+            vec![]
+        } else {
+            indices.get_raw_data().iter().copied().collect()
+        };
+
+        index_select_impl(Some(tensor), &result_tensor, indices, dim);
+    }
+
+    return result_tensor;
+}
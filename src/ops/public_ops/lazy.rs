@@ -0,0 +1,183 @@
+use num_traits::Float;
+use std::fmt::Debug;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::ops::lazy::lazy_expr::{BinaryKind, UnaryKind};
+use crate::ops::lazy::lazy_tensor::LazyTensor;
+use crate::ops::public_ops::lazy_public::eval_lazy_tensor;
+use crate::tensor_core::dtypes::DTComp;
+use crate::tensor_core::tensor::Tensor;
+
+impl<T> Tensor<T>
+where
+    T: DTComp + Debug,
+{
+    /// Start a lazy expression from this tensor. Element wise operations on the result are
+    /// recorded instead of computed, and `eval()` runs the whole chain as one fused kernel.
+    pub fn lazy(&self) -> LazyTensor<T> {
+        return LazyTensor::from_tensor(self);
+    }
+}
+
+impl<T> LazyTensor<T>
+where
+    T: DTComp + Debug + Float + 'static,
+{
+    pub fn exp(&self) -> Self {
+        return self.unary(UnaryKind::Exp);
+    }
+
+    pub fn exp2(&self) -> Self {
+        return self.unary(UnaryKind::Exp2);
+    }
+
+    pub fn ln(&self) -> Self {
+        return self.unary(UnaryKind::Ln);
+    }
+
+    pub fn log(&self, base: T) -> Self {
+        return self.unary(UnaryKind::Log(base));
+    }
+
+    pub fn tanh(&self) -> Self {
+        return self.unary(UnaryKind::Tanh);
+    }
+
+    pub fn pow(&self, base: T) -> Self {
+        return self.unary(UnaryKind::Pow(base));
+    }
+
+    #[track_caller]
+    pub fn eval(&self) -> Tensor<T> {
+        return eval_lazy_tensor(self);
+    }
+}
+
+impl<T> From<LazyTensor<T>> for Tensor<T>
+where
+    T: DTComp + Debug + Float + 'static,
+{
+    fn from(lazy_tensor: LazyTensor<T>) -> Self {
+        return lazy_tensor.eval();
+    }
+}
+
+impl<T> Neg for &LazyTensor<T>
+where
+    T: DTComp + Debug + Float + 'static,
+{
+    type Output = LazyTensor<T>;
+
+    fn neg(self) -> LazyTensor<T> {
+        return self.unary(UnaryKind::Neg);
+    }
+}
+
+impl<T> Neg for LazyTensor<T>
+where
+    T: DTComp + Debug + Float + 'static,
+{
+    type Output = LazyTensor<T>;
+
+    fn neg(self) -> LazyTensor<T> {
+        return -&self;
+    }
+}
+
+/// Implement a binary operator for every combination of lazy tensors, eager tensors and scalars
+macro_rules! impl_lazy_binary_op {
+    ($op_trait:ident, $op_fn:ident, $kind:expr) => {
+        impl<'a, T> $op_trait<&'a LazyTensor<T>> for &LazyTensor<T>
+        where
+            T: DTComp + Debug + Float + 'static,
+        {
+            type Output = LazyTensor<T>;
+
+            fn $op_fn(self, rhs: &'a LazyTensor<T>) -> LazyTensor<T> {
+                return self.binary($kind, rhs);
+            }
+        }
+
+        impl<'a, T> $op_trait<&'a LazyTensor<T>> for LazyTensor<T>
+        where
+            T: DTComp + Debug + Float + 'static,
+        {
+            type Output = LazyTensor<T>;
+
+            fn $op_fn(self, rhs: &'a LazyTensor<T>) -> LazyTensor<T> {
+                return self.binary($kind, rhs);
+            }
+        }
+
+        impl<T> $op_trait<LazyTensor<T>> for &LazyTensor<T>
+        where
+            T: DTComp + Debug + Float + 'static,
+        {
+            type Output = LazyTensor<T>;
+
+            fn $op_fn(self, rhs: LazyTensor<T>) -> LazyTensor<T> {
+                return self.binary($kind, &rhs);
+            }
+        }
+
+        impl<T> $op_trait<LazyTensor<T>> for LazyTensor<T>
+        where
+            T: DTComp + Debug + Float + 'static,
+        {
+            type Output = LazyTensor<T>;
+
+            fn $op_fn(self, rhs: LazyTensor<T>) -> LazyTensor<T> {
+                return self.binary($kind, &rhs);
+            }
+        }
+
+        impl<'a, T> $op_trait<&'a Tensor<T>> for &LazyTensor<T>
+        where
+            T: DTComp + Debug + Float + 'static,
+        {
+            type Output = LazyTensor<T>;
+
+            fn $op_fn(self, rhs: &'a Tensor<T>) -> LazyTensor<T> {
+                return self.binary($kind, &rhs.lazy());
+            }
+        }
+
+        impl<'a, T> $op_trait<&'a Tensor<T>> for LazyTensor<T>
+        where
+            T: DTComp + Debug + Float + 'static,
+        {
+            type Output = LazyTensor<T>;
+
+            fn $op_fn(self, rhs: &'a Tensor<T>) -> LazyTensor<T> {
+                return self.binary($kind, &rhs.lazy());
+            }
+        }
+
+        impl<T> $op_trait<T> for &LazyTensor<T>
+        where
+            T: DTComp + Debug + Float + 'static,
+        {
+            type Output = LazyTensor<T>;
+
+            fn $op_fn(self, rhs: T) -> LazyTensor<T> {
+                return self.binary($kind, &LazyTensor::from_scalar(rhs));
+            }
+        }
+
+        impl<T> $op_trait<T> for LazyTensor<T>
+        where
+            T: DTComp + Debug + Float + 'static,
+        {
+            type Output = LazyTensor<T>;
+
+            fn $op_fn(self, rhs: T) -> LazyTensor<T> {
+                return self.binary($kind, &LazyTensor::from_scalar(rhs));
+            }
+        }
+    };
+}
+
+impl_lazy_binary_op!(Add, add, BinaryKind::Add);
+impl_lazy_binary_op!(Sub, sub, BinaryKind::Sub);
+impl_lazy_binary_op!(Mul, mul, BinaryKind::Mul);
+impl_lazy_binary_op!(Div, div, BinaryKind::Div);
@@ -0,0 +1,39 @@
+use num_traits::Float;
+use std::fmt::Debug;
+use std::rc::Rc;
+
+use crate::ops::central::fused_impl::fused_impl;
+use crate::ops::compute::fused_compute::fused_compute_forward;
+use crate::ops::lazy::fused_program::FusedProgram;
+use crate::ops::lazy::lazy_tensor::LazyTensor;
+use crate::profiler::timing_profiler::{self, SpanCategory};
+use crate::tensor_core::dtypes::DTComp;
+use crate::tensor_core::tensor::Tensor;
+
+/// Compile the expression of a lazy tensor into one fused kernel and run it
+#[track_caller]
+pub fn eval_lazy_tensor<T>(lazy_tensor: &LazyTensor<T>) -> Tensor<T>
+where
+    T: DTComp + Debug + Float + 'static,
+{
+    let _span = timing_profiler::span_with_shapes::<T, _>(SpanCategory::Forward, "fused", || {
+        vec![lazy_tensor.get_shape()]
+    });
+
+    let (program, inputs) = FusedProgram::compile(lazy_tensor.get_expr());
+    let result_tensor;
+
+    if inputs.iter().any(|input| input.borrow().is_meta_()) {
+        result_tensor = Tensor::meta(lazy_tensor.get_shape(), false);
+    } else {
+        result_tensor = fused_compute_forward(&program, &inputs);
+    }
+
+    if lazy_tensor.does_require_grad() {
+        result_tensor.requires_grad_intermediate("fused");
+
+        fused_impl(inputs, &result_tensor, Rc::new(program));
+    }
+
+    return result_tensor;
+}
@@ -1,19 +1,29 @@
-use num_traits::Float;
 use std::fmt::Debug;
 
 use crate::ops::central::ln_impl::ln_impl;
 use crate::ops::compute::log_compute::ln_compute_tensor;
-use crate::tensor_core::dtypes::DTComp;
+use crate::ops::compute::meta_compute;
+use crate::profiler::timing_profiler::{self, SpanCategory};
+use crate::tensor_core::dtypes::{DTComp, DTFloat};
 use crate::tensor_core::tensor::Tensor;
 
+#[track_caller]
 pub fn ln_tensor<T>(tensor: &Tensor<T>) -> Tensor<T>
 where
-    T: Debug + DTComp + Float + 'static,
+    T: Debug + DTComp + DTFloat + 'static,
 {
-    let result_tensor = ln_compute_tensor(tensor);
+    let _span = timing_profiler::span(SpanCategory::Forward, "ln", &[tensor]);
+
+    let result_tensor;
+
+    if tensor.is_meta() {
+        result_tensor = meta_compute::meta_compute_like(tensor);
+    } else {
+        result_tensor = ln_compute_tensor(tensor);
+    }
 
     if tensor.does_require_grad() {
-        result_tensor.requires_grad_intermediate("Intermediate tensor from natural log");
+        result_tensor.requires_grad_intermediate("ln");
         ln_impl(Some(tensor), &result_tensor);
     }
 
@@ -10,6 +10,7 @@ impl<T> Tensor<T>
 where
     T: DTComp + Debug + Float + 'static + ScalarOperand,
 {
+    #[track_caller]
     pub fn log(&self, base: T) -> Self {
         return log_tensor(self, base);
     }
@@ -4,17 +4,28 @@ use std::fmt::Debug;
 
 use crate::ops::central::log_impl::log_impl;
 use crate::ops::compute::log_compute::log_compute_tensor;
+use crate::ops::compute::meta_compute;
+use crate::profiler::timing_profiler::{self, SpanCategory};
 use crate::tensor_core::dtypes::DTComp;
 use crate::tensor_core::tensor::Tensor;
 
+#[track_caller]
 pub fn log_tensor<T>(tensor: &Tensor<T>, base: T) -> Tensor<T>
 where
     T: Debug + DTComp + Float + 'static + ScalarOperand,
 {
-    let new_tensor = log_compute_tensor(tensor, base);
+    let _span = timing_profiler::span(SpanCategory::Forward, "log", &[tensor]);
+
+    let new_tensor;
+
+    if tensor.is_meta() {
+        new_tensor = meta_compute::meta_compute_like(tensor);
+    } else {
+        new_tensor = log_compute_tensor(tensor, base);
+    }
 
     if tensor.does_require_grad() {
-        new_tensor.requires_grad_intermediate("Intermediate tensor from log with arbitrary base");
+        new_tensor.requires_grad_intermediate("log");
         log_impl(Some(tensor), &new_tensor, Some(base.clone()));
     }
 
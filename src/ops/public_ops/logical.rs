@@ -0,0 +1,52 @@
+use ndarray::Axis;
+use num_traits::{One, Zero};
+use std::fmt::Debug;
+
+use crate::ops::public_ops::logical_public::{
+    logical_not_tensor, logical_tensor_tensor, mask_as_numeric_tensor, reduce_logical_tensor,
+};
+use crate::tensor_core::dtypes::DTComp;
+use crate::tensor_core::tensor::Tensor;
+
+impl Tensor<bool> {
+    #[track_caller]
+    pub fn logical_and(&self, other: &Tensor<bool>) -> Tensor<bool> {
+        return logical_tensor_tensor(self, other, "logical_and", |a, b| *a && *b);
+    }
+
+    #[track_caller]
+    pub fn logical_or(&self, other: &Tensor<bool>) -> Tensor<bool> {
+        return logical_tensor_tensor(self, other, "logical_or", |a, b| *a || *b);
+    }
+
+    #[track_caller]
+    pub fn logical_xor(&self, other: &Tensor<bool>) -> Tensor<bool> {
+        return logical_tensor_tensor(self, other, "logical_xor", |a, b| *a != *b);
+    }
+
+    #[track_caller]
+    pub fn logical_not(&self) -> Tensor<bool> {
+        return logical_not_tensor(self);
+    }
+
+    /// Whether any element along `dim` is true
+    #[track_caller]
+    pub fn any(&self, dim: Axis) -> Tensor<bool> {
+        return reduce_logical_tensor(self, dim, "any");
+    }
+
+    /// Whether every element along `dim` is true
+    #[track_caller]
+    pub fn all(&self, dim: Axis) -> Tensor<bool> {
+        return reduce_logical_tensor(self, dim, "all");
+    }
+
+    /// Ones where the mask is true and zeros elsewhere, as in counting correct predictions
+    #[track_caller]
+    pub fn as_numeric<U>(&self) -> Tensor<U>
+    where
+        U: Debug + DTComp + Clone + Zero + One,
+    {
+        return mask_as_numeric_tensor(self);
+    }
+}
@@ -0,0 +1,70 @@
+use ndarray::Axis;
+use num_traits::{One, Zero};
+use std::fmt::Debug;
+
+use crate::ops::compute::logical_compute::{
+    compute_all, compute_any, compute_logical_not, compute_mask_as_numeric, compute_zip_with,
+};
+use crate::ops::compute::meta_compute;
+use crate::profiler::timing_profiler::{self, SpanCategory};
+use crate::tensor_core::dtypes::DTComp;
+use crate::tensor_core::tensor::Tensor;
+
+#[track_caller]
+pub fn logical_tensor_tensor<F>(
+    lhs_tensor: &Tensor<bool>,
+    rhs_tensor: &Tensor<bool>,
+    name: &str,
+    f: F,
+) -> Tensor<bool>
+where
+    F: Fn(&bool, &bool) -> bool,
+{
+    let _span = timing_profiler::span(SpanCategory::Forward, name, &[lhs_tensor, rhs_tensor]);
+
+    if meta_compute::any_meta(&[lhs_tensor, rhs_tensor]) {
+        return meta_compute::meta_compute_compare(lhs_tensor, rhs_tensor);
+    }
+
+    return compute_zip_with(lhs_tensor, rhs_tensor, f);
+}
+
+#[track_caller]
+pub fn logical_not_tensor(tensor: &Tensor<bool>) -> Tensor<bool> {
+    let _span = timing_profiler::span(SpanCategory::Forward, "logical_not", &[tensor]);
+
+    if tensor.is_meta() {
+        return meta_compute::meta_compute_like(tensor);
+    }
+
+    return compute_logical_not(tensor);
+}
+
+#[track_caller]
+pub fn reduce_logical_tensor(tensor: &Tensor<bool>, dim: Axis, name: &str) -> Tensor<bool> {
+    let _span = timing_profiler::span(SpanCategory::Forward, name, &[tensor]);
+
+    if tensor.is_meta() {
+        return meta_compute::meta_compute_reduce(tensor, dim);
+    }
+
+    if name == "any" {
+        return compute_any(tensor, dim);
+    } else {
+        return compute_all(tensor, dim);
+    }
+}
+
+#[track_caller]
+pub fn mask_as_numeric_tensor<U>(tensor: &Tensor<bool>) -> Tensor<U>
+where
+    U: Debug + DTComp + Clone + Zero + One,
+{
+    let _span = timing_profiler::span(SpanCategory::Forward, "as_numeric", &[tensor]);
+
+    if tensor.is_meta() {
+        return Tensor::meta(tensor.get_shape().clone(), false);
+    }
+
+    return compute_mask_as_numeric(tensor);
+}
@@ -0,0 +1,18 @@
+use num_traits::Zero;
+use std::fmt::Debug;
+use std::ops::Add;
+
+use crate::ops::public_ops::masked_fill_public::masked_fill_tensor;
+use crate::tensor_core::dtypes::DTComp;
+use crate::tensor_core::tensor::Tensor;
+
+impl<T> Tensor<T>
+where
+    T: Debug + DTComp + Clone + 'static + Add<Output = T> + Zero,
+{
+    /// Replaces the elements where `mask` is true with `value`
+    #[track_caller]
+    pub fn masked_fill(&self, mask: &Tensor<bool>, value: T) -> Self {
+        return masked_fill_tensor(self, mask, value);
+    }
+}
@@ -0,0 +1,39 @@
+use num_traits::Zero;
+use std::fmt::Debug;
+use std::ops::Add;
+use std::rc::Rc;
+
+use crate::ops::central::masked_fill_impl::masked_fill_impl;
+use crate::ops::compute::indexing_compute::compute_mask_like;
+use crate::ops::compute::logical_compute::compute_masked_fill;
+use crate::ops::compute::meta_compute;
+use crate::profiler::timing_profiler::{self, SpanCategory};
+use crate::tensor_core::dtypes::DTComp;
+use crate::tensor_core::tensor::Tensor;
+
+#[track_caller]
+pub fn masked_fill_tensor<T>(tensor: &Tensor<T>, mask: &Tensor<bool>, value: T) -> Tensor<T>
+where
+    T: Clone + DTComp + Debug + 'static + Add<Output = T> + Zero,
+{
+    let _span = timing_profiler::span(SpanCategory::Forward, "masked_fill", &[tensor]);
+
+    let result_tensor;
+
+    if tensor.is_meta() || mask.is_meta() {
+        result_tensor = meta_compute::meta_compute_masked_fill(tensor, mask);
+    } else {
+        result_tensor = compute_masked_fill(tensor, mask, value);
+    }
+
+    if tensor.does_require_grad() {
+        result_tensor.requires_grad_intermediate("masked_fill");
+        masked_fill_impl(
+            Some(tensor),
+            &result_tensor,
+            Rc::new(compute_mask_like(tensor, mask)),
+        );
+    }
+
+    return result_tensor;
+}
@@ -0,0 +1,38 @@
+use num_traits::Zero;
+use std::fmt::Debug;
+use std::ops::Add;
+use std::rc::Rc;
+
+use crate::ops::central::masked_select_impl::masked_select_impl;
+use crate::ops::compute::indexing_compute::{compute_mask_like, compute_masked_select};
+use crate::ops::compute::meta_compute;
+use crate::profiler::timing_profiler::{self, SpanCategory};
+use crate::tensor_core::dtypes::DTComp;
+use crate::tensor_core::tensor::Tensor;
+
+#[track_caller]
+pub fn masked_select_tensor<T>(tensor: &Tensor<T>, mask: &Tensor<bool>) -> Tensor<T>
+where
+    T: Clone + DTComp + Debug + 'static + Add<Output = T> + Zero,
+{
+    let _span = timing_profiler::span(SpanCategory::Forward, "masked_select", &[tensor]);
+
+    let result_tensor;
+
+    if tensor.is_meta() {
+        result_tensor = meta_compute::meta_compute_masked_select(tensor, mask);
+    } else {
+        result_tensor = compute_masked_select(tensor, mask);
+    }
+
+    if tensor.does_require_grad() {
+        result_tensor.requires_grad_intermediate("masked_select");
+        masked_select_impl(
+            Some(tensor),
+            &result_tensor,
+            Rc::new(compute_mask_like(tensor, mask)),
+        );
+    }
+
+    return result_tensor;
+}
@@ -6,12 +6,14 @@ use crate::ops::public_ops::matmul_public::matmul_tensor_tensor;
 use crate::tensor_core::dtypes::DTComp;
 use crate::tensor_core::tensor::Tensor;
 
+#[track_caller]
 pub fn matmul_on_tensor<T>(lhs_tensor: Tensor<T>, rhs_tensor: Tensor<T>) -> Tensor<T>
 where
     T: DTComp + Clone + Debug + Add<Output = T> + 'static + LinalgScalar,
 {
     return matmul(&lhs_tensor, &rhs_tensor);
 }
+#[track_caller]
 pub fn matmul<T>(lhs_tensor: &Tensor<T>, rhs_tensor: &Tensor<T>) -> Tensor<T>
 where
     T: DTComp + Clone + Debug + Add<Output = T> + 'static + LinalgScalar,
@@ -4,17 +4,28 @@ use std::ops::Add;
 
 use crate::ops::central::matmul_impl::matmul_impl;
 use crate::ops::compute::matmul_compute::matmul_compute_tensor_tensor;
+use crate::ops::compute::meta_compute;
+use crate::profiler::timing_profiler::{self, SpanCategory};
 use crate::tensor_core::dtypes::DTComp;
 use crate::tensor_core::tensor::Tensor;
 
+#[track_caller]
 pub fn matmul_tensor_tensor<T>(lhs_tensor: &Tensor<T>, rhs_tensor: &Tensor<T>) -> Tensor<T>
 where
     T: DTComp + Clone + Debug + Add<Output = T> + 'static + LinalgScalar,
 {
-    let result_tensor = matmul_compute_tensor_tensor(lhs_tensor, rhs_tensor);
+    let _span = timing_profiler::span(SpanCategory::Forward, "matmul", &[lhs_tensor, rhs_tensor]);
+
+    let result_tensor;
+
+    if meta_compute::any_meta(&[lhs_tensor, rhs_tensor]) {
+        result_tensor = meta_compute::meta_compute_matmul(lhs_tensor, rhs_tensor);
+    } else {
+        result_tensor = matmul_compute_tensor_tensor(lhs_tensor, rhs_tensor);
+    }
 
     if lhs_tensor.does_require_grad() || rhs_tensor.does_require_grad() {
-        result_tensor.requires_grad_intermediate("Intermediate tensor from matmul operation");
+        result_tensor.requires_grad_intermediate("matmul");
     }
 
     matmul_impl(Some(lhs_tensor), Some(rhs_tensor), &result_tensor);
@@ -10,10 +10,12 @@ impl<T> Tensor<T>
 where
     T: Debug + DTComp + Clone + PartialOrd + Bounded + Zero + 'static + Float,
 {
+    #[track_caller]
     pub fn max(&self, dim: Axis) -> Self {
         return max_tensor(self, dim);
     }
 
+    #[track_caller]
     pub fn argmax(&self, dim: Axis) -> Tensor<usize> {
         return argmax_tensor(self, dim);
     }
@@ -18,6 +18,7 @@ where
         + Div<Output = T>
         + ScalarOperand,
 {
+    #[track_caller]
     pub fn mean(&self, dim: Axis) -> Self {
         return mean_tensor(self, dim);
     }
@@ -4,10 +4,13 @@ use std::fmt::Debug;
 use std::ops::{Add, Div};
 
 use crate::ops::central::mean_impl::mean_impl;
+use crate::ops::compute::meta_compute;
 use crate::ops::compute::sum_mean_compute::mean_compute_tensor;
+use crate::profiler::timing_profiler::{self, SpanCategory};
 use crate::tensor_core::dtypes::DTComp;
 use crate::tensor_core::tensor::Tensor;
 
+#[track_caller]
 pub fn mean_tensor<T>(tensor: &Tensor<T>, dim: Axis) -> Tensor<T>
 where
     T: Debug
@@ -19,10 +22,18 @@ where
         + Div<Output = T>
         + ScalarOperand,
 {
-    let result_tensor = mean_compute_tensor(tensor, dim);
+    let _span = timing_profiler::span(SpanCategory::Forward, "mean", &[tensor]);
+
+    let result_tensor;
+
+    if tensor.is_meta() {
+        result_tensor = meta_compute::meta_compute_reduce(tensor, dim);
+    } else {
+        result_tensor = mean_compute_tensor(tensor, dim);
+    }
 
     if tensor.does_require_grad() {
-        result_tensor.requires_grad_intermediate("Intermediate tensor from min");
+        result_tensor.requires_grad_intermediate("mean");
         mean_impl(Some(tensor), &result_tensor, dim);
     }
 
@@ -10,10 +10,12 @@ impl<T> Tensor<T>
 where
     T: Debug + DTComp + Clone + PartialOrd + Bounded + Zero + 'static + Float,
 {
+    #[track_caller]
     pub fn min(&self, dim: Axis) -> Self {
         return min_tensor(self, dim);
     }
 
+    #[track_caller]
     pub fn argmin(&self, dim: Axis) -> Tensor<usize> {
         return argmin_tensor(self, dim);
     }
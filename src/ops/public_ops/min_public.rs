@@ -5,18 +5,36 @@ use std::rc::Rc;
 
 use crate::ops::central::min_impl::min_impl;
 use crate::ops::compute::max_min_compute::{argmin_compute_tensor, min_compute_tensor};
+use crate::ops::compute::meta_compute;
+use crate::profiler::timing_profiler::{self, SpanCategory};
 use crate::tensor_core::dtypes::DTComp;
 use crate::tensor_core::tensor::Tensor;
 
+#[track_caller]
 pub fn min_tensor<T>(tensor: &Tensor<T>, dim: Axis) -> Tensor<T>
 where
     T: Debug + DTComp + Clone + PartialOrd + Bounded + Zero + 'static + Float,
 {
+    let _span = timing_profiler::span(SpanCategory::Forward, "min", &[tensor]);
+
+    if tensor.is_meta() {
+        let new_tensor = meta_compute::meta_compute_reduce(tensor, dim);
+
+        if tensor.does_require_grad() {
+            let indices = meta_compute::meta_compute_reduce(tensor, dim);
+            new_tensor.requires_grad_intermediate("min");
+
+            min_impl(Some(tensor), &new_tensor, Rc::new(indices), dim);
+        }
+
+        return new_tensor;
+    }
+
     if tensor.does_require_grad() {
         let (indices, new_tensor) = argmin_compute_tensor(tensor, dim, true);
         let new_tensor =
             new_tensor.expect("Internal error, no tensor found after calling min operation");
-        new_tensor.requires_grad_intermediate("Intermediate tensor from min");
+        new_tensor.requires_grad_intermediate("min");
 
         min_impl(Some(tensor), &new_tensor, Rc::new(indices), dim);
 
@@ -27,10 +45,17 @@ where
     }
 }
 
+#[track_caller]
 pub fn argmin_tensor<T>(tensor: &Tensor<T>, dim: Axis) -> Tensor<usize>
 where
     T: Debug + DTComp + Clone + PartialOrd + Bounded + Zero + 'static + Float,
 {
+    let _span = timing_profiler::span(SpanCategory::Forward, "argmin", &[tensor]);
+
+    if tensor.is_meta() {
+        return meta_compute::meta_compute_reduce(tensor, dim);
+    }
+
     let (indices, _) = argmin_compute_tensor(tensor, dim, false);
     return indices;
 }
@@ -13,6 +13,7 @@ where
 {
     type Output = Tensor<T>;
 
+    #[track_caller]
     fn mul(self, rhs: S) -> Self::Output {
         return mul_tensor_scalar(self, rhs);
     }
@@ -25,6 +26,7 @@ where
 {
     type Output = Tensor<T>;
 
+    #[track_caller]
     fn mul(self, rhs: S) -> Self::Output {
         return &self * rhs;
     }
@@ -36,6 +38,7 @@ where
 {
     type Output = Tensor<T>;
 
+    #[track_caller]
     fn mul(self, rhs: &'tl_out Tensor<T>) -> Self::Output {
         return mul_tensor_tensor(self, rhs);
     }
@@ -47,6 +50,7 @@ where
 {
     type Output = Tensor<T>;
 
+    #[track_caller]
     fn mul(self, rhs: &'tl Tensor<T>) -> Self::Output {
         return mul_tensor_tensor(&self, rhs);
     }
@@ -58,6 +62,7 @@ where
 {
     type Output = Tensor<T>;
 
+    #[track_caller]
     fn mul(self, rhs: Tensor<T>) -> Self::Output {
         return &self * &rhs;
     }
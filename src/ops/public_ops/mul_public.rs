@@ -3,18 +3,29 @@ use std::fmt::Debug;
 use std::ops::{Add, Mul};
 
 use crate::ops::central::mul_impl::mul_impl;
+use crate::ops::compute::meta_compute;
 use crate::ops::compute::mul_compute::{mul_compute_tensor_scalar, mul_compute_tensor_tensor};
+use crate::profiler::timing_profiler::{self, SpanCategory};
 use crate::tensor_core::dtypes::DTComp;
 use crate::tensor_core::tensor::Tensor;
 
+#[track_caller]
 pub fn mul_tensor_tensor<T>(lhs_tensor: &Tensor<T>, rhs_tensor: &Tensor<T>) -> Tensor<T>
 where
     T: DTComp + Clone + Debug + Mul<Output = T> + Add<Output = T> + 'static + ScalarOperand,
 {
-    let result_tensor = mul_compute_tensor_tensor(lhs_tensor, rhs_tensor);
+    let _span = timing_profiler::span(SpanCategory::Forward, "mul", &[lhs_tensor, rhs_tensor]);
+
+    let result_tensor;
+
+    if meta_compute::any_meta(&[lhs_tensor, rhs_tensor]) {
+        result_tensor = meta_compute::meta_compute_broadcast_binary(lhs_tensor, rhs_tensor);
+    } else {
+        result_tensor = mul_compute_tensor_tensor(lhs_tensor, rhs_tensor);
+    }
 
     if lhs_tensor.does_require_grad() || rhs_tensor.does_require_grad() {
-        result_tensor.requires_grad_intermediate("Intermediate tensor from mul");
+        result_tensor.requires_grad_intermediate("mul");
     }
 
     mul_impl(Some(lhs_tensor), Some(rhs_tensor), &result_tensor, None);
@@ -22,15 +33,24 @@ where
     return result_tensor;
 }
 
+#[track_caller]
 pub fn mul_tensor_scalar<T, S>(tensor: &Tensor<T>, scalar: S) -> Tensor<T>
 where
     T: DTComp + Clone + Debug + Mul<Output = T> + Mul<S, Output = T> + Add<Output = T> + 'static,
     S: ScalarOperand + Debug + Clone,
 {
-    let result_tensor = mul_compute_tensor_scalar(tensor, scalar.clone());
+    let _span = timing_profiler::span(SpanCategory::Forward, "mul", &[tensor]);
+
+    let result_tensor;
+
+    if tensor.is_meta() {
+        result_tensor = meta_compute::meta_compute_like(tensor);
+    } else {
+        result_tensor = mul_compute_tensor_scalar(tensor, scalar.clone());
+    }
 
     if tensor.does_require_grad() {
-        result_tensor.requires_grad_intermediate("Intermediate tensor from mul");
+        result_tensor.requires_grad_intermediate("mul");
     }
 
     mul_impl(Some(tensor), None, &result_tensor, Some(scalar.clone()));
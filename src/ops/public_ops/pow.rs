@@ -1,15 +1,18 @@
 use std::fmt::Debug;
 
 use ndarray::ScalarOperand;
-use num_traits::Float;
 
 use crate::ops::public_ops::pow_public::pow_tensor;
-use crate::tensor_core::{dtypes::DTComp, tensor::Tensor};
+use crate::tensor_core::{
+    dtypes::{DTComp, DTFloat},
+    tensor::Tensor,
+};
 
 impl<T> Tensor<T>
 where
-    T: DTComp + Debug + Float + 'static + ScalarOperand,
+    T: DTComp + Debug + DTFloat + 'static + ScalarOperand,
 {
+    #[track_caller]
     pub fn pow(&self, base: T) -> Self {
         return pow_tensor(self, base);
     }
@@ -1,20 +1,30 @@
 use ndarray::ScalarOperand;
-use num_traits::Float;
 use std::fmt::Debug;
 
 use crate::ops::central::pow_impl::pow_impl;
+use crate::ops::compute::meta_compute;
 use crate::ops::compute::pow_compute::pow_compute_tensor;
-use crate::tensor_core::dtypes::DTComp;
+use crate::profiler::timing_profiler::{self, SpanCategory};
+use crate::tensor_core::dtypes::{DTComp, DTFloat};
 use crate::tensor_core::tensor::Tensor;
 
+#[track_caller]
 pub fn pow_tensor<T>(tensor: &Tensor<T>, base: T) -> Tensor<T>
 where
-    T: Debug + DTComp + Float + 'static + ScalarOperand,
+    T: Debug + DTComp + DTFloat + 'static + ScalarOperand,
 {
-    let new_tensor = pow_compute_tensor(tensor, base);
+    let _span = timing_profiler::span(SpanCategory::Forward, "pow", &[tensor]);
+
+    let new_tensor;
+
+    if tensor.is_meta() {
+        new_tensor = meta_compute::meta_compute_like(tensor);
+    } else {
+        new_tensor = pow_compute_tensor(tensor, base);
+    }
 
     if tensor.does_require_grad() {
-        new_tensor.requires_grad_intermediate("Intermediate tensor expoential with arbitrary base");
+        new_tensor.requires_grad_intermediate("pow");
         pow_impl(Some(tensor), &new_tensor, Some(base.clone()));
     }
 
@@ -0,0 +1,159 @@
+use ndarray::ScalarOperand;
+use num_traits::AsPrimitive;
+use std::fmt::Debug;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::tensor_core::dtypes::{DTComp, Promote};
+use crate::tensor_core::tensor::Tensor;
+
+// Binary operations between tensors of different element types. Both operands are cast to the
+// promoted type of `Promote` with the differentiable `Tensor::to`, so the gradient of each operand
+// comes back in its own type. These are methods rather than operators, since operator impls for
+// every pair of types would leave the element type of tensors built from literals ambiguous.
+
+impl<T> Tensor<T>
+where
+    T: Debug + DTComp + Copy + Add<Output = T>,
+{
+    /// Casts both tensors to their promoted element type
+    #[track_caller]
+    pub fn promote_with<R>(
+        &self,
+        rhs: &Tensor<R>,
+    ) -> (
+        Tensor<<T as Promote<R>>::Output>,
+        Tensor<<T as Promote<R>>::Output>,
+    )
+    where
+        T: Promote<R> + AsPrimitive<<T as Promote<R>>::Output>,
+        R: Debug + DTComp + Copy + Add<Output = R> + AsPrimitive<<T as Promote<R>>::Output>,
+        <T as Promote<R>>::Output: Debug
+            + Copy
+            + Add<Output = <T as Promote<R>>::Output>
+            + AsPrimitive<T>
+            + AsPrimitive<R>,
+    {
+        return (self.to(), rhs.to());
+    }
+
+    #[track_caller]
+    pub fn add_promoted<R>(&self, rhs: &Tensor<R>) -> Tensor<<T as Promote<R>>::Output>
+    where
+        T: Promote<R> + AsPrimitive<<T as Promote<R>>::Output>,
+        R: Debug + DTComp + Copy + Add<Output = R> + AsPrimitive<<T as Promote<R>>::Output>,
+        <T as Promote<R>>::Output: Debug
+            + Copy
+            + Add<Output = <T as Promote<R>>::Output>
+            + AsPrimitive<T>
+            + AsPrimitive<R>,
+    {
+        let (lhs, rhs) = self.promote_with(rhs);
+        return &lhs + &rhs;
+    }
+
+    #[track_caller]
+    pub fn sub_promoted<R>(&self, rhs: &Tensor<R>) -> Tensor<<T as Promote<R>>::Output>
+    where
+        T: Promote<R> + AsPrimitive<<T as Promote<R>>::Output>,
+        R: Debug + DTComp + Copy + Add<Output = R> + AsPrimitive<<T as Promote<R>>::Output>,
+        <T as Promote<R>>::Output: Debug
+            + Copy
+            + Add<Output = <T as Promote<R>>::Output>
+            + Sub<Output = <T as Promote<R>>::Output>
+            + Neg<Output = <T as Promote<R>>::Output>
+            + AsPrimitive<T>
+            + AsPrimitive<R>,
+    {
+        let (lhs, rhs) = self.promote_with(rhs);
+        return &lhs - &rhs;
+    }
+
+    #[track_caller]
+    pub fn mul_promoted<R>(&self, rhs: &Tensor<R>) -> Tensor<<T as Promote<R>>::Output>
+    where
+        T: Promote<R> + AsPrimitive<<T as Promote<R>>::Output>,
+        R: Debug + DTComp + Copy + Add<Output = R> + AsPrimitive<<T as Promote<R>>::Output>,
+        <T as Promote<R>>::Output: Debug
+            + Copy
+            + Add<Output = <T as Promote<R>>::Output>
+            + Mul<Output = <T as Promote<R>>::Output>
+            + ScalarOperand
+            + AsPrimitive<T>
+            + AsPrimitive<R>,
+    {
+        let (lhs, rhs) = self.promote_with(rhs);
+        return &lhs * &rhs;
+    }
+
+    #[track_caller]
+    pub fn div_promoted<R>(&self, rhs: &Tensor<R>) -> Tensor<<T as Promote<R>>::Output>
+    where
+        T: Promote<R> + AsPrimitive<<T as Promote<R>>::Output>,
+        R: Debug + DTComp + Copy + Add<Output = R> + AsPrimitive<<T as Promote<R>>::Output>,
+        <T as Promote<R>>::Output: Debug
+            + Copy
+            + Add<Output = <T as Promote<R>>::Output>
+            + Div<Output = <T as Promote<R>>::Output>
+            + ScalarOperand
+            + Mul<Output = <T as Promote<R>>::Output>
+            + Neg<Output = <T as Promote<R>>::Output>
+            + AsPrimitive<T>
+            + AsPrimitive<R>,
+    {
+        let (lhs, rhs) = self.promote_with(rhs);
+        return &lhs / &rhs;
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    #[allow(unused)]
+    use super::*;
+    use crate::tensor_core::dtypes::DTypes;
+
+    #[test]
+    fn mixed_type_ops_promote_and_backpropagate() {
+        let x = Tensor::new(vec![1.0_f32, 2.0, 3.0], vec![3], true);
+        let y = Tensor::new(vec![2.0_f64, 4.0, 8.0], vec![3], true);
+        let n = Tensor::new(vec![1_i32, 2, 3], vec![3], false);
+
+        let z = x.mul_promoted(&y).sub_promoted(&n);
+        assert_eq!(z.get_type(), DTypes::Float64);
+        assert_eq!(
+            z.get_raw_data().iter().copied().collect::<Vec<_>>(),
+            vec![1.0, 6.0, 21.0]
+        );
+
+        z.backward(Tensor::new(vec![1.0, 1.0, 1.0], vec![3], false), false);
+        assert_eq!(x.grad().unwrap().get_type(), DTypes::Float32);
+        assert_eq!(
+            x.grad()
+                .unwrap()
+                .get_raw_data()
+                .iter()
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![2.0, 4.0, 8.0]
+        );
+        assert_eq!(
+            y.grad()
+                .unwrap()
+                .get_raw_data()
+                .iter()
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![1.0, 2.0, 3.0]
+        );
+
+        let u = Tensor::new(vec![200_u8, 100], vec![2], false);
+        let i = Tensor::new(vec![-100_i8, 100], vec![2], false);
+        assert_eq!(
+            u.add_promoted(&i)
+                .get_raw_data()
+                .iter()
+                .copied()
+                .collect::<Vec<_>>(),
+            vec![100_i16, 200]
+        );
+    }
+}
@@ -9,6 +9,7 @@ impl<T> Tensor<T>
 where
     T: Debug + DTComp + Clone + 'static + Add<Output = T>,
 {
+    #[track_caller]
     pub fn reshape(&self, axes_option: Vec<usize>) -> Self {
         return reshape_tensor(self, axes_option);
     }
@@ -2,18 +2,29 @@ use std::fmt::Debug;
 use std::ops::Add;
 
 use crate::ops::central::reshape_impl::reshape_impl;
+use crate::ops::compute::meta_compute;
 use crate::ops::compute::shape_compute::compute_reshape;
+use crate::profiler::timing_profiler::{self, SpanCategory};
 use crate::tensor_core::dtypes::DTComp;
 use crate::tensor_core::tensor::Tensor;
 
+#[track_caller]
 pub fn reshape_tensor<T>(tensor: &Tensor<T>, axes_option: Vec<usize>) -> Tensor<T>
 where
     T: Clone + DTComp + Debug + 'static + Add<Output = T>,
 {
-    let result_tensor = compute_reshape(tensor, axes_option.clone());
+    let _span = timing_profiler::span(SpanCategory::Forward, "reshape", &[tensor]);
+
+    let result_tensor;
+
+    if tensor.is_meta() {
+        result_tensor = meta_compute::meta_compute_reshape(tensor, axes_option.clone());
+    } else {
+        result_tensor = compute_reshape(tensor, axes_option.clone());
+    }
 
     if tensor.does_require_grad() {
-        result_tensor.requires_grad_intermediate("Intermediate tensor from min");
+        result_tensor.requires_grad_intermediate("reshape");
         reshape_impl(Some(tensor), &result_tensor, tensor.get_shape().to_vec());
     }
 
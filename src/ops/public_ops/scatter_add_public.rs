@@ -0,0 +1,46 @@
+use ndarray::Axis;
+use num_traits::Zero;
+use std::fmt::Debug;
+use std::ops::Add;
+use std::rc::Rc;
+
+use crate::ops::central::scatter_add_impl::scatter_add_impl;
+use crate::ops::compute::meta_compute;
+use crate::ops::compute::scatter_compute::compute_scatter_add;
+use crate::profiler::timing_profiler::{self, SpanCategory};
+use crate::tensor_core::dtypes::DTComp;
+use crate::tensor_core::tensor::Tensor;
+
+#[track_caller]
+pub fn scatter_add_tensor<T>(
+    tensor: &Tensor<T>,
+    dim: Axis,
+    indices: &Tensor<usize>,
+    src: &Tensor<T>,
+) -> Tensor<T>
+where
+    T: Clone + DTComp + Debug + 'static + Add<Output = T> + Zero,
+{
+    let _span = timing_profiler::span(SpanCategory::Forward, "scatter_add", &[tensor, src]);
+
+    let result_tensor;
+
+    if meta_compute::any_meta(&[tensor, src]) {
+        result_tensor = meta_compute::meta_compute_scatter_add(tensor, dim, indices, src);
+    } else {
+        result_tensor = compute_scatter_add(tensor, dim, indices, src);
+    }
+
+    if tensor.does_require_grad() || src.does_require_grad() {
+        result_tensor.requires_grad_intermediate("scatter_add");
+        scatter_add_impl(
+            Some(tensor),
+            Some(src),
+            &result_tensor,
+            Rc::new(indices.detach()),
+            dim,
+        );
+    }
+
+    return result_tensor;
+}
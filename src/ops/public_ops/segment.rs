@@ -0,0 +1,49 @@
+use num_traits::{NumCast, Zero};
+use std::fmt::Debug;
+use std::ops::{Add, Div};
+
+use crate::ops::public_ops::segment_public::{
+    segment_max_tensor, segment_mean_tensor, segment_sum_tensor,
+};
+use crate::tensor_core::dtypes::DTComp;
+use crate::tensor_core::tensor::Tensor;
+
+/// Sums the rows of `data` into `num_segments` rows, where row `i` is added to row `segment_ids[i]`
+#[track_caller]
+pub fn segment_sum<T>(
+    data: &Tensor<T>,
+    segment_ids: &Tensor<usize>,
+    num_segments: usize,
+) -> Tensor<T>
+where
+    T: Clone + DTComp + Debug + 'static + Add<Output = T> + Zero,
+{
+    return segment_sum_tensor(data, segment_ids, num_segments);
+}
+
+/// Averages the rows of `data` in every segment, segments without any row are zero
+#[track_caller]
+pub fn segment_mean<T>(
+    data: &Tensor<T>,
+    segment_ids: &Tensor<usize>,
+    num_segments: usize,
+) -> Tensor<T>
+where
+    T: Clone + DTComp + Debug + 'static + Add<Output = T> + Zero + Div<Output = T> + NumCast,
+{
+    return segment_mean_tensor(data, segment_ids, num_segments);
+}
+
+/// Takes the maximum of every element over the rows in each segment, segments without any row are
+/// zero
+#[track_caller]
+pub fn segment_max<T>(
+    data: &Tensor<T>,
+    segment_ids: &Tensor<usize>,
+    num_segments: usize,
+) -> Tensor<T>
+where
+    T: Clone + DTComp + Debug + 'static + Add<Output = T> + Zero + PartialOrd,
+{
+    return segment_max_tensor(data, segment_ids, num_segments);
+}
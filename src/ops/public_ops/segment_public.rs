@@ -0,0 +1,102 @@
+use num_traits::{NumCast, Zero};
+use std::fmt::Debug;
+use std::ops::{Add, Div};
+use std::rc::Rc;
+
+use crate::ops::central::segment_max_impl::segment_max_impl;
+use crate::ops::central::segment_mean_impl::segment_mean_impl;
+use crate::ops::central::segment_sum_impl::segment_sum_impl;
+use crate::ops::compute::meta_compute;
+use crate::ops::compute::scatter_compute::{
+    compute_segment_max, compute_segment_mean, compute_segment_sum,
+};
+use crate::profiler::timing_profiler::{self, SpanCategory};
+use crate::tensor_core::dtypes::DTComp;
+use crate::tensor_core::tensor::Tensor;
+
+#[track_caller]
+pub fn segment_sum_tensor<T>(
+    tensor: &Tensor<T>,
+    segment_ids: &Tensor<usize>,
+    num_segments: usize,
+) -> Tensor<T>
+where
+    T: Clone + DTComp + Debug + 'static + Add<Output = T> + Zero,
+{
+    let _span = timing_profiler::span(SpanCategory::Forward, "segment_sum", &[tensor]);
+
+    let result_tensor;
+
+    if tensor.is_meta() {
+        result_tensor = meta_compute::meta_compute_segment(tensor, segment_ids, num_segments);
+    } else {
+        result_tensor = compute_segment_sum(tensor, segment_ids, num_segments);
+    }
+
+    if tensor.does_require_grad() {
+        result_tensor.requires_grad_intermediate("segment_sum");
+        segment_sum_impl(Some(tensor), &result_tensor, Rc::new(segment_ids.detach()));
+    }
+
+    return result_tensor;
+}
+
+#[track_caller]
+pub fn segment_mean_tensor<T>(
+    tensor: &Tensor<T>,
+    segment_ids: &Tensor<usize>,
+    num_segments: usize,
+) -> Tensor<T>
+where
+    T: Clone + DTComp + Debug + 'static + Add<Output = T> + Zero + Div<Output = T> + NumCast,
+{
+    let _span = timing_profiler::span(SpanCategory::Forward, "segment_mean", &[tensor]);
+
+    let result_tensor;
+
+    if tensor.is_meta() {
+        result_tensor = meta_compute::meta_compute_segment(tensor, segment_ids, num_segments);
+    } else {
+        result_tensor = compute_segment_mean(tensor, segment_ids, num_segments);
+    }
+
+    if tensor.does_require_grad() {
+        result_tensor.requires_grad_intermediate("segment_mean");
+        segment_mean_impl(
+            Some(tensor),
+            &result_tensor,
+            Rc::new(segment_ids.detach()),
+            num_segments,
+        );
+    }
+
+    return result_tensor;
+}
+
+#[track_caller]
+pub fn segment_max_tensor<T>(
+    tensor: &Tensor<T>,
+    segment_ids: &Tensor<usize>,
+    num_segments: usize,
+) -> Tensor<T>
+where
+    T: Clone + DTComp + Debug + 'static + Add<Output = T> + Zero + PartialOrd,
+{
+    let _span = timing_profiler::span(SpanCategory::Forward, "segment_max", &[tensor]);
+
+    let (indices, result_tensor) = if tensor.is_meta() {
+        (
+            meta_compute::meta_compute_segment(tensor, segment_ids, num_segments),
+            meta_compute::meta_compute_segment(tensor, segment_ids, num_segments),
+        )
+    } else {
+        compute_segment_max(tensor, segment_ids, num_segments)
+    };
+
+    if tensor.does_require_grad() {
+        result_tensor.requires_grad_intermediate("segment_max");
+        segment_max_impl(Some(tensor), &result_tensor, Rc::new(indices));
+    }
+
+    return result_tensor;
+}
@@ -0,0 +1,37 @@
+use ndarray::{Axis, SliceInfoElem};
+use num_traits::Zero;
+use std::fmt::Debug;
+use std::ops::Add;
+
+use crate::ops::public_ops::slice_public::{select_tensor, slice_tensor};
+use crate::tensor_core::dtypes::DTComp;
+use crate::tensor_core::tensor::Tensor;
+
+#[doc(hidden)]
+pub use ndarray::s as __ndarray_s;
+
+/// Builds the slice info for `Tensor::slice`, with the same syntax as ndarray's `s![]`
+#[macro_export]
+macro_rules! ts {
+    ($($t:tt)*) => {
+        $crate::ops::public_ops::slice::__ndarray_s![$($t)*].to_vec()
+    };
+}
+
+impl<T> Tensor<T>
+where
+    T: Debug + DTComp + Clone + 'static + Add<Output = T> + Zero,
+{
+    #[track_caller]
+    pub fn slice<I>(&self, info: I) -> Self
+    where
+        I: AsRef<[SliceInfoElem]>,
+    {
+        return slice_tensor(self, info.as_ref().to_vec());
+    }
+
+    #[track_caller]
+    pub fn select(&self, dim: Axis, index: usize) -> Self {
+        return select_tensor(self, dim, index);
+    }
+}
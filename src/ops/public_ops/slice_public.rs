@@ -0,0 +1,60 @@
+use ndarray::{Axis, SliceInfoElem};
+use num_traits::Zero;
+use std::fmt::Debug;
+use std::ops::Add;
+
+use crate::ops::central::slice_impl::slice_impl;
+use crate::ops::compute::indexing_compute::{compute_select, select_slice_info};
+use crate::ops::compute::meta_compute;
+use crate::ops::compute::shape_compute::compute_slice;
+use crate::profiler::timing_profiler::{self, SpanCategory};
+use crate::tensor_core::dtypes::DTComp;
+use crate::tensor_core::tensor::Tensor;
+
+#[track_caller]
+pub fn slice_tensor<T>(tensor: &Tensor<T>, info: Vec<SliceInfoElem>) -> Tensor<T>
+where
+    T: Clone + DTComp + Debug + 'static + Add<Output = T> + Zero,
+{
+    let _span = timing_profiler::span(SpanCategory::Forward, "slice", &[tensor]);
+
+    let result_tensor;
+
+    if tensor.is_meta() {
+        result_tensor = meta_compute::meta_compute_slice(tensor, &info);
+    } else {
+        result_tensor = compute_slice(tensor, &info);
+    }
+
+    if tensor.does_require_grad() {
+        result_tensor.requires_grad_intermediate("slice");
+        slice_impl(Some(tensor), &result_tensor, info);
+    }
+
+    return result_tensor;
+}
+
+/// Selecting an index is a slice that drops the selected dimension, so it shares the slice node
+#[track_caller]
+pub fn select_tensor<T>(tensor: &Tensor<T>, dim: Axis, index: usize) -> Tensor<T>
+where
+    T: Clone + DTComp + Debug + 'static + Add<Output = T> + Zero,
+{
+    let _span = timing_profiler::span(SpanCategory::Forward, "select", &[tensor]);
+
+    let result_tensor;
+    let info = select_slice_info(tensor.get_shape().len(), dim, index);
+
+    if tensor.is_meta() {
+        result_tensor = meta_compute::meta_compute_slice(tensor, &info);
+    } else {
+        result_tensor = compute_select(tensor, dim, index);
+    }
+
+    if tensor.does_require_grad() {
+        result_tensor.requires_grad_intermediate("select");
+        slice_impl(Some(tensor), &result_tensor, info);
+    }
+
+    return result_tensor;
+}
@@ -7,6 +7,7 @@ use crate::{
     tensor_core::{dtypes::DTComp, tensor::Tensor},
 };
 
+#[track_caller]
 pub fn stack<T>(tensor_list: &[&Tensor<T>], dim: Axis) -> Tensor<T>
 where
     T: Clone + DTComp + Debug + 'static + Add<Output = T>,
@@ -4,15 +4,26 @@ use std::ops::Add;
 use ndarray::Axis;
 
 use crate::ops::central::stack_impl::stack_impl;
+use crate::ops::compute::meta_compute;
 use crate::ops::compute::stack_concat_compute::stack_compute;
+use crate::profiler::timing_profiler::{self, SpanCategory};
 use crate::tensor_core::dtypes::DTComp;
 use crate::tensor_core::tensor::Tensor;
 
+#[track_caller]
 pub fn stack_tensor<T>(tensor_list: &[&Tensor<T>], dim: Axis) -> Tensor<T>
 where
     T: Clone + DTComp + Debug + 'static + Add<Output = T>,
 {
-    let result_tensor = stack_compute(tensor_list, dim);
+    let _span = timing_profiler::span(SpanCategory::Forward, "stack", tensor_list);
+
+    let result_tensor;
+
+    if meta_compute::any_meta(tensor_list) {
+        result_tensor = meta_compute::meta_compute_stack(tensor_list, dim);
+    } else {
+        result_tensor = stack_compute(tensor_list, dim);
+    }
 
     stack_impl(tensor_list, &result_tensor, Axis(dim.index()));
 
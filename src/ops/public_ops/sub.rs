@@ -3,17 +3,24 @@ use crate::tensor_core::dtypes::DTComp;
 use crate::tensor_core::tensor::Tensor;
 
 use ndarray::ScalarOperand;
-use num_traits::Signed;
 use std::fmt::Debug;
-use std::ops::Sub;
+use std::ops::{Add, Neg, Sub};
 
 impl<'tl, T, S> Sub<S> for &'tl Tensor<T>
 where
-    T: DTComp + Sub<S, Output = T> + ScalarOperand + Signed + 'static + Debug + Clone,
+    T: DTComp
+        + Sub<S, Output = T>
+        + ScalarOperand
+        + Add<Output = T>
+        + Neg<Output = T>
+        + 'static
+        + Debug
+        + Clone,
     S: ScalarOperand,
 {
     type Output = Tensor<T>;
 
+    #[track_caller]
     fn sub(self, rhs: S) -> Self::Output {
         return sub_tensor_scalar(self, rhs);
     }
@@ -21,11 +28,19 @@ where
 
 impl<T, S> Sub<S> for Tensor<T>
 where
-    T: DTComp + Sub<S, Output = T> + ScalarOperand + Signed + 'static + Debug + Clone,
+    T: DTComp
+        + Sub<S, Output = T>
+        + ScalarOperand
+        + Add<Output = T>
+        + Neg<Output = T>
+        + 'static
+        + Debug
+        + Clone,
     S: ScalarOperand,
 {
     type Output = Tensor<T>;
 
+    #[track_caller]
     fn sub(self, rhs: S) -> Self::Output {
         return &self - rhs;
     }
@@ -33,10 +48,11 @@ where
 
 impl<'tl_a, 'tl_b, T> Sub<&'tl_b Tensor<T>> for &'tl_a Tensor<T>
 where
-    T: DTComp + Sub<T> + Signed + 'static + Debug + Clone,
+    T: DTComp + Sub<Output = T> + Add<Output = T> + Neg<Output = T> + 'static + Debug + Clone,
 {
     type Output = Tensor<T>;
 
+    #[track_caller]
     fn sub(self, rhs: &'tl_b Tensor<T>) -> Self::Output {
         return sub_tensor_tensor(self, rhs);
     }
@@ -44,10 +60,11 @@ where
 
 impl<'tl_a, T> Sub<&'tl_a Tensor<T>> for Tensor<T>
 where
-    T: DTComp + Sub<T> + Signed + 'static + Debug + Clone,
+    T: DTComp + Sub<Output = T> + Add<Output = T> + Neg<Output = T> + 'static + Debug + Clone,
 {
     type Output = Tensor<T>;
 
+    #[track_caller]
     fn sub(self, rhs: &'tl_a Tensor<T>) -> Self::Output {
         return sub_tensor_tensor(&self, rhs);
     }
@@ -55,10 +72,11 @@ where
 
 impl<T> Sub<Tensor<T>> for Tensor<T>
 where
-    T: DTComp + Sub<T> + Signed + 'static + Debug + Clone,
+    T: DTComp + Sub<Output = T> + Add<Output = T> + Neg<Output = T> + 'static + Debug + Clone,
 {
     type Output = Tensor<T>;
 
+    #[track_caller]
     fn sub(self, rhs: Tensor<T>) -> Self::Output {
         return &self - &rhs;
     }
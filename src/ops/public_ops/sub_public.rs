@@ -1,36 +1,62 @@
 use ndarray::ScalarOperand;
-use num_traits::Signed;
 use std::fmt::Debug;
-use std::ops::Sub;
+use std::ops::{Add, Neg, Sub};
 
 use crate::ops::central::sub_impl::sub_impl;
+use crate::ops::compute::meta_compute;
 use crate::ops::compute::sub_compute::{sub_compute_tensor_scalar, sub_compute_tensor_tensor};
+use crate::profiler::timing_profiler::{self, SpanCategory};
 use crate::tensor_core::dtypes::DTComp;
 use crate::tensor_core::tensor::Tensor;
 
+#[track_caller]
 pub fn sub_tensor_tensor<T>(lhs_tensor: &Tensor<T>, rhs_tensor: &Tensor<T>) -> Tensor<T>
 where
-    T: DTComp + Sub<T> + Signed + 'static + Debug + Clone,
+    T: DTComp + Sub<Output = T> + Add<Output = T> + Neg<Output = T> + 'static + Debug + Clone,
 {
-    let result_tensor = sub_compute_tensor_tensor(lhs_tensor, rhs_tensor);
+    let _span = timing_profiler::span(SpanCategory::Forward, "sub", &[lhs_tensor, rhs_tensor]);
+
+    let result_tensor;
+
+    if meta_compute::any_meta(&[lhs_tensor, rhs_tensor]) {
+        result_tensor = meta_compute::meta_compute_broadcast_binary(lhs_tensor, rhs_tensor);
+    } else {
+        result_tensor = sub_compute_tensor_tensor(lhs_tensor, rhs_tensor);
+    }
 
     if lhs_tensor.does_require_grad() || rhs_tensor.does_require_grad() {
-        result_tensor.requires_grad_intermediate("Intermediate tensor from sub");
+        result_tensor.requires_grad_intermediate("sub");
         sub_impl(Some(lhs_tensor), Some(rhs_tensor), &result_tensor);
     }
 
     return result_tensor;
 }
 
+#[track_caller]
 pub fn sub_tensor_scalar<T, S>(lhs_tensor: &Tensor<T>, scalar: S) -> Tensor<T>
 where
-    T: DTComp + Sub<S, Output = T> + ScalarOperand + Signed + 'static + Debug + Clone,
+    T: DTComp
+        + Sub<S, Output = T>
+        + ScalarOperand
+        + Add<Output = T>
+        + Neg<Output = T>
+        + 'static
+        + Debug
+        + Clone,
     S: ScalarOperand,
 {
-    let result_tensor = sub_compute_tensor_scalar(lhs_tensor, scalar);
+    let _span = timing_profiler::span(SpanCategory::Forward, "sub", &[lhs_tensor]);
+
+    let result_tensor;
+
+    if lhs_tensor.is_meta() {
+        result_tensor = meta_compute::meta_compute_like(lhs_tensor);
+    } else {
+        result_tensor = sub_compute_tensor_scalar(lhs_tensor, scalar);
+    }
 
     if lhs_tensor.does_require_grad() {
-        result_tensor.requires_grad_intermediate("Intermediate tensor from sub");
+        result_tensor.requires_grad_intermediate("sub");
         sub_impl(Some(lhs_tensor), None, &result_tensor);
     }
 
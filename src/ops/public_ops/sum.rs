@@ -10,6 +10,7 @@ impl<T> Tensor<T>
 where
     T: Debug + DTComp + Clone + 'static + Add<Output = T>,
 {
+    #[track_caller]
     pub fn sum(&self, dim: Axis) -> Self {
         return sum_tensor(self, dim);
     }
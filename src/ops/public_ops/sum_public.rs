@@ -3,18 +3,29 @@ use std::fmt::Debug;
 use std::ops::Add;
 
 use crate::ops::central::sum_impl::sum_impl;
+use crate::ops::compute::meta_compute;
 use crate::ops::compute::sum_mean_compute::sum_compute_tensor;
+use crate::profiler::timing_profiler::{self, SpanCategory};
 use crate::tensor_core::dtypes::DTComp;
 use crate::tensor_core::tensor::Tensor;
 
+#[track_caller]
 pub fn sum_tensor<T>(tensor: &Tensor<T>, dim: Axis) -> Tensor<T>
 where
     T: Debug + DTComp + Clone + 'static + Add<Output = T>,
 {
-    let result_tensor = sum_compute_tensor(tensor, dim);
+    let _span = timing_profiler::span(SpanCategory::Forward, "sum", &[tensor]);
+
+    let result_tensor;
+
+    if tensor.is_meta() {
+        result_tensor = meta_compute::meta_compute_reduce(tensor, dim);
+    } else {
+        result_tensor = sum_compute_tensor(tensor, dim);
+    }
 
     if tensor.does_require_grad() {
-        result_tensor.requires_grad_intermediate("Intermediate tensor from min");
+        result_tensor.requires_grad_intermediate("sum");
         sum_impl(Some(tensor), &result_tensor, dim);
     }
 
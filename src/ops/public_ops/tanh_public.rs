@@ -1,20 +1,30 @@
 use ndarray::ScalarOperand;
-use num_traits::Float;
 use std::fmt::Debug;
 
 use crate::ops::central::tanh_impl::tanh_impl;
 use crate::ops::compute::hyperbolic_compute::tanh_compute_tensor;
-use crate::tensor_core::dtypes::DTComp;
+use crate::ops::compute::meta_compute;
+use crate::profiler::timing_profiler::{self, SpanCategory};
+use crate::tensor_core::dtypes::{DTComp, DTFloat};
 use crate::tensor_core::tensor::Tensor;
 
+#[track_caller]
 pub fn tanh_tensor<T>(tensor: &Tensor<T>) -> Tensor<T>
 where
-    T: Debug + DTComp + Float + 'static + ScalarOperand,
+    T: Debug + DTComp + DTFloat + 'static + ScalarOperand,
 {
-    let new_tensor = tanh_compute_tensor(tensor);
+    let _span = timing_profiler::span(SpanCategory::Forward, "tanh", &[tensor]);
+
+    let new_tensor;
+
+    if tensor.is_meta() {
+        new_tensor = meta_compute::meta_compute_like(tensor);
+    } else {
+        new_tensor = tanh_compute_tensor(tensor);
+    }
 
     if tensor.does_require_grad() {
-        new_tensor.requires_grad_intermediate("Intermediate tensor from tanh operation");
+        new_tensor.requires_grad_intermediate("tanh");
 
         tanh_impl(Some(tensor), &new_tensor);
     }
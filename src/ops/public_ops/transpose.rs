@@ -9,6 +9,7 @@ impl<T> Tensor<T>
 where
     T: Debug + DTComp + Clone + 'static + Add<Output = T>,
 {
+    #[track_caller]
     pub fn transpose(&self, axes_option: Option<Vec<usize>>) -> Self {
         return transpose_tensor(self, axes_option);
     }
@@ -2,18 +2,29 @@ use std::fmt::Debug;
 use std::ops::Add;
 
 use crate::ops::central::transpose_impl::transpose_impl;
+use crate::ops::compute::meta_compute;
 use crate::ops::compute::shape_compute::compute_transpose;
+use crate::profiler::timing_profiler::{self, SpanCategory};
 use crate::tensor_core::dtypes::DTComp;
 use crate::tensor_core::tensor::Tensor;
 
+#[track_caller]
 pub fn transpose_tensor<T>(tensor: &Tensor<T>, axes_option: Option<Vec<usize>>) -> Tensor<T>
 where
     T: Clone + DTComp + Debug + 'static + Add<Output = T>,
 {
-    let result_tensor = compute_transpose(tensor, axes_option.clone());
+    let _span = timing_profiler::span(SpanCategory::Forward, "transpose", &[tensor]);
+
+    let result_tensor;
+
+    if tensor.is_meta() {
+        result_tensor = meta_compute::meta_compute_transpose(tensor, axes_option.clone());
+    } else {
+        result_tensor = compute_transpose(tensor, axes_option.clone());
+    }
 
     if tensor.does_require_grad() {
-        result_tensor.requires_grad_intermediate("Intermediate tensor from min");
+        result_tensor.requires_grad_intermediate("transpose");
         transpose_impl(Some(tensor), &result_tensor, axes_option);
     }
 
@@ -11,6 +11,7 @@ impl<T> Tensor<T>
 where
     T: Debug + DTComp + Clone + 'static + Add<Output = T>,
 {
+    #[track_caller]
     pub fn unsqueeze(&self, reduced_dim: Axis) -> Self {
         return unsqueeze_tensor(self, reduced_dim);
     }
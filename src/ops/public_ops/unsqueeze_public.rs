@@ -4,18 +4,29 @@ use std::ops::Add;
 use ndarray::Axis;
 
 use crate::ops::central::unsqueeze_impl::unsqueeze_impl;
+use crate::ops::compute::meta_compute;
 use crate::ops::compute::shape_compute::compute_unsqueeze;
+use crate::profiler::timing_profiler::{self, SpanCategory};
 use crate::tensor_core::dtypes::DTComp;
 use crate::tensor_core::tensor::Tensor;
 
+#[track_caller]
 pub fn unsqueeze_tensor<T>(tensor: &Tensor<T>, reduced_dim: Axis) -> Tensor<T>
 where
     T: Clone + DTComp + Debug + 'static + Add<Output = T>,
 {
-    let result_tensor = compute_unsqueeze(tensor, Axis(reduced_dim.index()));
+    let _span = timing_profiler::span(SpanCategory::Forward, "unsqueeze", &[tensor]);
+
+    let result_tensor;
+
+    if tensor.is_meta() {
+        result_tensor = meta_compute::meta_compute_unsqueeze(tensor, reduced_dim);
+    } else {
+        result_tensor = compute_unsqueeze(tensor, Axis(reduced_dim.index()));
+    }
 
     if tensor.does_require_grad() {
-        result_tensor.requires_grad_intermediate("Intermediate tensor from min");
+        result_tensor.requires_grad_intermediate("unsqueeze");
         unsqueeze_impl(Some(tensor), &result_tensor, reduced_dim);
     }
 
@@ -0,0 +1,22 @@
+use num_traits::Zero;
+use std::fmt::Debug;
+use std::ops::Add;
+
+use crate::ops::public_ops::where_public::where_tensor;
+use crate::tensor_core::dtypes::DTComp;
+use crate::tensor_core::tensor::Tensor;
+
+/// Takes elements of `lhs_tensor` where `condition` is true and of `rhs_tensor` elsewhere. All
+/// three are broadcast together, and the gradient of each operand only flows through the elements
+/// taken from it.
+#[track_caller]
+pub fn where_<T>(
+    condition: &Tensor<bool>,
+    lhs_tensor: &Tensor<T>,
+    rhs_tensor: &Tensor<T>,
+) -> Tensor<T>
+where
+    T: Clone + DTComp + Debug + 'static + Add<Output = T> + Zero,
+{
+    return where_tensor(condition, lhs_tensor, rhs_tensor);
+}
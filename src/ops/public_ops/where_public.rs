@@ -0,0 +1,49 @@
+use num_traits::Zero;
+use std::fmt::Debug;
+use std::ops::Add;
+use std::rc::Rc;
+
+use crate::ops::central::where_impl::where_impl;
+use crate::ops::compute::logical_compute::compute_where;
+use crate::ops::compute::meta_compute;
+use crate::ops::compute::shape_compute::compute_broadcast;
+use crate::profiler::timing_profiler::{self, SpanCategory};
+use crate::tensor_core::dtypes::DTComp;
+use crate::tensor_core::tensor::Tensor;
+
+#[track_caller]
+pub fn where_tensor<T>(
+    condition: &Tensor<bool>,
+    lhs_tensor: &Tensor<T>,
+    rhs_tensor: &Tensor<T>,
+) -> Tensor<T>
+where
+    T: Clone + DTComp + Debug + 'static + Add<Output = T> + Zero,
+{
+    let _span = timing_profiler::span(SpanCategory::Forward, "where", &[lhs_tensor, rhs_tensor]);
+
+    let result_tensor;
+
+    if condition.is_meta() || meta_compute::any_meta(&[lhs_tensor, rhs_tensor]) {
+        result_tensor = meta_compute::meta_compute_where(condition, lhs_tensor, rhs_tensor);
+    } else {
+        result_tensor = compute_where(condition, lhs_tensor, rhs_tensor);
+    }
+
+    if lhs_tensor.does_require_grad() || rhs_tensor.does_require_grad() {
+        result_tensor.requires_grad_intermediate("where");
+
+        // the shape is read before the call, since the graph node is attached to the result
+        let out_shape = result_tensor.get_shape().clone();
+        let condition = Rc::new(compute_broadcast(condition, out_shape));
+
+        where_impl(
+            Some(lhs_tensor),
+            Some(rhs_tensor),
+            &result_tensor,
+            condition,
+        );
+    }
+
+    return result_tensor;
+}
@@ -0,0 +1,2 @@
+pub mod memory_profiler;
+pub mod timing_profiler;
@@ -0,0 +1,417 @@
+use serde::Serialize;
+use serde_json::to_string_pretty;
+
+use crate::graph::backward::Backward;
+use crate::tensor_core::buffer_pool;
+use crate::tensor_core::dtypes::DTComp;
+
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{self, Debug, Display};
+use std::fs;
+use std::path::Path;
+
+/// Label for allocations that are not the output of any tracked operation, such as leaf tensors
+/// and results computed without gradient tracking
+pub const UNTRACKED_OP: &str = "Untracked";
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub enum Phase {
+    Forward,
+    Backward,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub enum MemoryEventKind {
+    Alloc,
+    Free,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MemoryEvent {
+    pub index: usize,
+    pub phase: Phase,
+    pub kind: MemoryEventKind,
+    pub op: String,
+    pub node_id: Option<usize>,
+    pub bytes: usize,
+    pub live_bytes: usize,
+    /// Bytes of freed buffers kept by the buffer pool for reuse, which are still allocated
+    pub pooled_bytes: usize,
+}
+
+/// Bytes held by the saved `input_refs` of a backward node when it was attached to the graph
+#[derive(Debug, Clone, Serialize)]
+pub struct NodeMemory {
+    pub op: String,
+    pub node_id: usize,
+    pub saved_bytes: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct OpMemory {
+    pub op: String,
+    pub allocations: usize,
+    pub bytes: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MemoryProfile {
+    pub forward_peak_bytes: usize,
+    pub backward_peak_bytes: usize,
+    pub pooled_peak_bytes: usize,
+    pub nodes: Vec<NodeMemory>,
+    pub timeline: Vec<MemoryEvent>,
+}
+
+#[derive(Debug)]
+struct LiveAllocation {
+    bytes: usize,
+    event_index: usize,
+}
+
+#[derive(Debug, Default)]
+struct MemoryProfilerState {
+    live: HashMap<usize, LiveAllocation>,
+    live_bytes: usize,
+    pooled_bytes: usize,
+    forward_peak_bytes: usize,
+    backward_peak_bytes: usize,
+    pooled_peak_bytes: usize,
+    node_stack: Vec<(String, usize)>,
+    nodes: Vec<NodeMemory>,
+    timeline: Vec<MemoryEvent>,
+}
+
+thread_local! {
+    static MEMORY_PROFILER: RefCell<Option<MemoryProfilerState>> = const { RefCell::new(None) };
+}
+
+/// Start recording tensor allocations on this thread. Any recording in progress is discarded.
+pub fn start() {
+    let pooled_bytes = buffer_pool::stats().pooled_bytes;
+
+    MEMORY_PROFILER.with(|profiler| {
+        *profiler.borrow_mut() = Some(MemoryProfilerState {
+            pooled_bytes,
+            pooled_peak_bytes: pooled_bytes,
+            ..MemoryProfilerState::default()
+        });
+    });
+}
+
+/// Stop recording and return everything recorded since `start()`
+pub fn stop() -> MemoryProfile {
+    let state = MEMORY_PROFILER.with(|profiler| profiler.borrow_mut().take());
+
+    match state {
+        Some(state) => {
+            let profile = MemoryProfile {
+                forward_peak_bytes: state.forward_peak_bytes,
+                backward_peak_bytes: state.backward_peak_bytes,
+                pooled_peak_bytes: state.pooled_peak_bytes,
+                nodes: state.nodes,
+                timeline: state.timeline,
+            };
+
+            return profile;
+        }
+        None => {
+            panic!("Error: Memory profiler was stopped before it was started.");
+        }
+    }
+}
+
+pub fn is_active() -> bool {
+    return MEMORY_PROFILER
+        .try_with(|profiler| profiler.borrow().is_some())
+        .unwrap_or(false);
+}
+
+fn with_state<F>(f: F)
+where
+    F: FnOnce(&mut MemoryProfilerState),
+{
+    // the thread local may already be destroyed when tensors are dropped at thread exit
+    let _ = MEMORY_PROFILER.try_with(|profiler| {
+        if let Some(state) = profiler.borrow_mut().as_mut() {
+            f(state);
+        }
+    });
+}
+
+impl MemoryProfilerState {
+    fn current_phase(&self) -> Phase {
+        match self.node_stack.is_empty() {
+            true => Phase::Forward,
+            false => Phase::Backward,
+        }
+    }
+
+    fn current_op(&self) -> (String, Option<usize>) {
+        match self.node_stack.last() {
+            Some((op, node_id)) => (op.clone(), Some(*node_id)),
+            None => (String::from(UNTRACKED_OP), None),
+        }
+    }
+
+    fn push_event(
+        &mut self,
+        kind: MemoryEventKind,
+        op: String,
+        node_id: Option<usize>,
+        bytes: usize,
+    ) -> usize {
+        let phase = self.current_phase();
+
+        match phase {
+            Phase::Forward => {
+                self.forward_peak_bytes = self.forward_peak_bytes.max(self.live_bytes)
+            }
+            Phase::Backward => {
+                self.backward_peak_bytes = self.backward_peak_bytes.max(self.live_bytes)
+            }
+        }
+
+        self.timeline.push(MemoryEvent {
+            index: self.timeline.len(),
+            phase,
+            kind,
+            op,
+            node_id,
+            bytes,
+            live_bytes: self.live_bytes,
+            pooled_bytes: self.pooled_bytes,
+        });
+
+        return self.timeline.len() - 1;
+    }
+}
+
+/// Called when a storage buffer is created. Allocations made while a backward node is running are
+/// attributed to that node.
+pub(crate) fn record_alloc(allocation_id: usize, bytes: usize) {
+    if bytes == 0 {
+        return;
+    }
+
+    with_state(|state| {
+        state.live_bytes += bytes;
+
+        let (op, node_id) = state.current_op();
+        let event_index = state.push_event(MemoryEventKind::Alloc, op, node_id, bytes);
+
+        state
+            .live
+            .insert(allocation_id, LiveAllocation { bytes, event_index });
+    });
+}
+
+/// Called when a storage buffer is dropped
+pub(crate) fn record_free(allocation_id: usize) {
+    with_state(|state| {
+        if let Some(allocation) = state.live.remove(&allocation_id) {
+            state.live_bytes -= allocation.bytes;
+
+            let alloc_event = &state.timeline[allocation.event_index];
+            let (op, node_id) = (alloc_event.op.clone(), alloc_event.node_id);
+
+            state.push_event(MemoryEventKind::Free, op, node_id, allocation.bytes);
+        }
+    });
+}
+
+/// Called by the buffer pool whenever the bytes it holds change. Buffers released into the pool
+/// are recorded as freed, so this keeps the memory they still occupy visible.
+pub(crate) fn record_pooled_bytes(pooled_bytes: usize) {
+    with_state(|state| {
+        state.pooled_bytes = pooled_bytes;
+        state.pooled_peak_bytes = state.pooled_peak_bytes.max(pooled_bytes);
+    });
+}
+
+/// Called when a node is attached to the tensor it produced. The output allocation of a forward
+/// operation is attributed to the node and the bytes held by its saved inputs are recorded.
+pub(crate) fn record_node_output<T>(allocation_id: usize, node: &dyn Backward<T>)
+where
+    T: DTComp + Debug,
+{
+    if !is_active() {
+        return;
+    }
+
+    let op = node.get_name();
+    let node_id = node.get_id();
+    // an input saved twice, such as both operands of `&a * &a`, holds its storage only once
+    let mut counted_allocations = HashSet::new();
+    let saved_bytes = node
+        .get_input_refs()
+        .iter()
+        .filter_map(|input_ref| {
+            let input = input_ref.borrow();
+            let storage = input.get_storage_();
+
+            if counted_allocations.insert(storage.get_allocation_id()) {
+                return Some(storage.get_nbytes());
+            }
+            return None;
+        })
+        .sum();
+
+    with_state(|state| {
+        if let Some(allocation) = state.live.get(&allocation_id) {
+            let alloc_event = &mut state.timeline[allocation.event_index];
+            if alloc_event.phase == Phase::Forward {
+                alloc_event.op = op.clone();
+                alloc_event.node_id = Some(node_id);
+            }
+        }
+
+        state.nodes.push(NodeMemory {
+            op,
+            node_id,
+            saved_bytes,
+        });
+    });
+}
+
+/// Mark the start of a node's apply during backward
+pub(crate) fn enter_node<T>(node: &dyn Backward<T>)
+where
+    T: DTComp + Debug,
+{
+    if !is_active() {
+        return;
+    }
+
+    let (op, node_id) = (node.get_name(), node.get_id());
+    with_state(|state| state.node_stack.push((op, node_id)));
+}
+
+/// Mark the end of a node's apply during backward
+pub(crate) fn exit_node() {
+    with_state(|state| {
+        state.node_stack.pop();
+    });
+}
+
+impl MemoryProfile {
+    /// Operations sorted by the total number of bytes they allocated, largest first
+    pub fn top_ops(&self, n: usize) -> Vec<OpMemory> {
+        let mut per_op: HashMap<&str, OpMemory> = HashMap::new();
+
+        for event in self.timeline.iter() {
+            if event.kind != MemoryEventKind::Alloc {
+                continue;
+            }
+
+            let entry = per_op.entry(event.op.as_str()).or_insert(OpMemory {
+                op: event.op.clone(),
+                allocations: 0,
+                bytes: 0,
+            });
+
+            entry.allocations += 1;
+            entry.bytes += event.bytes;
+        }
+
+        let mut ops: Vec<OpMemory> = per_op.into_values().collect();
+        ops.sort_by(|a, b| b.bytes.cmp(&a.bytes).then(a.op.cmp(&b.op)));
+        ops.truncate(n);
+
+        return ops;
+    }
+
+    pub fn to_json(&self) -> String {
+        return to_string_pretty(self).expect("Error: Failed to serialize memory profile");
+    }
+
+    pub fn save_json(&self, path: &Path) {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .expect("Error: Failed to create directory for memory profile");
+        }
+
+        fs::write(path, self.to_json()).expect("Error: Failed to write memory profile");
+    }
+}
+
+impl Display for MemoryProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Memory profile:")?;
+        writeln!(f, "  Forward peak: {} bytes", self.forward_peak_bytes)?;
+        writeln!(f, "  Backward peak: {} bytes", self.backward_peak_bytes)?;
+        writeln!(f, "  Pooled peak: {} bytes", self.pooled_peak_bytes)?;
+        writeln!(f, "  Top allocating ops:")?;
+
+        for op in self.top_ops(5).iter() {
+            writeln!(
+                f,
+                "    {:<20} {:>6} allocations {:>12} bytes",
+                op.op, op.allocations, op.bytes
+            )?;
+        }
+
+        write!(f, "  Nodes recorded: {}", self.nodes.len())
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    #[allow(unused)]
+    use super::*;
+    use crate::tensor_core::tensor::Tensor;
+
+    #[test]
+    fn memory_profile_attributes_allocations_to_nodes() {
+        start();
+
+        let x1 = Tensor::new(vec![1.0, 2.0, 3.0, 4.0], vec![4, 1], true).as_float_32();
+        let x2 = Tensor::new(vec![0.5, 0.25, 0.1, 0.2], vec![4, 1], true).as_float_32();
+
+        let a = &x1 * &x2;
+        let z = a.exp();
+        z.backward(Tensor::ones_like(&z, None), false);
+
+        let profile = stop();
+
+        assert!(profile.forward_peak_bytes > 0);
+        assert!(profile.backward_peak_bytes > 0);
+
+        let mul_node = profile
+            .nodes
+            .iter()
+            .find(|node| node.op == "MulBackward")
+            .unwrap();
+        assert_eq!(mul_node.saved_bytes, 2 * 4 * std::mem::size_of::<f32>());
+
+        let top_ops: Vec<String> = profile.top_ops(10).into_iter().map(|op| op.op).collect();
+        assert!(top_ops.contains(&String::from("MulBackward")));
+        assert!(top_ops.contains(&String::from("ExpBackward")));
+
+        assert!(
+            profile
+                .timeline
+                .iter()
+                .any(|event| event.phase == Phase::Backward)
+        );
+        assert!(profile.to_json().contains("\"backward_peak_bytes\""));
+    }
+
+    #[test]
+    fn memory_profile_counts_a_shared_saved_input_once() {
+        start();
+
+        let x = Tensor::new(vec![1.0, 2.0, 3.0, 4.0], vec![4, 1], true).as_float_32();
+        let _square = &x * &x;
+
+        let profile = stop();
+
+        let mul_node = profile
+            .nodes
+            .iter()
+            .find(|node| node.op == "MulBackward")
+            .unwrap();
+        assert_eq!(mul_node.saved_bytes, 4 * std::mem::size_of::<f32>());
+    }
+}
@@ -0,0 +1,353 @@
+use serde::Serialize;
+use serde_json::{json, to_string_pretty};
+
+use crate::tensor_core::dtypes::DTComp;
+use crate::tensor_core::tensor::Tensor;
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt::{self, Debug, Display};
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum SpanCategory {
+    /// A public op, from receiving its inputs to returning the result
+    Forward,
+    /// Construction of a graph node in `ops::central`
+    Graph,
+    /// A call to `Backward::apply`
+    Backward,
+}
+
+impl Display for SpanCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SpanCategory::Forward => write!(f, "forward"),
+            SpanCategory::Graph => write!(f, "graph"),
+            SpanCategory::Backward => write!(f, "backward"),
+        }
+    }
+}
+
+/// A finished span. `self_us` excludes the time spent in spans nested inside this one.
+#[derive(Debug, Clone, Serialize)]
+pub struct TimingSpan {
+    pub name: String,
+    pub category: SpanCategory,
+    pub start_us: f64,
+    pub duration_us: f64,
+    pub self_us: f64,
+    pub depth: usize,
+    pub shapes: Vec<Vec<usize>>,
+    pub dtype: String,
+}
+
+/// Aggregated timing of one op within one category
+#[derive(Debug, Clone, Serialize)]
+pub struct OpTiming {
+    pub name: String,
+    pub category: SpanCategory,
+    pub calls: usize,
+    pub total_us: f64,
+    pub self_us: f64,
+    pub max_us: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TimingProfile {
+    pub spans: Vec<TimingSpan>,
+}
+
+#[derive(Debug)]
+struct OpenSpan {
+    name: String,
+    category: SpanCategory,
+    start: Instant,
+    child_time: Duration,
+    shapes: Vec<Vec<usize>>,
+    dtype: String,
+}
+
+#[derive(Debug)]
+struct TimingProfilerState {
+    origin: Instant,
+    open: Vec<OpenSpan>,
+    spans: Vec<TimingSpan>,
+}
+
+thread_local! {
+    static TIMING_PROFILER: RefCell<Option<TimingProfilerState>> = const { RefCell::new(None) };
+}
+
+/// Closes its span when dropped. Returned by `span()` even when the profiler is off, in which case
+/// it does nothing.
+#[derive(Debug)]
+pub struct SpanGuard {
+    active: bool,
+}
+
+impl Drop for SpanGuard {
+    fn drop(&mut self) {
+        if self.active {
+            close_span();
+        }
+    }
+}
+
+/// Start recording spans on this thread. Any recording in progress is discarded.
+pub fn start() {
+    TIMING_PROFILER.with(|profiler| {
+        *profiler.borrow_mut() = Some(TimingProfilerState {
+            origin: Instant::now(),
+            open: vec![],
+            spans: vec![],
+        });
+    });
+}
+
+/// Stop recording and return every span finished since `start()`
+pub fn stop() -> TimingProfile {
+    let state = TIMING_PROFILER.with(|profiler| profiler.borrow_mut().take());
+
+    match state {
+        Some(state) => {
+            return TimingProfile { spans: state.spans };
+        }
+        None => {
+            panic!("Error: Timing profiler was stopped before it was started.");
+        }
+    }
+}
+
+pub fn is_active() -> bool {
+    return TIMING_PROFILER
+        .try_with(|profiler| profiler.borrow().is_some())
+        .unwrap_or(false);
+}
+
+/// Open a span named after an op, with the shapes of the given tensors attached
+pub fn span<T>(category: SpanCategory, name: &str, tensors: &[&Tensor<T>]) -> SpanGuard
+where
+    T: DTComp + Debug,
+{
+    return span_with_shapes::<T, _>(category, name, || {
+        tensors
+            .iter()
+            .map(|tensor| tensor.get_shape().to_vec())
+            .collect()
+    });
+}
+
+/// Open a span whose shapes are computed by `shapes`. The closure only runs when the profiler is
+/// on.
+pub fn span_with_shapes<T, F>(category: SpanCategory, name: &str, shapes: F) -> SpanGuard
+where
+    T: DTComp,
+    F: FnOnce() -> Vec<Vec<usize>>,
+{
+    if !is_active() {
+        return SpanGuard { active: false };
+    }
+
+    let open_span = OpenSpan {
+        name: String::from(name),
+        category,
+        start: Instant::now(),
+        child_time: Duration::ZERO,
+        shapes: shapes(),
+        dtype: T::dtype().to_string(),
+    };
+
+    TIMING_PROFILER.with(|profiler| {
+        if let Some(state) = profiler.borrow_mut().as_mut() {
+            state.open.push(open_span);
+        }
+    });
+
+    return SpanGuard { active: true };
+}
+
+fn close_span() {
+    let end = Instant::now();
+
+    let _ = TIMING_PROFILER.try_with(|profiler| {
+        if let Some(state) = profiler.borrow_mut().as_mut() {
+            if let Some(open_span) = state.open.pop() {
+                let duration = end.duration_since(open_span.start);
+
+                if let Some(parent) = state.open.last_mut() {
+                    parent.child_time += duration;
+                }
+
+                state.spans.push(TimingSpan {
+                    name: open_span.name,
+                    category: open_span.category,
+                    start_us: micros(open_span.start.duration_since(state.origin)),
+                    duration_us: micros(duration),
+                    self_us: micros(duration.saturating_sub(open_span.child_time)),
+                    depth: state.open.len(),
+                    shapes: open_span.shapes,
+                    dtype: open_span.dtype,
+                });
+            }
+        }
+    });
+}
+
+fn micros(duration: Duration) -> f64 {
+    return duration.as_secs_f64() * 1e6;
+}
+
+impl TimingProfile {
+    /// Timing per op and category, sorted by self time, largest first
+    pub fn summary(&self) -> Vec<OpTiming> {
+        let mut per_op: HashMap<(SpanCategory, &str), OpTiming> = HashMap::new();
+
+        for span in self.spans.iter() {
+            let entry = per_op
+                .entry((span.category, span.name.as_str()))
+                .or_insert(OpTiming {
+                    name: span.name.clone(),
+                    category: span.category,
+                    calls: 0,
+                    total_us: 0.0,
+                    self_us: 0.0,
+                    max_us: 0.0,
+                });
+
+            entry.calls += 1;
+            entry.total_us += span.duration_us;
+            entry.self_us += span.self_us;
+            entry.max_us = entry.max_us.max(span.duration_us);
+        }
+
+        let mut ops: Vec<OpTiming> = per_op.into_values().collect();
+        ops.sort_by(|a, b| b.self_us.total_cmp(&a.self_us).then(a.name.cmp(&b.name)));
+
+        return ops;
+    }
+
+    pub fn summary_table(&self) -> String {
+        let mut table = format!(
+            "{:<24} {:<10} {:>8} {:>14} {:>14} {:>14}\n",
+            "op", "category", "calls", "self (us)", "total (us)", "max (us)"
+        );
+
+        for op in self.summary().iter() {
+            table.push_str(&format!(
+                "{:<24} {:<10} {:>8} {:>14.3} {:>14.3} {:>14.3}\n",
+                op.name,
+                op.category.to_string(),
+                op.calls,
+                op.self_us,
+                op.total_us,
+                op.max_us
+            ));
+        }
+
+        return table;
+    }
+
+    /// Trace in the Chrome trace event format, which can be opened in chrome://tracing or Perfetto
+    pub fn to_chrome_trace(&self) -> String {
+        let events: Vec<_> = self
+            .spans
+            .iter()
+            .map(|span| {
+                json!({
+                    "name": span.name,
+                    "cat": span.category.to_string(),
+                    "ph": "X",
+                    "ts": span.start_us,
+                    "dur": span.duration_us,
+                    "pid": 1,
+                    "tid": 1,
+                    "args": {
+                        "shapes": span.shapes,
+                        "dtype": span.dtype,
+                    },
+                })
+            })
+            .collect();
+
+        let trace = json!({
+            "traceEvents": events,
+            "displayTimeUnit": "ms",
+        });
+
+        return to_string_pretty(&trace).expect("Error: Failed to serialize chrome trace");
+    }
+
+    pub fn save_chrome_trace(&self, path: &Path) {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).expect("Error: Failed to create directory for chrome trace");
+        }
+
+        fs::write(path, self.to_chrome_trace()).expect("Error: Failed to write chrome trace");
+    }
+}
+
+impl Display for TimingProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.summary_table())
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    #[allow(unused)]
+    use super::*;
+
+    #[test]
+    fn timing_profile_records_forward_graph_and_backward_spans() {
+        start();
+
+        let x1 = Tensor::new(vec![1.0, 2.0, 3.0, 4.0], vec![4, 1], true).as_float_32();
+        let x2 = Tensor::new(vec![0.5, 0.25, 0.1, 0.2], vec![4, 1], true).as_float_32();
+
+        let z = (&x1 * &x2).exp();
+        z.backward(Tensor::ones_like(&z, None), false);
+
+        let profile = stop();
+
+        let mul_forward = profile
+            .spans
+            .iter()
+            .find(|span| span.category == SpanCategory::Forward && span.name == "mul")
+            .unwrap();
+        assert_eq!(mul_forward.shapes, vec![vec![4, 1], vec![4, 1]]);
+        assert_eq!(mul_forward.dtype, "float32");
+
+        // graph construction happens inside the forward span of the op
+        let mul_graph = profile
+            .spans
+            .iter()
+            .find(|span| span.category == SpanCategory::Graph && span.name == "mul")
+            .unwrap();
+        assert_eq!(mul_graph.depth, mul_forward.depth + 1);
+        assert!(mul_forward.self_us <= mul_forward.duration_us);
+
+        let backward_names: Vec<&str> = profile
+            .spans
+            .iter()
+            .filter(|span| span.category == SpanCategory::Backward)
+            .map(|span| span.name.as_str())
+            .collect();
+        assert!(backward_names.contains(&"ExpBackward"));
+        assert!(backward_names.contains(&"MulBackward"));
+        assert!(backward_names.contains(&"GradAccum"));
+
+        let summary = profile.summary();
+        for pair in summary.windows(2) {
+            assert!(pair[0].self_us >= pair[1].self_us);
+        }
+
+        let trace: serde_json::Value = serde_json::from_str(&profile.to_chrome_trace()).unwrap();
+        assert_eq!(
+            trace["traceEvents"].as_array().unwrap().len(),
+            profile.spans.len()
+        );
+    }
+}
@@ -1,8 +1,14 @@
 pub mod autograd_meta;
+pub mod buffer_pool;
 pub mod dtypes;
+pub mod half;
+pub mod printing;
+pub mod random;
+pub mod raw_data;
 pub mod storage;
 
 pub mod tensor;
 pub mod tensor_builder;
 pub mod tensor_impl;
+pub mod tensor_serde;
 pub mod tensor_utils;
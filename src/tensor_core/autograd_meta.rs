@@ -3,7 +3,11 @@ use super::tensor::Tensor;
 use super::tensor_impl::TensorImpl;
 
 use super::super::graph::backward::Backward;
+use super::super::graph::backward::backward_utils::apply_node;
 use super::super::graph::backward::grad_accum::GradAccum;
+use super::super::ops::compute::add_compute::add_compute_tensor_tensor;
+use super::super::profiler::memory_profiler;
+use super::super::profiler::timing_profiler::{self, SpanCategory};
 
 use std::cell::{Ref, RefCell};
 use std::fmt::Debug;
@@ -40,6 +44,7 @@ where
         return autograd_meta;
     }
 
+    #[track_caller]
     pub fn new_for_leaf(name: String, origin: Rc<RefCell<TensorImpl<T>>>) -> Self {
         let grad_accum = Rc::new(RefCell::new(GradAccum::<T>::new_with_origin(
             vec![],
@@ -116,18 +121,57 @@ impl<T> AutogradMeta<T>
 where
     T: DTComp + Clone + Debug + Add<Output = T>,
 {
+    /// Add `grad` to the gradient of this tensor. When the existing gradient buffer is not shared
+    /// with anything else, the sum is written into it instead of allocating a new tensor.
+    pub fn accumulate_grad(&self, grad: &Rc<Tensor<T>>) {
+        let mut tensor_grad = self.grad.borrow_mut();
+
+        match tensor_grad.as_mut() {
+            Some(existing_grad) => {
+                let uniquely_owned = Rc::strong_count(existing_grad) == 1
+                    && Rc::strong_count(existing_grad.__get_tensor_impl()) == 1;
+
+                if uniquely_owned && *existing_grad.get_shape() == *grad.get_shape() {
+                    let mut existing_impl = existing_grad.__get_tensor_impl().borrow_mut();
+
+                    if let Some(existing_raw) = existing_impl.get_raw_data_mut_() {
+                        let grad_raw = grad.get_raw_data();
+                        existing_raw
+                            .zip_mut_with(&grad_raw.view(), |a, b| *a = a.clone() + b.clone());
+
+                        return;
+                    }
+                }
+
+                let new_grad = add_compute_tensor_tensor(existing_grad.deref(), grad.deref());
+                *existing_grad = Rc::new(new_grad);
+            }
+            None => {
+                *tensor_grad = Some(Rc::clone(grad));
+            }
+        }
+    }
+
     pub fn start_backprop_chain(&self, starting_gradient: Rc<Tensor<T>>, retain_graph: bool) {
         if self.is_leaf() {
             println!(
                 "Warning: Calling backward on leaf tensor will directly set the gradient of the tensor to the starting gradient of backpropagation"
             );
             if let Some(node_arc_ref) = self.get_grad_accum() {
+                let _span = timing_profiler::span(
+                    SpanCategory::Backward,
+                    &node_arc_ref.borrow().get_name(),
+                    &[starting_gradient.as_ref()],
+                );
+
+                memory_profiler::enter_node(&*node_arc_ref.borrow());
                 node_arc_ref.borrow().apply(starting_gradient, retain_graph);
+                memory_profiler::exit_node();
                 return;
             }
         } else {
             if let Some(node_arc_ref) = self.get_grad_fn() {
-                node_arc_ref.borrow().apply(starting_gradient, retain_graph);
+                apply_node(node_arc_ref, starting_gradient, retain_graph);
             } else {
                 panic!(
                     "Warning: Calling backward on a tensor that is not a leaf tensor and not an intermediate tensor. This tensor has no connection to the computation graph."
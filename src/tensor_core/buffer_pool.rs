@@ -0,0 +1,282 @@
+use ndarray::{ArrayBase, ArrayD, ArrayView, Dimension, IxDyn};
+
+use crate::profiler::memory_profiler;
+use crate::tensor_core::dtypes::DTComp;
+use crate::utils::shaping_utils::broadcast_shapes;
+
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+
+/// Upper bound on the bytes kept alive by the pool on each thread
+pub const DEFAULT_POOL_CAPACITY_BYTES: usize = 256 * 1024 * 1024;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PoolStats {
+    pub hits: usize,
+    pub misses: usize,
+    pub releases: usize,
+    pub evictions: usize,
+    pub pooled_bytes: usize,
+}
+
+impl PoolStats {
+    pub fn hit_rate(&self) -> f64 {
+        let requests = self.hits + self.misses;
+        if requests == 0 {
+            return 0.0;
+        }
+
+        return self.hits as f64 / requests as f64;
+    }
+}
+
+#[derive(Debug)]
+struct BufferPool {
+    enabled: bool,
+    capacity_bytes: usize,
+    buckets: HashMap<(TypeId, usize), Vec<Box<dyn Any>>>,
+    // addresses of the buffers handed out by `acquire` that are still in use
+    issued: HashSet<usize>,
+    stats: PoolStats,
+}
+
+impl Default for BufferPool {
+    fn default() -> Self {
+        return BufferPool {
+            enabled: false,
+            capacity_bytes: DEFAULT_POOL_CAPACITY_BYTES,
+            buckets: HashMap::new(),
+            issued: HashSet::new(),
+            stats: PoolStats::default(),
+        };
+    }
+}
+
+thread_local! {
+    static BUFFER_POOL: RefCell<BufferPool> = RefCell::new(BufferPool::default());
+}
+
+/// Buffers are bucketed by capacity rounded to a power of two, so any buffer in a bucket can hold
+/// every request mapped to that bucket
+fn request_bucket(numel: usize) -> usize {
+    return numel.max(1).next_power_of_two();
+}
+
+fn release_bucket(capacity: usize) -> usize {
+    return 1 << (usize::BITS - 1 - capacity.leading_zeros());
+}
+
+/// Get an empty buffer that can hold at least `numel` elements, reusing a released buffer when one
+/// is available
+pub fn acquire<T>(numel: usize) -> Vec<T>
+where
+    T: DTComp,
+{
+    let bucket = request_bucket(numel);
+
+    let buffer = BUFFER_POOL
+        .try_with(|pool| {
+            let mut pool = pool.borrow_mut();
+            if !pool.enabled {
+                return None;
+            }
+
+            let reused = pool
+                .buckets
+                .get_mut(&(TypeId::of::<T>(), bucket))
+                .and_then(|buffers| buffers.pop());
+
+            let buffer = match reused {
+                Some(buffer) => {
+                    let buffer = *buffer
+                        .downcast::<Vec<T>>()
+                        .expect("Error: Buffer pool bucket holds a buffer of the wrong type");
+
+                    pool.stats.hits += 1;
+                    pool.stats.pooled_bytes -= buffer.capacity() * std::mem::size_of::<T>();
+                    memory_profiler::record_pooled_bytes(pool.stats.pooled_bytes);
+
+                    buffer
+                }
+                None => {
+                    pool.stats.misses += 1;
+                    Vec::with_capacity(bucket)
+                }
+            };
+
+            pool.issued.insert(buffer.as_ptr() as usize);
+
+            return Some(buffer);
+        })
+        .ok()
+        .flatten();
+
+    return buffer.unwrap_or_else(|| Vec::with_capacity(bucket));
+}
+
+/// Hand a buffer that is no longer used back to the pool. Only buffers that came from `acquire`
+/// are kept, any other buffer would never be asked for again and is simply freed.
+pub fn release<T>(mut buffer: Vec<T>)
+where
+    T: DTComp,
+{
+    if buffer.capacity() == 0 {
+        return;
+    }
+
+    buffer.clear();
+    let nbytes = buffer.capacity() * std::mem::size_of::<T>();
+    let bucket = release_bucket(buffer.capacity());
+
+    // the thread local may already be destroyed when tensors are dropped at thread exit
+    let _ = BUFFER_POOL.try_with(|pool| {
+        let mut pool = pool.borrow_mut();
+        if !pool.enabled || !pool.issued.remove(&(buffer.as_ptr() as usize)) {
+            return;
+        }
+
+        if pool.stats.pooled_bytes + nbytes > pool.capacity_bytes {
+            pool.stats.evictions += 1;
+            return;
+        }
+
+        pool.stats.releases += 1;
+        pool.stats.pooled_bytes += nbytes;
+        memory_profiler::record_pooled_bytes(pool.stats.pooled_bytes);
+        pool.buckets
+            .entry((TypeId::of::<T>(), bucket))
+            .or_default()
+            .push(Box::new(buffer));
+    });
+}
+
+/// Array of the given shape with every element set to `value`, backed by a pooled buffer
+pub fn filled<T>(shape: &[usize], value: T) -> ArrayD<T>
+where
+    T: DTComp + Clone,
+{
+    let numel = shape.iter().product();
+    let mut buffer = acquire::<T>(numel);
+    buffer.resize(numel, value);
+
+    return ArrayBase::from_shape_vec(IxDyn(shape), buffer)
+        .expect("Error: Pooled buffer does not match the requested shape");
+}
+
+/// Element wise combination of two arrays with broadcasting, written into a pooled buffer
+pub fn zip_map<T, D1, D2, F>(lhs: ArrayView<T, D1>, rhs: ArrayView<T, D2>, f: F) -> ArrayD<T>
+where
+    T: DTComp + Clone,
+    D1: Dimension,
+    D2: Dimension,
+    F: Fn(&T, &T) -> T,
+{
+    let shape = broadcast_shapes(&[lhs.shape().to_vec(), rhs.shape().to_vec()])
+        .expect("Error: Shapes of the operands cannot be broadcasted together");
+
+    let lhs = lhs.broadcast(shape.clone()).unwrap();
+    let rhs = rhs.broadcast(shape.clone()).unwrap();
+
+    let mut buffer = acquire::<T>(lhs.len());
+    buffer.extend(lhs.iter().zip(rhs.iter()).map(|(a, b)| f(a, b)));
+
+    return ArrayBase::from_shape_vec(IxDyn(&shape), buffer)
+        .expect("Error: Pooled buffer does not match the broadcasted shape");
+}
+
+/// The pool is off by default. Only add, mul and the filled constructors such as `zeros` take
+/// their buffers from it, so it pays off when those dominate a workload. Buffers of other ops are
+/// freed as usual and never take up room in the pool.
+pub fn set_enabled(enabled: bool) {
+    BUFFER_POOL.with(|pool| {
+        let mut pool = pool.borrow_mut();
+        pool.enabled = enabled;
+
+        if !enabled {
+            pool.buckets.clear();
+            pool.issued.clear();
+            pool.stats.pooled_bytes = 0;
+            memory_profiler::record_pooled_bytes(0);
+        }
+    });
+}
+
+pub fn is_enabled() -> bool {
+    return BUFFER_POOL.with(|pool| pool.borrow().enabled);
+}
+
+pub fn set_capacity_bytes(capacity_bytes: usize) {
+    BUFFER_POOL.with(|pool| pool.borrow_mut().capacity_bytes = capacity_bytes);
+}
+
+pub fn stats() -> PoolStats {
+    return BUFFER_POOL.with(|pool| pool.borrow().stats);
+}
+
+pub fn reset_stats() {
+    BUFFER_POOL.with(|pool| {
+        let mut pool = pool.borrow_mut();
+        pool.stats = PoolStats {
+            pooled_bytes: pool.stats.pooled_bytes,
+            ..PoolStats::default()
+        };
+    });
+}
+
+/// Drop every pooled buffer
+pub fn clear() {
+    BUFFER_POOL.with(|pool| {
+        let mut pool = pool.borrow_mut();
+        pool.buckets.clear();
+        pool.stats.pooled_bytes = 0;
+        memory_profiler::record_pooled_bytes(0);
+    });
+}
+
+#[cfg(test)]
+pub mod test {
+    #[allow(unused)]
+    use super::*;
+    use crate::tensor_core::tensor::Tensor;
+
+    #[test]
+    fn freed_storage_is_reused() {
+        set_enabled(true);
+        clear();
+        reset_stats();
+
+        let x1 = Tensor::<f32>::ones(&vec![3, 5], None);
+        let ptr = x1.get_raw_data().as_ptr();
+        drop(x1);
+
+        assert_eq!(stats().releases, 1);
+
+        // buffers that did not come from the pool are freed instead of filling it up
+        drop(Tensor::new(vec![1.0_f32; 8], vec![2, 4], false).exp());
+        assert_eq!(stats().releases, 1);
+        assert_eq!(stats().pooled_bytes, 16 * std::mem::size_of::<f32>());
+
+        // 12 elements fall in the same bucket as 15
+        let x2 = Tensor::<f32>::zeros(&vec![4, 3], None);
+        assert_eq!(x2.get_raw_data().as_ptr(), ptr);
+        assert!(x2.get_raw_data().iter().all(|elem| *elem == 0.0));
+
+        let pool_stats = stats();
+        assert_eq!(pool_stats.hits, 1);
+        assert_eq!(pool_stats.misses, 1);
+        assert_eq!(pool_stats.hit_rate(), 0.5);
+
+        // buffers kept by the pool still show up in the memory profile
+        memory_profiler::start();
+        drop(x2);
+        let x3 = Tensor::<f32>::ones(&vec![16], None);
+        let profile = memory_profiler::stop();
+        assert_eq!(profile.pooled_peak_bytes, 16 * std::mem::size_of::<f32>());
+        assert_eq!(profile.timeline.last().unwrap().pooled_bytes, 0);
+        drop(x3);
+
+        set_enabled(false);
+        assert!(!is_enabled());
+    }
+}
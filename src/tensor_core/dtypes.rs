@@ -1,6 +1,17 @@
-#[derive(Debug, Copy, Clone)]
+use ndarray::ScalarOperand;
+use num_complex::Complex;
+use num_traits::{Float, One, Zero};
+
+use crate::tensor_core::half::{BF16, F16};
+
+use std::fmt::Debug;
+use std::ops::{Add, Div, Mul, Sub};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum DTypes {
     Bool,
+    Float16,
+    BFloat16,
     Float32,
     Float64,
     Int8,
@@ -15,9 +26,11 @@ pub enum DTypes {
     Uint64,
     Uint128,
     Usize,
+    Complex64,
+    Complex128,
 }
 
-pub trait DTComp {
+pub trait DTComp: 'static {
     fn dtype() -> DTypes;
 }
 
@@ -111,10 +124,24 @@ impl DTComp for usize {
     }
 }
 
+impl DTComp for Complex<f32> {
+    fn dtype() -> DTypes {
+        return DTypes::Complex64;
+    }
+}
+
+impl DTComp for Complex<f64> {
+    fn dtype() -> DTypes {
+        return DTypes::Complex128;
+    }
+}
+
 impl std::fmt::Display for DTypes {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             DTypes::Bool => write!(f, "bool"),
+            DTypes::Float16 => write!(f, "float16"),
+            DTypes::BFloat16 => write!(f, "bfloat16"),
             DTypes::Float32 => write!(f, "float32"),
             DTypes::Float64 => write!(f, "float64"),
             DTypes::Int8 => write!(f, "int8"),
@@ -129,6 +156,285 @@ impl std::fmt::Display for DTypes {
             DTypes::Uint64 => write!(f, "uint64"),
             DTypes::Uint128 => write!(f, "uint128"),
             DTypes::Usize => write!(f, "usize"),
+            DTypes::Complex64 => write!(f, "complex64"),
+            DTypes::Complex128 => write!(f, "complex128"),
         }
     }
 }
+
+/// Kind and width in bits of a dtype, kinds are ordered as bool, unsigned, signed, float and
+/// complex
+fn dtype_category(dtype: DTypes) -> (u8, usize) {
+    match dtype {
+        DTypes::Bool => (0, 8),
+        DTypes::Uint8 => (1, 8),
+        DTypes::Uint16 => (1, 16),
+        DTypes::Uint32 => (1, 32),
+        DTypes::Uint64 | DTypes::Usize => (1, 64),
+        DTypes::Uint128 => (1, 128),
+        DTypes::Int8 => (2, 8),
+        DTypes::Int16 => (2, 16),
+        DTypes::Int32 => (2, 32),
+        DTypes::Int64 | DTypes::Isize => (2, 64),
+        DTypes::Int128 => (2, 128),
+        DTypes::Float16 | DTypes::BFloat16 => (3, 16),
+        DTypes::Float32 => (3, 32),
+        DTypes::Float64 => (3, 64),
+        DTypes::Complex64 => (4, 64),
+        DTypes::Complex128 => (4, 128),
+    }
+}
+
+fn dtype_from_category(kind: u8, bits: usize) -> DTypes {
+    match (kind, bits) {
+        (0, _) => DTypes::Bool,
+        (1, 8) => DTypes::Uint8,
+        (1, 16) => DTypes::Uint16,
+        (1, 32) => DTypes::Uint32,
+        (1, 64) => DTypes::Uint64,
+        (1, 128) => DTypes::Uint128,
+        (2, 8) => DTypes::Int8,
+        (2, 16) => DTypes::Int16,
+        (2, 32) => DTypes::Int32,
+        (2, 64) => DTypes::Int64,
+        (2, 128) => DTypes::Int128,
+        (3, 16) => DTypes::Float16,
+        (3, 32) => DTypes::Float32,
+        (4, 64) => DTypes::Complex64,
+        (4, 128) => DTypes::Complex128,
+        _ => DTypes::Float64,
+    }
+}
+
+/// Element type of the result of a binary operation between two dtypes. Bool promotes to any
+/// other type, floats win over integers, and between two types of the same kind the wider one
+/// wins. An unsigned and a signed integer promote to the narrowest signed integer that holds both,
+/// or to float64 when that would need more than 64 bits. The two 16 bit floats promote to
+/// float32, since neither holds the other. Complex wins over every other kind, with parts wide
+/// enough to hold a float operand.
+pub fn promote_types(lhs: DTypes, rhs: DTypes) -> DTypes {
+    if lhs == rhs {
+        return lhs;
+    }
+
+    let (lhs_kind, lhs_bits) = dtype_category(lhs);
+    let (rhs_kind, rhs_bits) = dtype_category(rhs);
+
+    if lhs_kind == 4 || rhs_kind == 4 {
+        // width of a single part, integers and bools fit in any of them
+        let part_bits = |kind: u8, bits: usize| match kind {
+            4 => bits / 2,
+            3 => bits,
+            _ => 0,
+        };
+
+        let bits = part_bits(lhs_kind, lhs_bits)
+            .max(part_bits(rhs_kind, rhs_bits))
+            .max(32);
+        return dtype_from_category(4, bits * 2);
+    }
+
+    if matches!(
+        (lhs, rhs),
+        (DTypes::Float16, DTypes::BFloat16) | (DTypes::BFloat16, DTypes::Float16)
+    ) {
+        return DTypes::Float32;
+    }
+
+    if lhs_kind == rhs_kind {
+        return dtype_from_category(lhs_kind, lhs_bits.max(rhs_bits));
+    } else if lhs_kind == 0 || rhs_kind == 3 {
+        return rhs;
+    } else if rhs_kind == 0 || lhs_kind == 3 {
+        return lhs;
+    }
+
+    let (unsigned_bits, signed_bits) = if lhs_kind == 1 {
+        (lhs_bits, rhs_bits)
+    } else {
+        (rhs_bits, lhs_bits)
+    };
+
+    if signed_bits > unsigned_bits {
+        return dtype_from_category(2, signed_bits);
+    } else if unsigned_bits * 2 <= 64 {
+        return dtype_from_category(2, unsigned_bits * 2);
+    } else {
+        return DTypes::Float64;
+    }
+}
+
+/// Type level counterpart of `promote_types`, giving the element type of a binary operation
+/// between tensors of `Self` and `Rhs`
+pub trait Promote<Rhs>: DTComp {
+    type Output: DTComp;
+}
+
+impl<T> Promote<T> for T
+where
+    T: DTComp,
+{
+    type Output = T;
+}
+
+macro_rules! impl_promote {
+    ($lhs:ty: $($rhs:ty => $output:ty),+) => {
+        $(
+            impl Promote<$rhs> for $lhs {
+                type Output = $output;
+            }
+        )+
+    };
+}
+
+impl_promote!(f32: f64 => f64, i8 => f32, i16 => f32, i32 => f32, i64 => f32, u8 => f32, u16 => f32, u32 => f32, u64 => f32);
+impl_promote!(f64: f32 => f64, i8 => f64, i16 => f64, i32 => f64, i64 => f64, u8 => f64, u16 => f64, u32 => f64, u64 => f64);
+impl_promote!(i8: f32 => f32, f64 => f64, i16 => i16, i32 => i32, i64 => i64, u8 => i16, u16 => i32, u32 => i64, u64 => f64);
+impl_promote!(i16: f32 => f32, f64 => f64, i8 => i16, i32 => i32, i64 => i64, u8 => i16, u16 => i32, u32 => i64, u64 => f64);
+impl_promote!(i32: f32 => f32, f64 => f64, i8 => i32, i16 => i32, i64 => i64, u8 => i32, u16 => i32, u32 => i64, u64 => f64);
+impl_promote!(i64: f32 => f32, f64 => f64, i8 => i64, i16 => i64, i32 => i64, u8 => i64, u16 => i64, u32 => i64, u64 => f64);
+impl_promote!(u8: f32 => f32, f64 => f64, i8 => i16, i16 => i16, i32 => i32, i64 => i64, u16 => u16, u32 => u32, u64 => u64);
+impl_promote!(u16: f32 => f32, f64 => f64, i8 => i32, i16 => i32, i32 => i32, i64 => i64, u8 => u16, u32 => u32, u64 => u64);
+impl_promote!(u32: f32 => f32, f64 => f64, i8 => i64, i16 => i64, i32 => i64, i64 => i64, u8 => u32, u16 => u32, u64 => u64);
+impl_promote!(u64: f32 => f32, f64 => f64, i8 => f64, i16 => f64, i32 => f64, i64 => f64, u8 => u64, u16 => u64, u32 => u64);
+impl_promote!(F16: BF16 => f32, f32 => f32, f64 => f64, i8 => F16, i16 => F16, i32 => F16, i64 => F16, u8 => F16, u16 => F16, u32 => F16, u64 => F16);
+impl_promote!(BF16: F16 => f32, f32 => f32, f64 => f64, i8 => BF16, i16 => BF16, i32 => BF16, i64 => BF16, u8 => BF16, u16 => BF16, u32 => BF16, u64 => BF16);
+impl_promote!(f32: F16 => f32, BF16 => f32);
+impl_promote!(f64: F16 => f64, BF16 => f64);
+impl_promote!(i8: F16 => F16, BF16 => BF16);
+impl_promote!(i16: F16 => F16, BF16 => BF16);
+impl_promote!(i32: F16 => F16, BF16 => BF16);
+impl_promote!(i64: F16 => F16, BF16 => BF16);
+impl_promote!(u8: F16 => F16, BF16 => BF16);
+impl_promote!(u16: F16 => F16, BF16 => BF16);
+impl_promote!(u32: F16 => F16, BF16 => BF16);
+impl_promote!(u64: F16 => F16, BF16 => BF16);
+impl_promote!(Complex<f32>: Complex<f64> => Complex<f64>, f32 => Complex<f32>, f64 => Complex<f64>);
+impl_promote!(Complex<f64>: Complex<f32> => Complex<f64>, f32 => Complex<f64>, f64 => Complex<f64>);
+impl_promote!(f32: Complex<f32> => Complex<f32>, Complex<f64> => Complex<f64>);
+impl_promote!(f64: Complex<f32> => Complex<f64>, Complex<f64> => Complex<f64>);
+
+/// Floating point dtypes, real or complex. Element wise functions such as `exp` are defined on
+/// both, complex numbers taking the principal branch of `ln` and `pow`.
+pub trait DTFloat:
+    DTComp
+    + Copy
+    + Debug
+    + One
+    + Zero
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + ScalarOperand
+{
+    fn elem_exp(self) -> Self;
+    fn elem_exp2(self) -> Self;
+    fn elem_ln(self) -> Self;
+    fn elem_tanh(self) -> Self;
+    fn elem_pow(self, exponent: Self) -> Self;
+}
+
+macro_rules! impl_dtfloat {
+    ($pow:ident: $($ty:ty),+) => {
+        $(
+            impl DTFloat for $ty {
+                fn elem_exp(self) -> Self {
+                    return self.exp();
+                }
+
+                fn elem_exp2(self) -> Self {
+                    return self.exp2();
+                }
+
+                fn elem_ln(self) -> Self {
+                    return self.ln();
+                }
+
+                fn elem_tanh(self) -> Self {
+                    return self.tanh();
+                }
+
+                fn elem_pow(self, exponent: Self) -> Self {
+                    return self.$pow(exponent);
+                }
+            }
+        )+
+    };
+}
+
+impl_dtfloat!(powf: f32, f64, F16, BF16);
+impl_dtfloat!(powc: Complex<f32>, Complex<f64>);
+
+#[cfg(test)]
+pub mod test {
+    #[allow(unused)]
+    use super::*;
+
+    fn promoted_dtype<L, R>() -> DTypes
+    where
+        L: Promote<R>,
+    {
+        return <L as Promote<R>>::Output::dtype();
+    }
+
+    #[test]
+    fn type_promotion_rules() {
+        assert_eq!(
+            promote_types(DTypes::Float32, DTypes::Float64),
+            DTypes::Float64
+        );
+        assert_eq!(
+            promote_types(DTypes::Int64, DTypes::Float32),
+            DTypes::Float32
+        );
+        assert_eq!(promote_types(DTypes::Uint8, DTypes::Int8), DTypes::Int16);
+        assert_eq!(
+            promote_types(DTypes::Uint64, DTypes::Int64),
+            DTypes::Float64
+        );
+        assert_eq!(promote_types(DTypes::Bool, DTypes::Uint16), DTypes::Uint16);
+        assert_eq!(promote_types(DTypes::Isize, DTypes::Int32), DTypes::Int64);
+
+        // the type level rules agree with the runtime ones
+        assert_eq!(promoted_dtype::<f32, f64>(), DTypes::Float64);
+        assert_eq!(
+            promoted_dtype::<u16, i8>(),
+            promote_types(DTypes::Uint16, DTypes::Int8)
+        );
+        assert_eq!(
+            promoted_dtype::<u32, i32>(),
+            promote_types(DTypes::Uint32, DTypes::Int32)
+        );
+        assert_eq!(
+            promoted_dtype::<i64, u64>(),
+            promote_types(DTypes::Int64, DTypes::Uint64)
+        );
+        assert_eq!(promoted_dtype::<i32, i32>(), DTypes::Int32);
+
+        assert_eq!(
+            promote_types(DTypes::Float16, DTypes::BFloat16),
+            DTypes::Float32
+        );
+        assert_eq!(
+            promote_types(DTypes::Int64, DTypes::BFloat16),
+            DTypes::BFloat16
+        );
+        assert_eq!(promoted_dtype::<F16, f32>(), DTypes::Float32);
+        assert_eq!(promoted_dtype::<u8, F16>(), DTypes::Float16);
+
+        assert_eq!(
+            promote_types(DTypes::Complex64, DTypes::Float64),
+            DTypes::Complex128
+        );
+        assert_eq!(
+            promote_types(DTypes::Int64, DTypes::Complex64),
+            DTypes::Complex64
+        );
+        assert_eq!(
+            promote_types(DTypes::Float16, DTypes::Complex64),
+            DTypes::Complex64
+        );
+        assert_eq!(promoted_dtype::<f64, Complex<f32>>(), DTypes::Complex128);
+    }
+}
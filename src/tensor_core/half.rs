@@ -0,0 +1,615 @@
+use ndarray::ScalarOperand;
+use num_traits::{AsPrimitive, Bounded, Float, Num, NumCast, One, Signed, ToPrimitive, Zero};
+use serde::{Serialize, Serializer};
+
+use crate::tensor_core::dtypes::{DTComp, DTypes};
+use crate::tensor_core::tensor::Tensor;
+
+use std::any::Any;
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display};
+use std::num::FpCategory;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+
+// Software implemented 16 bit floats. Only the bits are stored, every arithmetic operation widens
+// to f32, computes there and rounds back to the nearest representable value.
+
+/// IEEE 754 half precision float, with 5 exponent bits and 10 mantissa bits
+#[derive(Clone, Copy, Default)]
+pub struct F16(u16);
+
+/// Brain float, with the 8 exponent bits of f32 and 7 mantissa bits
+#[derive(Clone, Copy, Default)]
+pub struct BF16(u16);
+
+/// Element types stored in 16 bits that are computed on as f32
+pub trait HalfFloat: DTComp + Copy + Debug {
+    fn from_f32(value: f32) -> Self;
+
+    fn to_f32(self) -> f32;
+}
+
+impl F16 {
+    pub const ZERO: F16 = F16(0x0000);
+    pub const NEG_ZERO: F16 = F16(0x8000);
+    pub const ONE: F16 = F16(0x3c00);
+    pub const MAX: F16 = F16(0x7bff);
+    pub const MIN: F16 = F16(0xfbff);
+    pub const MIN_POSITIVE: F16 = F16(0x0400);
+    pub const EPSILON: F16 = F16(0x1400);
+    pub const INFINITY: F16 = F16(0x7c00);
+    pub const NEG_INFINITY: F16 = F16(0xfc00);
+    pub const NAN: F16 = F16(0x7e00);
+
+    pub const fn from_bits(bits: u16) -> Self {
+        return F16(bits);
+    }
+
+    pub const fn to_bits(self) -> u16 {
+        return self.0;
+    }
+
+    /// Rounds to the nearest half precision value, ties to even. Values too large for half
+    /// precision become infinity.
+    pub fn from_f32(value: f32) -> Self {
+        let x = value.to_bits();
+
+        let sign = ((x >> 16) & 0x8000) as u16;
+        let exp = ((x >> 23) & 0xff) as i32;
+        let man = x & 0x007f_ffff;
+
+        // infinity and nan, keeping nan quiet
+        if exp == 0xff {
+            let nan_bit = if man != 0 { 0x0200 } else { 0 };
+            return F16(sign | 0x7c00 | nan_bit | (man >> 13) as u16);
+        }
+
+        let half_exp = exp - 127 + 15;
+
+        if half_exp >= 0x1f {
+            return F16(sign | 0x7c00);
+        }
+
+        if half_exp <= 0 {
+            // too small even for a subnormal
+            if 14 - half_exp > 24 {
+                return F16(sign);
+            }
+
+            let man = man | 0x0080_0000;
+            let mut half_man = man >> (14 - half_exp);
+            let round_bit = 1 << (13 - half_exp);
+
+            if (man & round_bit) != 0 && (man & (3 * round_bit - 1)) != 0 {
+                half_man += 1;
+            }
+
+            return F16(sign | half_man as u16);
+        }
+
+        let half_bits = sign | ((half_exp as u16) << 10) | (man >> 13) as u16;
+        let round_bit = 0x1000;
+
+        // a carry out of the mantissa correctly rounds up into the exponent
+        if (man & round_bit) != 0 && (man & (3 * round_bit - 1)) != 0 {
+            return F16(half_bits + 1);
+        }
+
+        return F16(half_bits);
+    }
+
+    pub fn to_f32(self) -> f32 {
+        let bits = self.0 as u32;
+
+        let sign = (bits & 0x8000) << 16;
+        let exp = (bits >> 10) & 0x1f;
+        let man = bits & 0x03ff;
+
+        if exp == 0 {
+            // zero and subnormals are multiples of 2^-24
+            let value = man as f32 * (1.0 / 16_777_216.0);
+            return if sign != 0 { -value } else { value };
+        }
+
+        if exp == 0x1f {
+            return f32::from_bits(sign | 0x7f80_0000 | (man << 13));
+        }
+
+        return f32::from_bits(sign | ((exp + 127 - 15) << 23) | (man << 13));
+    }
+}
+
+impl BF16 {
+    pub const ZERO: BF16 = BF16(0x0000);
+    pub const NEG_ZERO: BF16 = BF16(0x8000);
+    pub const ONE: BF16 = BF16(0x3f80);
+    pub const MAX: BF16 = BF16(0x7f7f);
+    pub const MIN: BF16 = BF16(0xff7f);
+    pub const MIN_POSITIVE: BF16 = BF16(0x0080);
+    pub const EPSILON: BF16 = BF16(0x3c00);
+    pub const INFINITY: BF16 = BF16(0x7f80);
+    pub const NEG_INFINITY: BF16 = BF16(0xff80);
+    pub const NAN: BF16 = BF16(0x7fc0);
+
+    pub const fn from_bits(bits: u16) -> Self {
+        return BF16(bits);
+    }
+
+    pub const fn to_bits(self) -> u16 {
+        return self.0;
+    }
+
+    /// Rounds to the nearest brain float value, ties to even
+    pub fn from_f32(value: f32) -> Self {
+        let x = value.to_bits();
+
+        if value.is_nan() {
+            return BF16(((x >> 16) as u16) | 0x0040);
+        }
+
+        let rounding = 0x7fff + ((x >> 16) & 1);
+        return BF16((x.wrapping_add(rounding) >> 16) as u16);
+    }
+
+    pub fn to_f32(self) -> f32 {
+        return f32::from_bits((self.0 as u32) << 16);
+    }
+}
+
+impl DTComp for F16 {
+    fn dtype() -> DTypes {
+        return DTypes::Float16;
+    }
+}
+
+impl DTComp for BF16 {
+    fn dtype() -> DTypes {
+        return DTypes::BFloat16;
+    }
+}
+
+macro_rules! unary_through_f32 {
+    ($($name:ident),+) => {
+        $(
+            fn $name(self) -> Self {
+                return Self::from_f32(self.to_f32().$name());
+            }
+        )+
+    };
+}
+
+macro_rules! predicate_through_f32 {
+    ($($name:ident),+) => {
+        $(
+            fn $name(self) -> bool {
+                return self.to_f32().$name();
+            }
+        )+
+    };
+}
+
+macro_rules! binary_op_through_f32 {
+    ($half:ty, $($trait:ident $method:ident $assign_trait:ident $assign_method:ident $op:tt),+) => {
+        $(
+            impl $trait for $half {
+                type Output = $half;
+
+                fn $method(self, rhs: $half) -> $half {
+                    return <$half>::from_f32(self.to_f32() $op rhs.to_f32());
+                }
+            }
+
+            impl $assign_trait for $half {
+                fn $assign_method(&mut self, rhs: $half) {
+                    *self = *self $op rhs;
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! as_primitive_through_f32 {
+    ($half:ty: $($primitive:ty),+) => {
+        $(
+            impl AsPrimitive<$primitive> for $half {
+                fn as_(self) -> $primitive {
+                    return self.to_f32() as $primitive;
+                }
+            }
+
+            impl AsPrimitive<$half> for $primitive {
+                fn as_(self) -> $half {
+                    return <$half>::from_f32(self as f32);
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_half_float {
+    ($half:ident) => {
+        impl HalfFloat for $half {
+            fn from_f32(value: f32) -> Self {
+                return $half::from_f32(value);
+            }
+
+            fn to_f32(self) -> f32 {
+                return $half::to_f32(self);
+            }
+        }
+
+        impl From<$half> for f32 {
+            fn from(value: $half) -> f32 {
+                return value.to_f32();
+            }
+        }
+
+        impl From<$half> for f64 {
+            fn from(value: $half) -> f64 {
+                return value.to_f32() as f64;
+            }
+        }
+
+        impl PartialEq for $half {
+            fn eq(&self, other: &$half) -> bool {
+                return $half::to_f32(*self) == $half::to_f32(*other);
+            }
+        }
+
+        impl PartialOrd for $half {
+            fn partial_cmp(&self, other: &$half) -> Option<Ordering> {
+                return $half::to_f32(*self).partial_cmp(&$half::to_f32(*other));
+            }
+        }
+
+        impl Debug for $half {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                return Debug::fmt(&$half::to_f32(*self), f);
+            }
+        }
+
+        impl Display for $half {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                return Display::fmt(&$half::to_f32(*self), f);
+            }
+        }
+
+        impl Serialize for $half {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                return serializer.serialize_f32($half::to_f32(*self));
+            }
+        }
+
+        binary_op_through_f32!(
+            $half,
+            Add add AddAssign add_assign +,
+            Sub sub SubAssign sub_assign -,
+            Mul mul MulAssign mul_assign *,
+            Div div DivAssign div_assign /
+        );
+
+        impl Rem for $half {
+            type Output = $half;
+
+            fn rem(self, rhs: $half) -> $half {
+                return $half::from_f32(self.to_f32() % rhs.to_f32());
+            }
+        }
+
+        impl Neg for $half {
+            type Output = $half;
+
+            fn neg(self) -> $half {
+                return $half(self.0 ^ 0x8000);
+            }
+        }
+
+        impl ScalarOperand for $half {}
+
+        impl Zero for $half {
+            fn zero() -> Self {
+                return $half::ZERO;
+            }
+
+            fn is_zero(&self) -> bool {
+                return $half::to_f32(*self) == 0.0;
+            }
+        }
+
+        impl One for $half {
+            fn one() -> Self {
+                return $half::ONE;
+            }
+        }
+
+        impl Num for $half {
+            type FromStrRadixErr = <f32 as Num>::FromStrRadixErr;
+
+            fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+                return f32::from_str_radix(str, radix).map($half::from_f32);
+            }
+        }
+
+        impl ToPrimitive for $half {
+            fn to_i64(&self) -> Option<i64> {
+                return $half::to_f32(*self).to_i64();
+            }
+
+            fn to_u64(&self) -> Option<u64> {
+                return $half::to_f32(*self).to_u64();
+            }
+
+            fn to_f32(&self) -> Option<f32> {
+                return Some($half::to_f32(*self));
+            }
+
+            fn to_f64(&self) -> Option<f64> {
+                return Some($half::to_f32(*self) as f64);
+            }
+        }
+
+        impl NumCast for $half {
+            fn from<N: ToPrimitive>(n: N) -> Option<Self> {
+                return n.to_f32().map($half::from_f32);
+            }
+        }
+
+        impl Bounded for $half {
+            fn min_value() -> Self {
+                return $half::MIN;
+            }
+
+            fn max_value() -> Self {
+                return $half::MAX;
+            }
+        }
+
+        impl Signed for $half {
+            fn abs(&self) -> Self {
+                return Float::abs(*self);
+            }
+
+            fn signum(&self) -> Self {
+                return Float::signum(*self);
+            }
+
+            fn abs_sub(&self, other: &Self) -> Self {
+                return $half::from_f32(Float::abs_sub($half::to_f32(*self), $half::to_f32(*other)));
+            }
+
+            fn is_positive(&self) -> bool {
+                return $half::to_f32(*self) > 0.0;
+            }
+
+            fn is_negative(&self) -> bool {
+                return $half::to_f32(*self) < 0.0;
+            }
+        }
+
+        impl Float for $half {
+            unary_through_f32!(
+                floor, ceil, round, trunc, fract, abs, signum, recip, sqrt, exp, exp2, ln, log2,
+                log10, cbrt, sin, cos, tan, asin, acos, atan, exp_m1, ln_1p, sinh, cosh, tanh,
+                asinh, acosh, atanh
+            );
+
+            predicate_through_f32!(
+                is_nan,
+                is_infinite,
+                is_finite,
+                is_normal,
+                is_sign_positive,
+                is_sign_negative
+            );
+
+            fn nan() -> Self {
+                return $half::NAN;
+            }
+
+            fn infinity() -> Self {
+                return $half::INFINITY;
+            }
+
+            fn neg_infinity() -> Self {
+                return $half::NEG_INFINITY;
+            }
+
+            fn neg_zero() -> Self {
+                return $half::NEG_ZERO;
+            }
+
+            fn min_value() -> Self {
+                return $half::MIN;
+            }
+
+            fn min_positive_value() -> Self {
+                return $half::MIN_POSITIVE;
+            }
+
+            fn epsilon() -> Self {
+                return $half::EPSILON;
+            }
+
+            fn max_value() -> Self {
+                return $half::MAX;
+            }
+
+            fn classify(self) -> FpCategory {
+                return self.to_f32().classify();
+            }
+
+            fn mul_add(self, a: Self, b: Self) -> Self {
+                return $half::from_f32(self.to_f32().mul_add(a.to_f32(), b.to_f32()));
+            }
+
+            fn powi(self, n: i32) -> Self {
+                return $half::from_f32(self.to_f32().powi(n));
+            }
+
+            fn powf(self, n: Self) -> Self {
+                return $half::from_f32(self.to_f32().powf(n.to_f32()));
+            }
+
+            fn log(self, base: Self) -> Self {
+                return $half::from_f32(self.to_f32().log(base.to_f32()));
+            }
+
+            fn max(self, other: Self) -> Self {
+                return $half::from_f32(self.to_f32().max(other.to_f32()));
+            }
+
+            fn min(self, other: Self) -> Self {
+                return $half::from_f32(self.to_f32().min(other.to_f32()));
+            }
+
+            fn abs_sub(self, other: Self) -> Self {
+                return Signed::abs_sub(&self, &other);
+            }
+
+            fn hypot(self, other: Self) -> Self {
+                return $half::from_f32(self.to_f32().hypot(other.to_f32()));
+            }
+
+            fn atan2(self, other: Self) -> Self {
+                return $half::from_f32(self.to_f32().atan2(other.to_f32()));
+            }
+
+            fn sin_cos(self) -> (Self, Self) {
+                let (sin, cos) = self.to_f32().sin_cos();
+                return ($half::from_f32(sin), $half::from_f32(cos));
+            }
+
+            fn integer_decode(self) -> (u64, i16, i8) {
+                return self.to_f32().integer_decode();
+            }
+        }
+
+        as_primitive_through_f32!($half: f32, f64, i8, i16, i32, i64, u8, u16, u32, u64);
+
+        impl AsPrimitive<$half> for $half {
+            fn as_(self) -> $half {
+                return self;
+            }
+        }
+    };
+}
+
+impl_half_float!(F16);
+impl_half_float!(BF16);
+
+impl AsPrimitive<BF16> for F16 {
+    fn as_(self) -> BF16 {
+        return BF16::from_f32(self.to_f32());
+    }
+}
+
+impl AsPrimitive<F16> for BF16 {
+    fn as_(self) -> F16 {
+        return F16::from_f32(self.to_f32());
+    }
+}
+
+fn accumulate_half_in_f32<T, H, F>(tensor_list: &[&Tensor<T>], f: F) -> Tensor<T>
+where
+    T: DTComp + Debug,
+    H: HalfFloat,
+    F: Fn(&[Tensor<f32>]) -> Tensor<f32>,
+{
+    let widened = tensor_list
+        .iter()
+        .map(|tensor| {
+            let tensor = (*tensor as &dyn Any)
+                .downcast_ref::<Tensor<H>>()
+                .expect("Error: Internal error, half precision tensor has a mismatching dtype");
+
+            return Tensor::from_raw_array(tensor.get_raw_data().mapv(|x| x.to_f32()), false);
+        })
+        .collect::<Vec<Tensor<f32>>>();
+
+    let result_tensor = f(&widened);
+    let narrowed = Tensor::from_raw_array(result_tensor.get_raw_data().mapv(H::from_f32), false);
+
+    return *(Box::new(narrowed) as Box<dyn Any>)
+        .downcast::<Tensor<T>>()
+        .expect("Error: Internal error, half precision tensor has a mismatching dtype");
+}
+
+/// Runs `f` on f32 copies of half precision tensors and rounds the result back, so long sums do
+/// not lose precision or overflow midway. Returns `None` for tensors of any other dtype, which
+/// should be computed directly.
+pub fn accumulate_in_f32<T, F>(tensor_list: &[&Tensor<T>], f: F) -> Option<Tensor<T>>
+where
+    T: DTComp + Debug,
+    F: Fn(&[Tensor<f32>]) -> Tensor<f32>,
+{
+    match T::dtype() {
+        DTypes::Float16 => {
+            return Some(accumulate_half_in_f32::<T, F16, F>(tensor_list, f));
+        }
+        DTypes::BFloat16 => {
+            return Some(accumulate_half_in_f32::<T, BF16, F>(tensor_list, f));
+        }
+        _ => {
+            return None;
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    #[allow(unused)]
+    use super::*;
+
+    #[test]
+    fn half_precision_rounding() {
+        assert_eq!(F16::from_f32(1.0).to_bits(), 0x3c00);
+        assert_eq!(F16::from_f32(65504.0).to_bits(), F16::MAX.to_bits());
+        assert_eq!(F16::from_f32(70000.0).to_bits(), F16::INFINITY.to_bits());
+        assert_eq!(F16::from_f32(-2.0).to_f32(), -2.0);
+        assert_eq!(F16::from_f32(5.960_464_5e-8).to_bits(), 0x0001);
+        assert!(F16::from_f32(f32::NAN).is_nan());
+
+        // 1 + 2^-11 lies halfway between 1 and the next half, and rounds to the even one
+        assert_eq!(F16::from_f32(1.0 + 2f32.powi(-11)).to_bits(), 0x3c00);
+        assert_eq!(F16::from_f32(1.0 + 3.0 * 2f32.powi(-11)).to_bits(), 0x3c02);
+
+        assert_eq!(BF16::from_f32(1.0).to_bits(), 0x3f80);
+        assert!(BF16::from_f32(f32::MAX).is_infinite());
+        assert_eq!(BF16::from_f32(1.0 + 2f32.powi(-8)).to_f32(), 1.0);
+        assert!(BF16::from_f32(f32::NAN).is_nan());
+
+        assert_eq!(
+            F16::from_f32(1.5) + F16::from_f32(2.25),
+            F16::from_f32(3.75)
+        );
+        assert_eq!((-BF16::ONE).to_f32(), -1.0);
+    }
+
+    #[test]
+    fn half_precision_accumulates_in_f32() {
+        use crate::ops::public_ops::matmul::matmul;
+        use ndarray::Axis;
+
+        // summing one at a time in half precision would get stuck at 2048
+        let ones = Tensor::new(vec![1.0f32; 4096], vec![4096], false).to::<F16>();
+        assert_eq!(ones.get_type(), DTypes::Float16);
+        assert_eq!(ones.get_nbytes(), 4096 * 2);
+
+        let total = ones.sum(Axis(0));
+        assert_eq!(
+            total.get_raw_data().iter().copied().collect::<Vec<_>>(),
+            vec![F16::from_f32(4096.0)]
+        );
+
+        let x1 = Tensor::new(vec![1.0f32; 4096], vec![1, 4096], false).to::<BF16>();
+        let x2 = Tensor::new(vec![1.0f32; 4096], vec![4096, 1], false).to::<BF16>();
+        let product = matmul(&x1, &x2);
+        assert_eq!(
+            product.get_raw_data().iter().copied().collect::<Vec<_>>(),
+            vec![BF16::from_f32(4096.0)]
+        );
+    }
+}
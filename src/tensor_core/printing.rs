@@ -0,0 +1,433 @@
+use crate::tensor_core::{
+    dtypes::DTComp,
+    half::{BF16, F16},
+};
+use ndarray::{ArrayViewD, Axis};
+use num_complex::Complex;
+
+use std::{any::Any, fmt::Debug, sync::RwLock};
+
+static PRINT_OPTIONS: RwLock<PrintOptions> = RwLock::new(PrintOptions::new());
+
+/// Options for how `Display` lays out the data of a tensor
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrintOptions {
+    precision: usize,
+    threshold: usize,
+    edge_items: usize,
+    line_width: usize,
+    sci_mode: Option<bool>,
+}
+
+impl Default for PrintOptions {
+    fn default() -> Self {
+        return PrintOptions::new();
+    }
+}
+
+impl PrintOptions {
+    pub const fn new() -> Self {
+        return PrintOptions {
+            precision: 4,
+            threshold: 1000,
+            edge_items: 3,
+            line_width: 80,
+            sci_mode: None,
+        };
+    }
+
+    /// Number of digits after the decimal point of floating point elements
+    pub fn with_precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        return self;
+    }
+
+    /// Tensors with more elements than this are summarized with `...`
+    pub fn with_threshold(mut self, threshold: usize) -> Self {
+        self.threshold = threshold;
+        return self;
+    }
+
+    /// Number of elements kept at the start and at the end of every summarized axis
+    pub fn with_edge_items(mut self, edge_items: usize) -> Self {
+        self.edge_items = edge_items;
+        return self;
+    }
+
+    /// Number of characters after which a row is wrapped
+    pub fn with_line_width(mut self, line_width: usize) -> Self {
+        self.line_width = line_width;
+        return self;
+    }
+
+    /// Forces scientific notation on or off. `None` picks it from the magnitude of the elements.
+    pub fn with_sci_mode(mut self, sci_mode: Option<bool>) -> Self {
+        self.sci_mode = sci_mode;
+        return self;
+    }
+
+    pub fn get_precision(&self) -> usize {
+        return self.precision;
+    }
+
+    pub fn get_threshold(&self) -> usize {
+        return self.threshold;
+    }
+
+    pub fn get_edge_items(&self) -> usize {
+        return self.edge_items;
+    }
+
+    pub fn get_line_width(&self) -> usize {
+        return self.line_width;
+    }
+
+    pub fn get_sci_mode(&self) -> Option<bool> {
+        return self.sci_mode;
+    }
+}
+
+/// Sets the options used by every `Display` of a tensor, on every thread
+pub fn set_print_options(options: PrintOptions) {
+    *PRINT_OPTIONS
+        .write()
+        .expect("Error: Print options lock is poisoned") = options;
+}
+
+pub fn get_print_options() -> PrintOptions {
+    return PRINT_OPTIONS
+        .read()
+        .expect("Error: Print options lock is poisoned")
+        .clone();
+}
+
+pub fn reset_print_options() {
+    set_print_options(PrintOptions::new());
+}
+
+enum Element {
+    Float(f64),
+    Complex(f64, f64),
+    Other(String),
+}
+
+fn to_element<T>(value: &T) -> Element
+where
+    T: DTComp + Debug,
+{
+    let value_any = value as &dyn Any;
+
+    if let Some(value) = value_any.downcast_ref::<f32>() {
+        return Element::Float(*value as f64);
+    }
+    if let Some(value) = value_any.downcast_ref::<f64>() {
+        return Element::Float(*value);
+    }
+    if let Some(value) = value_any.downcast_ref::<F16>() {
+        return Element::Float(f32::from(*value) as f64);
+    }
+    if let Some(value) = value_any.downcast_ref::<BF16>() {
+        return Element::Float(f32::from(*value) as f64);
+    }
+    if let Some(value) = value_any.downcast_ref::<Complex<f32>>() {
+        return Element::Complex(value.re as f64, value.im as f64);
+    }
+    if let Some(value) = value_any.downcast_ref::<Complex<f64>>() {
+        return Element::Complex(value.re, value.im);
+    }
+
+    // integers and booleans print the same with Debug
+    return Element::Other(format!("{:?}", value));
+}
+
+/// How every floating point element of one tensor is printed, so they line up
+struct FloatFormat {
+    precision: usize,
+    sci_mode: bool,
+    int_mode: bool,
+}
+
+impl FloatFormat {
+    fn from_values(value_list: &[f64], options: &PrintOptions) -> Self {
+        let finite_list: Vec<f64> = value_list
+            .iter()
+            .copied()
+            .filter(|value| value.is_finite())
+            .collect();
+        let int_mode = finite_list.iter().all(|value| value.fract() == 0.0);
+
+        let nonzero_abs_list: Vec<f64> = finite_list
+            .iter()
+            .map(|value| value.abs())
+            .filter(|value| *value != 0.0)
+            .collect();
+        let max_abs = nonzero_abs_list.iter().copied().fold(0.0, f64::max);
+        let min_abs = nonzero_abs_list
+            .iter()
+            .copied()
+            .fold(f64::INFINITY, f64::min);
+
+        // same switch as PyTorch: large spreads or magnitudes are unreadable in fixed point
+        let auto_sci_mode = if nonzero_abs_list.is_empty() {
+            false
+        } else if int_mode {
+            max_abs > 1e8
+        } else {
+            max_abs / min_abs > 1e3 || max_abs > 1e8 || min_abs < 1e-4
+        };
+
+        return FloatFormat {
+            precision: options.precision,
+            sci_mode: options.sci_mode.unwrap_or(auto_sci_mode),
+            int_mode,
+        };
+    }
+
+    fn format(&self, value: f64) -> String {
+        if value.is_nan() {
+            return String::from("nan");
+        }
+        if value.is_infinite() {
+            return String::from(if value > 0.0 { "inf" } else { "-inf" });
+        }
+
+        if self.sci_mode {
+            // Rust prints `1e-5` and `1e0`, pad the exponent so elements line up like `1e-05`
+            let formatted = format!("{:.*e}", self.precision, value);
+            let (mantissa, exponent) = formatted.split_once('e').unwrap();
+            let exponent: i32 = exponent.parse().unwrap();
+            let sign = if exponent < 0 { '-' } else { '+' };
+
+            return format!("{}e{}{:02}", mantissa, sign, exponent.abs());
+        }
+        if self.int_mode {
+            return format!("{:.0}.", value);
+        }
+
+        return format!("{:.*}", self.precision, value);
+    }
+}
+
+struct Layout {
+    summarize: bool,
+    edge_items: usize,
+    line_width: usize,
+    element_width: usize,
+    float_format: FloatFormat,
+}
+
+impl Layout {
+    fn format_element(&self, element: &Element) -> String {
+        return match element {
+            Element::Float(value) => self.float_format.format(*value),
+            Element::Complex(re, im) => {
+                let sign = if im.is_sign_negative() { "-" } else { "+" };
+                format!(
+                    "{}{}{}j",
+                    self.float_format.format(*re),
+                    sign,
+                    self.float_format.format(im.abs())
+                )
+            }
+            Element::Other(value) => value.clone(),
+        };
+    }
+
+    /// Indices along an axis of length `len`, with `None` standing for the summarized middle
+    fn visible_indices(&self, len: usize) -> Vec<Option<usize>> {
+        if self.summarize && len > 2 * self.edge_items {
+            let mut index_list: Vec<Option<usize>> = (0..self.edge_items).map(Some).collect();
+            index_list.push(None);
+            index_list.extend((len - self.edge_items..len).map(Some));
+
+            return index_list;
+        }
+
+        return (0..len).map(Some).collect();
+    }
+
+    fn collect_visible<T>(&self, view: ArrayViewD<T>, element_list: &mut Vec<Element>)
+    where
+        T: DTComp + Debug,
+    {
+        if view.ndim() == 0 {
+            element_list.push(to_element(view.first().unwrap()));
+            return;
+        }
+
+        for index in self
+            .visible_indices(view.len_of(Axis(0)))
+            .into_iter()
+            .flatten()
+        {
+            self.collect_visible(view.index_axis(Axis(0), index), element_list);
+        }
+    }
+
+    /// `indent` is the column the opening bracket of `view` is printed at
+    fn layout<T>(&self, view: ArrayViewD<T>, indent: usize) -> String
+    where
+        T: DTComp + Debug,
+    {
+        if view.ndim() == 0 {
+            return self.format_element(&to_element(view.first().unwrap()));
+        }
+
+        let index_list = self.visible_indices(view.len_of(Axis(0)));
+
+        if view.ndim() == 1 {
+            let mut output = String::from("[");
+            let mut column = indent + 1;
+
+            for (position, index) in index_list.iter().enumerate() {
+                let item = match index {
+                    Some(index) => format!(
+                        "{:>width$}",
+                        self.format_element(&to_element(&view[[*index]])),
+                        width = self.element_width
+                    ),
+                    None => String::from("..."),
+                };
+                let item_width = item.chars().count();
+
+                if position > 0 {
+                    // keep room for the separator and the closing bracket
+                    if column + 2 + item_width + 1 > self.line_width {
+                        output.push_str(",\n");
+                        output.push_str(&" ".repeat(indent + 1));
+                        column = indent + 1;
+                    } else {
+                        output.push_str(", ");
+                        column += 2;
+                    }
+                }
+
+                output.push_str(&item);
+                column += item_width;
+            }
+
+            output.push(']');
+            return output;
+        }
+
+        // blank lines between blocks grow with the number of dimensions, like NumPy
+        let separator = format!(
+            ",{}{}",
+            "\n".repeat(view.ndim() - 1),
+            " ".repeat(indent + 1)
+        );
+
+        let row_list: Vec<String> = index_list
+            .iter()
+            .map(|index| match index {
+                Some(index) => self.layout(view.index_axis(Axis(0), *index), indent + 1),
+                None => String::from("..."),
+            })
+            .collect();
+
+        return format!("[{}]", row_list.join(&separator));
+    }
+}
+
+/// Lays out `data` following `options`. `indent` is the column the data starts at, which lines
+/// after the first are aligned to.
+pub fn format_array<T>(data: ArrayViewD<T>, options: &PrintOptions, indent: usize) -> String
+where
+    T: DTComp + Debug,
+{
+    let mut layout = Layout {
+        summarize: data.len() > options.threshold,
+        edge_items: options.edge_items,
+        line_width: options.line_width,
+        element_width: 0,
+        float_format: FloatFormat::from_values(&[], options),
+    };
+
+    let mut element_list = Vec::new();
+    layout.collect_visible(data.view(), &mut element_list);
+
+    let float_list: Vec<f64> = element_list
+        .iter()
+        .flat_map(|element| match element {
+            Element::Float(value) => vec![*value],
+            Element::Complex(re, im) => vec![*re, *im],
+            Element::Other(_) => vec![],
+        })
+        .collect();
+    layout.float_format = FloatFormat::from_values(&float_list, options);
+
+    layout.element_width = element_list
+        .iter()
+        .map(|element| layout.format_element(element).chars().count())
+        .max()
+        .unwrap_or(0);
+
+    return layout.layout(data, indent);
+}
+
+#[cfg(test)]
+pub mod test {
+    #[allow(unused)]
+    use super::*;
+
+    use crate::tensor_core::tensor::Tensor;
+
+    fn format_tensor<T>(tensor: &Tensor<T>, options: &PrintOptions) -> String
+    where
+        T: DTComp + Debug + Clone,
+    {
+        return format_array(tensor.get_raw_data().view(), options, 0);
+    }
+
+    #[test]
+    fn print_options_control_layout() {
+        let options = PrintOptions::new();
+
+        let matrix = Tensor::new(vec![1.5, -2.0, 3.25, 10.0], vec![2, 2], false);
+        assert_eq!(
+            format_tensor(&matrix, &options),
+            "[[ 1.5000, -2.0000],\n [ 3.2500, 10.0000]]"
+        );
+        assert_eq!(
+            format_tensor(&matrix, &options.clone().with_precision(1)),
+            "[[ 1.5, -2.0],\n [ 3.2, 10.0]]"
+        );
+
+        let whole = Tensor::new(vec![1.0, 2.0, 30.0], vec![3], false);
+        assert_eq!(format_tensor(&whole, &options), "[ 1.,  2., 30.]");
+
+        let spread = Tensor::new(vec![1e-5, 1.0], vec![2], false);
+        assert_eq!(
+            format_tensor(&spread, &options.clone().with_precision(2)),
+            "[1.00e-05, 1.00e+00]"
+        );
+        assert_eq!(
+            format_tensor(&spread, &options.clone().with_sci_mode(Some(false))),
+            "[0.0000, 1.0000]"
+        );
+
+        let long = Tensor::<i64>::arange(0, 20, 1, None);
+        let summarized = options.clone().with_threshold(10).with_edge_items(2);
+        assert_eq!(format_tensor(&long, &summarized), "[ 0,  1, ..., 18, 19]");
+        assert_eq!(
+            format_tensor(&long, &options.clone().with_line_width(20)),
+            "[ 0,  1,  2,  3,  4,\n  5,  6,  7,  8,  9,\n 10, 11, 12, 13, 14,\n 15, 16, 17, 18, 19]"
+        );
+
+        let cube = Tensor::<i32>::arange(0, 8, 1, None).reshape(vec![2, 2, 2]);
+        assert_eq!(
+            format_tensor(&cube, &options),
+            "[[[0, 1],\n  [2, 3]],\n\n [[4, 5],\n  [6, 7]]]"
+        );
+
+        let complex = Tensor::new(vec![Complex::new(1.0, -2.0)], vec![1], false);
+        assert_eq!(format_tensor(&complex, &options), "[1.-2.j]");
+
+        let tracked = Tensor::new(vec![1.0, 2.0], vec![2], true);
+        let doubled = (&tracked * 2.0).with_name("doubled");
+        assert_eq!(
+            format!("{}", doubled),
+            "tensor(name=doubled, shape=[2], dtype=float64, requires_grad=true, grad_fn=MulBackward, data=\n  [2., 4.])"
+        );
+    }
+}
@@ -0,0 +1,218 @@
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+// Every thread draws from a generator of its own. The thread calling `manual_seed` gets a
+// generator seeded with exactly that seed, and any other thread seeds its generator from the
+// global seed the first time it draws after a reseed. The order in which those threads first draw
+// is up to the scheduler, so for reproducible multi threaded runs, fork a generator for every
+// thread up front with `fork_rng` and install it with `set_generator`.
+
+/// Seed in use until `manual_seed` is called
+pub const DEFAULT_SEED: u64 = 42;
+
+static GLOBAL_SEED: AtomicU64 = AtomicU64::new(DEFAULT_SEED);
+
+/// Bumped on every reseed, so threads notice their generator is stale
+static SEED_EPOCH: AtomicU64 = AtomicU64::new(0);
+
+/// Number of threads seeded from the global seed since the last reseed
+static THREAD_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+thread_local! {
+    static THREAD_GENERATOR: RefCell<Option<(u64, Generator)>> = const { RefCell::new(None) };
+}
+
+/// Seedable random number generator
+#[derive(Debug, Clone)]
+pub struct Generator {
+    seed: u64,
+    rng: StdRng,
+}
+
+impl Generator {
+    pub fn new(seed: u64) -> Self {
+        return Generator {
+            seed,
+            rng: StdRng::seed_from_u64(seed),
+        };
+    }
+
+    pub fn get_seed(&self) -> u64 {
+        return self.seed;
+    }
+
+    /// Splits off an independent generator. The child is seeded from this generator's stream, so
+    /// forking is as reproducible as drawing.
+    pub fn fork(&mut self) -> Generator {
+        return Generator::new(splitmix64(self.rng.next_u64()));
+    }
+}
+
+impl RngCore for Generator {
+    fn next_u32(&mut self) -> u32 {
+        return self.rng.next_u32();
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        return self.rng.next_u64();
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.fill_bytes(dest);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        return self.rng.try_fill_bytes(dest);
+    }
+}
+
+/// Scrambles a seed, so nearby seeds give unrelated streams
+fn splitmix64(x: u64) -> u64 {
+    let mut z = x.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+
+    return z ^ (z >> 31);
+}
+
+/// Reseeds the global generator. The current thread draws from `seed` right away, and every other
+/// thread reseeds from it on its next draw.
+pub fn manual_seed(seed: u64) {
+    GLOBAL_SEED.store(seed, Ordering::SeqCst);
+    THREAD_COUNTER.store(0, Ordering::SeqCst);
+    let epoch = SEED_EPOCH.fetch_add(1, Ordering::SeqCst) + 1;
+
+    THREAD_GENERATOR.with(|generator| {
+        *generator.borrow_mut() = Some((epoch, Generator::new(seed)));
+    });
+}
+
+/// The seed last passed to `manual_seed`, or `DEFAULT_SEED`
+pub fn initial_seed() -> u64 {
+    return GLOBAL_SEED.load(Ordering::SeqCst);
+}
+
+/// Replaces the generator of the current thread until the next `manual_seed`
+pub fn set_generator(generator: Generator) {
+    let epoch = SEED_EPOCH.load(Ordering::SeqCst);
+
+    THREAD_GENERATOR.with(|thread_generator| {
+        *thread_generator.borrow_mut() = Some((epoch, generator));
+    });
+}
+
+/// Forks the generator of the current thread, usually to hand to a spawned thread
+pub fn fork_rng() -> Generator {
+    return with_generator(|generator| generator.fork());
+}
+
+/// Runs `f` with the generator of the current thread, seeding it first if it is stale
+pub fn with_generator<R, F>(f: F) -> R
+where
+    F: FnOnce(&mut Generator) -> R,
+{
+    return THREAD_GENERATOR.with(|thread_generator| {
+        let mut thread_generator = thread_generator.borrow_mut();
+        let epoch = SEED_EPOCH.load(Ordering::SeqCst);
+
+        let is_stale = match thread_generator.as_ref() {
+            Some((generator_epoch, _)) => *generator_epoch != epoch,
+            None => true,
+        };
+
+        if is_stale {
+            let thread_index = THREAD_COUNTER.fetch_add(1, Ordering::SeqCst);
+            let seed = splitmix64(initial_seed() ^ splitmix64(thread_index));
+
+            *thread_generator = Some((epoch, Generator::new(seed)));
+        }
+
+        let (_, generator) = thread_generator
+            .as_mut()
+            .expect("Error: Internal error, thread generator was not seeded");
+
+        return f(generator);
+    });
+}
+
+#[cfg(test)]
+pub mod test {
+    #[allow(unused)]
+    use super::*;
+
+    use crate::tensor_core::tensor::Tensor;
+    use crate::utils::testing_utils::raw_data_to_vec;
+
+    #[test]
+    fn seeded_generators_are_reproducible() {
+        manual_seed(7);
+        let first = Tensor::<f32>::randn(&vec![2, 3], None);
+        let mut forked = fork_rng();
+        let after_fork = Tensor::<f32>::rand(&vec![4], None);
+
+        manual_seed(7);
+        assert_eq!(initial_seed(), 7);
+        assert_eq!(
+            raw_data_to_vec(&first),
+            raw_data_to_vec(&Tensor::<f32>::randn(&vec![2, 3], None))
+        );
+        assert_eq!(forked.next_u64(), fork_rng().next_u64());
+        assert_eq!(
+            raw_data_to_vec(&after_fork),
+            raw_data_to_vec(&Tensor::<f32>::rand(&vec![4], None))
+        );
+
+        // A forked generator behaves the same on another thread
+        let mut generator = fork_rng();
+        let handed_over = generator.clone();
+        let expected = generator.next_u64();
+        let drawn = std::thread::spawn(move || {
+            set_generator(handed_over);
+            return with_generator(|generator| generator.next_u64());
+        })
+        .join()
+        .unwrap();
+        assert_eq!(drawn, expected);
+
+        let uniform = Tensor::<f64>::uniform(&vec![100], -2.0, 3.0, Some(true));
+        assert!(uniform.does_require_grad());
+        assert!(
+            uniform
+                .get_raw_data()
+                .iter()
+                .all(|x| (-2.0..3.0).contains(x))
+        );
+
+        let randint = Tensor::<i32>::randint(&vec![100], 3, 6, None);
+        assert!(randint.get_raw_data().iter().all(|x| (3..6).contains(x)));
+
+        let bernoulli = Tensor::<f32>::bernoulli(&vec![100], 1.0, None);
+        assert!(bernoulli.get_raw_data().iter().all(|x| *x == 1.0));
+
+        let mut permutation: Vec<i64> = Tensor::<i64>::randperm(10, None)
+            .get_raw_data()
+            .iter()
+            .copied()
+            .collect();
+        permutation.sort();
+        assert_eq!(permutation, (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn half_precision_uniform_stays_below_high() {
+        use crate::tensor_core::half::{BF16, F16};
+
+        let bf16 = Tensor::<BF16>::rand(&vec![100_000], None);
+        assert!(bf16.get_raw_data().iter().all(|x| x.to_f32() < 1.0));
+
+        let f16 = Tensor::<F16>::rand(&vec![100_000], None);
+        assert!(f16.get_raw_data().iter().all(|x| x.to_f32() < 1.0));
+
+        let high = F16::from_f32(1.5);
+        let narrow = Tensor::<F16>::uniform(&vec![1000], F16::from_f32(1.0), high, None);
+        assert!(narrow.get_raw_data().iter().all(|x| *x < high));
+    }
+}
@@ -0,0 +1,106 @@
+use ndarray::iter::Iter;
+use ndarray::{ArcArray, ArrayD, ArrayView, IxDyn};
+
+use crate::tensor_core::dtypes::DTComp;
+use crate::tensor_core::tensor_impl::TensorImpl;
+
+use std::cell::{OnceCell, Ref};
+use std::fmt::Debug;
+use std::ops::Deref;
+
+/// Data of a tensor, borrowed from its storage for as long as the guard lives. The methods below
+/// read the data in place, broadcast dimensions included. Anything else goes through `Deref` to an
+/// `ArcArray`, which for a broadcast tensor is a copy made for this guard only and dropped with it.
+pub struct RawData<'a, T>
+where
+    T: DTComp + Debug,
+{
+    tensor_impl: Ref<'a, TensorImpl<T>>,
+    materialized: OnceCell<ArcArray<T, IxDyn>>,
+}
+
+impl<'a, T> RawData<'a, T>
+where
+    T: DTComp + Debug + Clone,
+{
+    pub fn new(tensor_impl: Ref<'a, TensorImpl<T>>) -> Self {
+        return RawData {
+            tensor_impl,
+            materialized: OnceCell::new(),
+        };
+    }
+
+    /// Strided view of the data, without copying
+    pub fn view(&self) -> ArrayView<'_, T, IxDyn> {
+        return self.tensor_impl.get_raw_data_();
+    }
+
+    pub fn iter(&self) -> Iter<'_, T, IxDyn> {
+        return self.view().into_iter();
+    }
+
+    pub fn shape(&self) -> &[usize] {
+        return &self.tensor_impl.shape;
+    }
+
+    pub fn len(&self) -> usize {
+        return self.tensor_impl.numel;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.len() == 0;
+    }
+
+    /// Contiguous slice of the data, `None` for views that are not in row major order
+    pub fn as_slice(&self) -> Option<&[T]> {
+        return self.view().to_slice();
+    }
+
+    pub fn as_ptr(&self) -> *const T {
+        return self.view().as_ptr();
+    }
+
+    pub fn to_owned(&self) -> ArrayD<T> {
+        return self.view().to_owned();
+    }
+
+    pub fn mapv<U, F>(&self, f: F) -> ArrayD<U>
+    where
+        F: FnMut(T) -> U,
+    {
+        return self.view().mapv(f);
+    }
+
+    pub fn map<U, F>(&self, f: F) -> ArrayD<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        return self.view().map(f);
+    }
+}
+
+impl<T> Deref for RawData<'_, T>
+where
+    T: DTComp + Debug + Clone,
+{
+    type Target = ArcArray<T, IxDyn>;
+
+    fn deref(&self) -> &Self::Target {
+        if !self.tensor_impl.is_broadcast_() {
+            return self.tensor_impl.get_storage_().get_data();
+        }
+
+        return self
+            .materialized
+            .get_or_init(|| self.view().to_owned().into_shared());
+    }
+}
+
+impl<T> Debug for RawData<'_, T>
+where
+    T: DTComp + Debug + Clone,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        return Debug::fmt(&self.view(), f);
+    }
+}
@@ -1,14 +1,21 @@
+use super::buffer_pool;
 use super::dtypes::{self, DTComp, DTypes};
-use ndarray::{ArrayBase, Ix1, Ix2, IxDyn, OwnedRepr};
+use crate::profiler::memory_profiler;
+use ndarray::{ArcArray, ArrayBase, IxDyn, OwnedRepr};
 
+/// A view into a reference counted buffer. Every storage holds its own layout (offset, shape and
+/// strides) of the buffer, so tensors created by view operations share the buffer without copying.
 #[derive(Debug)]
 pub struct Storage<T>
 where
     T: DTComp,
 {
-    data: ArrayBase<OwnedRepr<T>, IxDyn>,
+    data: ArcArray<T, IxDyn>,
+    allocation_id: usize,
     nbytes: usize,
     dtype: dtypes::DTypes,
+    meta: bool,
+    view: bool,
 }
 
 impl<T> Storage<T>
@@ -17,16 +24,104 @@ where
 {
     pub fn new(x: ArrayBase<OwnedRepr<T>, IxDyn>, nbytes: usize, dtype: dtypes::DTypes) -> Self {
         let storage = Storage {
-            data: x,
+            allocation_id: x.as_ptr() as usize,
+            data: ArcArray::from(x),
             nbytes: nbytes,
             dtype: dtype,
+            meta: false,
+            view: false,
+        };
+
+        memory_profiler::record_alloc(storage.get_allocation_id(), storage.nbytes);
+
+        return storage;
+    }
+
+    /// Storage of a meta tensor. It keeps the size the data would take up, but never allocates
+    /// it, and any access to the data panics.
+    pub fn new_meta(nbytes: usize, dtype: dtypes::DTypes) -> Self {
+        let storage = Storage {
+            data: ArcArray::from_shape_vec(IxDyn(&[0]), vec![]).unwrap(),
+            allocation_id: 0,
+            nbytes: nbytes,
+            dtype: dtype,
+            meta: true,
+            view: false,
         };
 
         return storage;
     }
 
-    pub fn get_data(&self) -> &ArrayBase<OwnedRepr<T>, IxDyn> {
-        return &(self.data);
+    /// Another storage over the same buffer. The buffer is freed once every storage sharing it has
+    /// been dropped.
+    pub fn share(&self) -> Self {
+        return self.share_as(|data| data);
+    }
+
+    /// Another storage over the same buffer, with its layout changed by `layout`. `layout` may only
+    /// move the view within the buffer (permute, slice, insert or remove axes, reshape in place),
+    /// never copy it.
+    pub fn share_as<F>(&self, layout: F) -> Self
+    where
+        F: FnOnce(ArcArray<T, IxDyn>) -> ArcArray<T, IxDyn>,
+    {
+        let data = layout(self.data.clone());
+
+        let storage = Storage {
+            data: data,
+            allocation_id: self.allocation_id,
+            nbytes: self.nbytes,
+            dtype: self.dtype,
+            meta: self.meta,
+            view: true,
+        };
+
+        return storage;
+    }
+
+    pub fn get_data(&self) -> &ArcArray<T, IxDyn> {
+        if self.meta {
+            panic!("Error: Cannot access the data of a meta tensor, it only carries a shape.");
+        }
+
+        return &self.data;
+    }
+
+    /// Mutable access to the buffer, only available while no other storage shares it
+    pub fn get_data_mut(&mut self) -> Option<&mut ArcArray<T, IxDyn>> {
+        if self.meta {
+            panic!("Error: Cannot access the data of a meta tensor, it only carries a shape.");
+        }
+
+        if !self.data.is_unique() {
+            return None;
+        }
+
+        return Some(&mut self.data);
+    }
+
+    pub fn is_meta(&self) -> bool {
+        return self.meta;
+    }
+
+    /// Whether this storage was created as a view of another storage's buffer
+    pub fn is_view(&self) -> bool {
+        return self.view;
+    }
+
+    /// Number of elements between the start of the buffer and the first element of this view
+    pub fn get_offset(&self) -> usize {
+        if self.meta {
+            return 0;
+        }
+
+        return (self.data.as_ptr() as usize - self.allocation_id)
+            / std::mem::size_of::<T>().max(1);
+    }
+
+    /// Strides of this view, in number of elements
+    pub fn get_strides(&self) -> Vec<isize> {
+        return self.data.strides().to_vec();
     }
 
     pub fn get_nbytes(&self) -> usize {
@@ -40,28 +135,35 @@ where
     pub fn get_raw_shape(&self) -> Vec<usize> {
         return self.data.shape().to_vec();
     }
+
+    /// Address of the underlying buffer, which identifies it for as long as it lives. Views of the
+    /// same buffer share the allocation id.
+    pub fn get_allocation_id(&self) -> usize {
+        return self.allocation_id;
+    }
 }
 
-impl<T> Storage<T>
+impl<T> Drop for Storage<T>
 where
-    T: DTComp + Clone,
+    T: DTComp,
 {
-    pub fn get_data_as_ix2(&self) -> ArrayBase<OwnedRepr<T>, Ix2> {
-        let fixed_shape = self
-            .data
-            .clone()
-            .into_dimensionality::<Ix2>()
-            .expect("Error: Attempting to cast tensor as as 2D tensor failed");
-        return fixed_shape;
-    }
+    fn drop(&mut self) {
+        if self.meta {
+            return;
+        }
+
+        let allocation_id = self.get_allocation_id();
+        let data = std::mem::replace(
+            &mut self.data,
+            ArcArray::from_shape_vec(IxDyn(&[0]), vec![]).unwrap(),
+        );
 
-    pub fn get_data_as_ix1(&self) -> ArrayBase<OwnedRepr<T>, Ix1> {
-        let fixed_shape = self
-            .data
-            .clone()
-            .into_dimensionality::<Ix1>()
-            .expect("Error: Attempting to cast tensor as as 1D tensor failed");
+        // the buffer is still alive while another storage shares it
+        if let Ok(data) = data.try_into_owned_nocopy() {
+            memory_profiler::record_free(allocation_id);
 
-        return fixed_shape;
+            let (buffer, _) = data.into_raw_vec_and_offset();
+            buffer_pool::release(buffer);
+        }
     }
 }
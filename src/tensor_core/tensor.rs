@@ -3,11 +3,14 @@ use crate::graph::backward::grad_accum::GradAccum;
 
 use super::autograd_meta::AutogradMeta;
 use super::dtypes::{DTComp, DTypes};
+use super::printing;
+use super::raw_data::RawData;
 use super::storage::Storage;
 use super::tensor_impl::TensorImpl;
 
 use ndarray::{ArrayBase, IxDyn, OwnedRepr};
 use ndarray::{Ix1, Ix2};
+use num_traits::One;
 use std::cell::{Ref, RefCell};
 use std::fmt::{Debug, Display};
 use std::ops::Add;
@@ -33,12 +36,47 @@ where
         return Rc::clone(self.__get_tensor_impl());
     }
 
-    pub fn get_raw_data(&self) -> Ref<ArrayBase<OwnedRepr<T>, IxDyn>> {
-        return Ref::map(self.__get_tensor_impl().borrow(), |tensor_impl| {
-            tensor_impl.get_storage_().get_data()
-        });
+    /// Creates a meta tensor, which carries a shape and dtype but no data. Ops on meta tensors
+    /// only run their shape inference and build the graph as usual, which makes them useful to
+    /// validate a model and print its shapes without allocating anything.
+    #[track_caller]
+    pub fn meta(shape: Vec<usize>, requires_grad: bool) -> Self {
+        let tensor_impl = TensorImpl::new_meta(shape);
+
+        let tensor = Tensor {
+            tensor_impl: TensorImpl::generate_pointer_for_tensor(tensor_impl),
+        };
+
+        if requires_grad {
+            tensor.requires_grad();
+        }
+
+        return tensor;
+    }
+
+    pub fn is_meta(&self) -> bool {
+        return self.__get_tensor_impl().borrow().is_meta_();
+    }
+
+    pub fn from_tensor_impl(tensor_impl: TensorImpl<T>) -> Self {
+        return Tensor {
+            tensor_impl: TensorImpl::generate_pointer_for_tensor(tensor_impl),
+        };
+    }
+
+    /// Returns a tensor sharing the same data but cut off from the graph. The data stays alive as
+    /// long as either tensor does.
+    pub fn detach(&self) -> Self {
+        let tensor_impl = self.__get_tensor_impl().borrow().detach_();
+
+        let tensor = Tensor {
+            tensor_impl: TensorImpl::generate_pointer_for_tensor(tensor_impl),
+        };
+
+        return tensor;
     }
 
+    #[track_caller]
     pub fn new(x: Vec<T>, shape: Vec<usize>, requires_grad: bool) -> Self {
         let tensor_impl = TensorImpl::new(x, shape);
 
@@ -48,7 +86,7 @@ where
 
         if requires_grad {
             let autograd_meta = AutogradMeta::<T>::new_for_leaf(
-                String::from("leaf_grad_meta"),
+                String::from("leaf"),
                 tensor.__clone_ptr_to_tensor_impl(),
             );
 
@@ -70,12 +108,23 @@ where
         });
     }
 
-    pub fn get_strides(&self) -> Ref<Vec<usize>> {
+    /// Strides of the tensor in number of elements. Broadcast dimensions have a stride of 0.
+    pub fn get_strides(&self) -> Ref<Vec<isize>> {
         return Ref::map(self.__get_tensor_impl().borrow(), |tensor_impl| {
             &(tensor_impl.strides)
         });
     }
 
+    /// Number of elements between the start of the storage buffer and the first element of the
+    /// tensor, which is only non zero for views
+    pub fn get_offset(&self) -> usize {
+        return self.__get_tensor_impl().borrow().offset;
+    }
+
+    pub fn is_contiguous(&self) -> bool {
+        return self.__get_tensor_impl().borrow().is_contiguous_();
+    }
+
     pub fn get_numel(&self) -> usize {
         return self.__get_tensor_impl().borrow().numel;
     }
@@ -92,6 +141,23 @@ where
         return self.get_storage().get_dtype();
     }
 
+    /// Gives the tensor a name, which is shown by the visualizer, graph exports, error messages
+    /// and `Display`
+    pub fn with_name(self, name: &str) -> Self {
+        self.set_name(name);
+        return self;
+    }
+
+    pub fn set_name(&self, name: &str) {
+        self.__get_tensor_impl().borrow_mut().name = Some(String::from(name));
+    }
+
+    /// Name given with `with_name` or `set_name`. Tensors tracking gradients without a given name
+    /// fall back to a default name, "leaf" for leaves or the name of the op that created them.
+    pub fn get_name(&self) -> Option<String> {
+        return self.__get_tensor_impl().borrow().get_name_();
+    }
+
     pub fn get_autograd_ref(&self) -> Ref<Option<AutogradMeta<T>>> {
         return Ref::map(self.__get_tensor_impl().borrow(), |tensor_impl_| {
             &tensor_impl_.autograd_meta
@@ -104,23 +170,6 @@ where
 
     // DISPLAY FUNCTIONS
 
-    pub fn display_grad(&self) {
-        let borrowed_impl = self.tensor_impl.borrow();
-        match &borrowed_impl.autograd_meta {
-            Some(meta) => match &meta.grad.borrow().as_ref() {
-                Some(grad) => {
-                    println!("Grad: {}", grad);
-                }
-                None => {
-                    println!("Grad has not been computed or is None.");
-                }
-            },
-            None => {
-                println!("Tensor has no autograd metadata.");
-            }
-        }
-    }
-
     pub fn display_autograd_meta(&self) {
         let borrowed_impl = self.tensor_impl.borrow();
         match &borrowed_impl.autograd_meta {
@@ -181,13 +230,89 @@ where
 
     /// Turns on grad tracking for a leaf tensor. Any intermediate tensor will always be
     /// created with gradient, so this method does not apply for them.
+    #[track_caller]
     pub fn requires_grad(&self) {
-        let autograd_meta = AutogradMeta::<T>::new_for_leaf(
-            String::from("leaf_grad_meta"),
-            self.__clone_ptr_to_tensor_impl(),
-        );
+        self.set_requires_grad(true);
+    }
 
-        self.set_autograd_meta(autograd_meta);
+    /// Turns grad tracking on or off for a leaf tensor. Turning it on keeps any existing metadata
+    /// and gradient, while turning it off drops both, so the tensor is frozen for every graph
+    /// built afterwards.
+    #[track_caller]
+    pub fn set_requires_grad(&self, requires_grad: bool) {
+        if requires_grad {
+            if !self.does_require_grad() {
+                let autograd_meta = AutogradMeta::<T>::new_for_leaf(
+                    String::from("leaf"),
+                    self.__clone_ptr_to_tensor_impl(),
+                );
+
+                self.set_autograd_meta(autograd_meta);
+            }
+
+            return;
+        }
+
+        if self.does_require_grad() && !self.is_leaf() {
+            panic!(
+                "Error: Cannot turn off gradient tracking on intermediate tensor{}. Use `detach()` to get a tensor cut off from the graph instead.",
+                self.__get_tensor_impl().borrow().describe_name_()
+            );
+        }
+
+        self.__get_tensor_impl().borrow_mut().autograd_meta = None;
+    }
+
+    /// Returns the gradient of this tensor, or `None` if it has not been computed or the tensor
+    /// does not require gradient tracking
+    pub fn grad(&self) -> Option<Tensor<T>> {
+        let tensor_impl = self.__get_tensor_impl().borrow();
+
+        let grad = match tensor_impl.get_autograd_ref_() {
+            Some(autograd_meta) => autograd_meta
+                .get_grad_as_ref()
+                .borrow()
+                .as_ref()
+                .map(|grad| Tensor {
+                    tensor_impl: grad.__clone_ptr_to_tensor_impl(),
+                }),
+            None => None,
+        };
+
+        return grad;
+    }
+
+    /// Replaces the gradient of this tensor. Passing `None` clears it, so the next backward pass
+    /// starts accumulating from scratch.
+    pub fn set_grad(&self, grad: Option<Tensor<T>>) {
+        let tensor_impl = self.__get_tensor_impl().borrow();
+
+        let autograd_meta = tensor_impl.get_autograd_ref_().as_ref().unwrap_or_else(|| {
+            panic!(
+                "Error: Cannot set the gradient of tensor{} that does not require gradient tracking.",
+                tensor_impl.describe_name_()
+            )
+        });
+
+        if let Some(grad) = grad.as_ref() {
+            if *grad.get_shape() != tensor_impl.shape {
+                panic!(
+                    "Error: Gradient of shape {:?} does not match tensor{} of shape {:?}",
+                    grad.get_shape(),
+                    tensor_impl.describe_name_(),
+                    tensor_impl.shape
+                );
+            }
+        }
+
+        *autograd_meta.get_grad_as_ref().borrow_mut() = grad.map(Rc::new);
+    }
+
+    /// Clears the gradient of this tensor. Does nothing on a tensor without gradient tracking.
+    pub fn zero_grad(&self) {
+        if self.does_require_grad() {
+            self.set_grad(None);
+        }
     }
 
     pub fn requires_grad_intermediate(&self, name: &str) {
@@ -196,6 +321,7 @@ where
         self.set_autograd_meta(autograd_meta);
     }
 
+    #[track_caller]
     pub fn from_raw_array(x: ArrayBase<OwnedRepr<T>, IxDyn>, requires_grad: bool) -> Self {
         let tensor_impl = TensorImpl::from_raw_array_(x);
 
@@ -205,7 +331,7 @@ where
 
         if requires_grad {
             let autograd_meta = AutogradMeta::<T>::new_for_leaf(
-                String::from("leaf_grad_meta"),
+                String::from("leaf"),
                 tensor.__clone_ptr_to_tensor_impl(),
             );
 
@@ -225,36 +351,252 @@ where
             .borrow()
             .backward_(starting_gradient, retain_graph);
     }
+
+    /// Runs `backward` seeded with a gradient of one, for 0-d and other single element outputs
+    /// such as losses
+    pub fn backward_scalar(&self, retain_graph: bool)
+    where
+        T: One,
+    {
+        let shape = self.get_shape().clone();
+        let numel: usize = shape.iter().product();
+        if numel != 1 {
+            panic!(
+                "Error: backward_scalar needs a single element tensor, got{} of shape {:?}. Use `backward` with a starting gradient instead.",
+                self.__get_tensor_impl().borrow().describe_name_(),
+                shape
+            );
+        }
+
+        self.backward(Tensor::ones(&shape, None), retain_graph);
+    }
 }
 
 impl<T> Tensor<T>
 where
     T: DTComp + Debug + Clone,
 {
+    /// Data of the tensor. Views read straight from the shared buffer without copying, see
+    /// `RawData` for broadcast tensors.
+    pub fn get_raw_data(&self) -> RawData<'_, T> {
+        return RawData::new(self.__get_tensor_impl().borrow());
+    }
+
+    /// Value of a 0-d or other single element tensor
+    pub fn item(&self) -> T {
+        if self.is_meta() {
+            panic!("Error: Cannot read the value of a meta tensor");
+        }
+
+        let raw_data = self.get_raw_data();
+        if raw_data.len() != 1 {
+            panic!(
+                "Error: item needs a single element tensor, got one of shape {:?}",
+                raw_data.shape()
+            );
+        }
+
+        return raw_data.iter().next().unwrap().clone();
+    }
+
+    pub fn display_grad(&self) {
+        let borrowed_impl = self.tensor_impl.borrow();
+        match &borrowed_impl.autograd_meta {
+            Some(meta) => match &meta.grad.borrow().as_ref() {
+                Some(grad) => {
+                    println!("Grad: {}", grad);
+                }
+                None => {
+                    println!("Grad has not been computed or is None.");
+                }
+            },
+            None => {
+                println!("Tensor has no autograd metadata.");
+            }
+        }
+    }
+
     pub fn get_raw_data_as_ix2(&self) -> ArrayBase<OwnedRepr<T>, Ix2> {
-        return self.get_storage().get_data_as_ix2();
+        return self.__get_tensor_impl().borrow().get_raw_data_as_ix2_();
     }
 
     pub fn get_raw_data_as_ix1(&self) -> ArrayBase<OwnedRepr<T>, Ix1> {
-        return self.get_storage().get_data_as_ix1();
+        return self.__get_tensor_impl().borrow().get_raw_data_as_ix1_();
     }
 }
 
 impl<T> Display for Tensor<T>
 where
-    T: DTComp + Debug,
+    T: DTComp + Debug + Clone,
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let shape = self.get_shape();
+
+        // only names given by the user are printed, default names are left to the graph tools
+        let name = match self.__get_tensor_impl().borrow().name.as_ref() {
+            Some(name) => format!("name={}, ", name),
+            None => String::new(),
+        };
+
+        let grad_info = match self.get_autograd_ref().as_ref() {
+            Some(autograd_meta) => match &autograd_meta.grad_fn {
+                Some(grad_fn) => format!(
+                    ", requires_grad=true, grad_fn={}",
+                    grad_fn.borrow().get_name()
+                ),
+                None => String::from(", requires_grad=true"),
+            },
+            None => String::new(),
+        };
+
+        if self.is_meta() {
+            return write!(
+                f,
+                "tensor({}shape={:?}, dtype={}{}, meta)",
+                name,
+                shape,
+                self.get_type(),
+                grad_info
+            );
+        }
+
         writeln!(
             f,
-            "tensor(shape={:?}, dtype={}, data=",
+            "tensor({}shape={:?}, dtype={}{}, data=",
+            name,
             shape,
-            self.get_type()
+            self.get_type(),
+            grad_info
         )?;
 
         let raw_data = self.get_raw_data();
-        write!(f, "  {:?}", raw_data)?;
+        let options = printing::get_print_options();
+        write!(
+            f,
+            "  {}",
+            printing::format_array(raw_data.view(), &options, 2)
+        )?;
         write!(f, ")")
     }
 }
+
+#[cfg(test)]
+pub mod test {
+    #[allow(unused)]
+    use super::*;
+
+    #[test]
+    fn freeze_and_toggle_grad_tracking() {
+        let x = Tensor::new(vec![1.0_f32, 2.0, 3.0], vec![3], false);
+        let w = Tensor::new(vec![4.0_f32, 5.0, 6.0], vec![3], true);
+        let b = Tensor::new(vec![1.0_f32, 1.0, 1.0], vec![3], true);
+
+        let y = &(&x * &w) + &b;
+        y.backward(Tensor::<f32>::ones_as_f32(vec![3]), false);
+
+        assert_eq!(
+            w.grad().unwrap().get_raw_data().as_slice().unwrap(),
+            &[1.0, 2.0, 3.0]
+        );
+        assert!(b.grad().is_some());
+
+        // turning tracking on again keeps the existing gradient
+        w.requires_grad();
+        assert!(w.grad().is_some());
+
+        // freeze the bias and start the weight from a clean gradient
+        b.set_requires_grad(false);
+        w.zero_grad();
+        assert!(w.grad().is_none());
+
+        let y = &(&x * &w) + &b;
+        y.backward(Tensor::<f32>::ones_as_f32(vec![3]), false);
+
+        assert!(!b.does_require_grad());
+        assert!(b.grad().is_none());
+        assert_eq!(
+            w.grad().unwrap().get_raw_data().as_slice().unwrap(),
+            &[1.0, 2.0, 3.0]
+        );
+
+        w.set_grad(Some(Tensor::<f32>::ones_as_f32(vec![3])));
+        assert_eq!(
+            w.grad().unwrap().get_raw_data().as_slice().unwrap(),
+            &[1.0, 1.0, 1.0]
+        );
+    }
+
+    #[test]
+    fn tensor_names_flow_through_graph() {
+        use crate::graph::visualize::serialize_graph_fn::serialize_tensor;
+        use ndarray::Axis;
+
+        let w = Tensor::new(vec![1.0_f32, 2.0, 3.0, 4.0], vec![2, 2], true).with_name("weights");
+        let b = Tensor::new(vec![1.0_f32, 1.0], vec![2], true);
+        let x = Tensor::new(vec![5.0_f32, 6.0], vec![2], false);
+
+        assert_eq!(w.get_name().as_deref(), Some("weights"));
+        assert_eq!(b.get_name().as_deref(), Some("leaf"));
+        assert_eq!(x.get_name(), None);
+
+        // default names come from the op that created the tensor
+        let s = w.sum(Axis(0));
+        assert_eq!(s.get_name().as_deref(), Some("sum"));
+
+        let h = &(&s * &x) + &b;
+        assert_eq!(h.get_name().as_deref(), Some("add"));
+        h.set_name("hidden");
+        assert_eq!(h.get_name().as_deref(), Some("hidden"));
+
+        assert!(format!("{}", h).starts_with("tensor(name=hidden, shape=[2]"));
+        assert!(format!("{}", b).starts_with("tensor(shape=[2]"));
+
+        let origin = h.get_grad_fn().borrow().get_origin().unwrap();
+        assert_eq!(serialize_tensor(origin).name.as_deref(), Some("hidden"));
+
+        let leaf = w.get_grad_accum().borrow().get_origin().unwrap();
+        assert_eq!(serialize_tensor(leaf).name.as_deref(), Some("weights"));
+    }
+
+    #[test]
+    fn zero_dimensional_scalars() {
+        use crate::ops::public_ops::matmul::matmul;
+        use ndarray::Axis;
+
+        let a = Tensor::new(vec![1.0_f64, 2.0, 3.0], vec![3], true);
+        let b = Tensor::new(vec![4.0_f64, 5.0, 6.0], vec![3], true);
+
+        // dot products of vectors and reductions over the last axis are 0-d
+        let dot = matmul(&a, &b);
+        assert_eq!(*dot.get_shape(), Vec::<usize>::new());
+        assert_eq!(dot.item(), 32.0);
+        assert_eq!(a.max(Axis(0)).get_shape().len(), 0);
+
+        dot.backward_scalar(false);
+        assert_eq!(
+            a.grad().unwrap().get_raw_data().as_slice().unwrap(),
+            &[4.0, 5.0, 6.0]
+        );
+        assert_eq!(
+            b.grad().unwrap().get_raw_data().as_slice().unwrap(),
+            &[1.0, 2.0, 3.0]
+        );
+
+        // matrix vector products reduce to a scalar loss
+        let m = Tensor::new(vec![1.0_f64, 2.0, 3.0, 4.0, 5.0, 6.0], vec![2, 3], true);
+        let scale = Tensor::scalar(2.0_f64, Some(true));
+        let loss = &matmul(&m, &a).sum(Axis(0)) * &scale;
+        assert_eq!(loss.item(), 92.0);
+
+        loss.backward_scalar(false);
+        assert_eq!(scale.grad().unwrap().item(), 46.0);
+        assert_eq!(
+            m.grad().unwrap().get_raw_data().as_slice().unwrap(),
+            &[2.0, 4.0, 6.0, 2.0, 4.0, 6.0]
+        );
+
+        // single element tensors of any rank work too
+        let single = Tensor::new(vec![3_i32], vec![1, 1], false);
+        assert_eq!(single.item(), 3);
+    }
+}
@@ -1,49 +1,191 @@
 use crate::tensor_core::{
-    dtypes::DTComp, tensor::Tensor, tensor_impl::TensorImpl, tensor_utils::handle_requires_grad,
+    buffer_pool,
+    dtypes::{DTComp, DTypes},
+    random::with_generator,
+    tensor::Tensor,
+    tensor_impl::TensorImpl,
+    tensor_utils::handle_requires_grad,
 };
-use ndarray::{ArrayD, IxDyn};
-use num_traits::{AsPrimitive, One, Zero};
+use ndarray::{ArrayD, Dimension, IxDyn};
+use ndarray_rand::rand_distr::{Distribution, StandardNormal};
+use num_traits::{AsPrimitive, Float, NumCast, One, Zero};
+use rand::{Rng, seq::SliceRandom};
 
 use std::{fmt::Debug, ops::Deref};
 
+/// Builds a tensor from nested brackets of any depth. The outermost brackets are left out, and
+/// `dtype` and `requires_grad` are optional:
+///
+/// ```ignore
+/// let x = tensor!([1.0, 2.0], [3.0, 4.0]; requires_grad = true);
+/// let y = tensor!(1, 2, 3; dtype = f32);
+/// let z = tensor!(scalar: 5.0);
+/// ```
 #[macro_export]
 macro_rules! tensor {
-    ( $([$([$([$([$([$($x:expr),* $(,)*]),+ $(,)*]),*]),*]),*]),+ $(,)*;requires_grad=$requires_grad:expr ) => {{
-        let data: Vec<Vec<Vec<Vec<Vec<_>>>>> = vec![$(vec![$(vec![$(vec![$(vec![$(vec![$($x,)*],)*],)*])*])*])*];
-        let tensor = $crate::tensor_core::tensor::Tensor::new_from_6d(data, $requires_grad);
-
-        tensor
-    }};
-    ( $([$([$([$([$($x:expr),* $(,)*]),+ $(,)*]),*]),*]),+ $(,)*;requires_grad=$requires_grad:expr ) => {{
-        let data: Vec<Vec<Vec<Vec<Vec<_>>>>> = vec![$(vec![$(vec![$(vec![$(vec![$($x,)*],)*],)*])*])*];
-        let tensor = $crate::tensor_core::tensor::Tensor::new_from_5d(data, $requires_grad);
-
-        tensor
-
-    }};
-    ( $([$([$([$($x:expr),* $(,)*]),+ $(,)*]),*]),+ $(,)*;requires_grad=$requires_grad:expr ) => {{
-        let data: Vec<Vec<Vec<Vec<_>>>> = vec![$(vec![$(vec![$(vec![$($x,)*],)*],)*])*];
-        let tensor = $crate::tensor_core::tensor::Tensor::new_from_4d(data, $requires_grad);
-
-        tensor
-    }};
-    ( $([$([$($x:expr),* $(,)*]),+ $(,)*]),+ $(,)*;requires_grad=$requires_grad:expr ) => {{
-        let data: Vec<Vec<Vec<_>>> = vec![$(vec![$(vec![$($x,)*],)*],)*];
-        let tensor = $crate::tensor_core::tensor::Tensor::new_from_3d(data, $requires_grad);
-
-        tensor
-    }};
-    ( $([$($x:expr),*$(,)*]),+ $(,)*;requires_grad=$requires_grad:expr ) => {{
-        let data : Vec<Vec<_>> = vec![$(vec![$($x,)*],)*];
-        let tensor = $crate::tensor_core::tensor::Tensor::new_from_2d(data, $requires_grad);
-
-        tensor
-    }};
-    ( $($x:expr),*$(,)*;requires_grad=$requires_grad:expr ) => {{
-        let tensor = $crate::tensor_core::tensor::Tensor::new_from_1d(vec![$($x,)*], $requires_grad);
-
-        tensor
-    }};
+    ( scalar: $x:expr $(; $($option:tt)*)? ) => {
+        $crate::__tensor_options!(
+            $crate::tensor_core::tensor_builder::TensorLiteral::Scalar($x);
+            $($($option)*)?
+        )
+    };
+    ( $([$($inner:tt)*]),+ $(,)? $(; $($option:tt)*)? ) => {
+        $crate::__tensor_options!(
+            $crate::tensor_core::tensor_builder::TensorLiteral::List(
+                vec![$($crate::__tensor_item!([$($inner)*])),+]
+            );
+            $($($option)*)?
+        )
+    };
+    ( $($x:expr),+ $(,)? $(; $($option:tt)*)? ) => {
+        $crate::__tensor_options!(
+            $crate::tensor_core::tensor_builder::TensorLiteral::List(
+                vec![$($crate::tensor_core::tensor_builder::TensorLiteral::Scalar($x)),+]
+            );
+            $($($option)*)?
+        )
+    };
+}
+
+/// One bracketed level of a `tensor!` literal. Levels holding brackets are matched first, so
+/// every level is a single step of recursion whatever its length.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tensor_item {
+    ( [$([$($inner:tt)*]),+ $(,)?] ) => {
+        $crate::tensor_core::tensor_builder::TensorLiteral::List(
+            vec![$($crate::__tensor_item!([$($inner)*])),+]
+        )
+    };
+    ( [$($x:expr),* $(,)?] ) => {
+        $crate::tensor_core::tensor_builder::TensorLiteral::List(
+            vec![$($crate::tensor_core::tensor_builder::TensorLiteral::Scalar($x)),*]
+        )
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __tensor_options {
+    ( $literal:expr; ) => {
+        $literal.into_tensor(false)
+    };
+    ( $literal:expr; requires_grad = $requires_grad:expr ) => {
+        $literal.into_tensor($requires_grad)
+    };
+    ( $literal:expr; dtype = $dtype:ty ) => {
+        $literal.into_tensor_as::<$dtype>(false)
+    };
+    ( $literal:expr; dtype = $dtype:ty, requires_grad = $requires_grad:expr ) => {
+        $literal.into_tensor_as::<$dtype>($requires_grad)
+    };
+    ( $literal:expr; requires_grad = $requires_grad:expr, dtype = $dtype:ty ) => {
+        $literal.into_tensor_as::<$dtype>($requires_grad)
+    };
+}
+
+/// Nested elements written in a `tensor!` literal, before their shape is checked
+#[derive(Debug, Clone)]
+pub enum TensorLiteral<T> {
+    Scalar(T),
+    List(Vec<TensorLiteral<T>>),
+}
+
+impl<T> TensorLiteral<T> {
+    /// Shape of the literal, taken from the first element of every level
+    fn shape(&self) -> Vec<usize> {
+        let mut shape = Vec::new();
+        let mut literal = self;
+
+        while let TensorLiteral::List(element_list) = literal {
+            shape.push(element_list.len());
+
+            match element_list.first() {
+                Some(first) => literal = first,
+                None => break,
+            }
+        }
+
+        return shape;
+    }
+
+    /// Moves the elements into `data` in C order, checking every level against `shape`
+    fn flatten_into(self, shape: &[usize], path: &mut Vec<usize>, data: &mut Vec<T>) {
+        match (self, shape.split_first()) {
+            (TensorLiteral::Scalar(x), None) => data.push(x),
+            (TensorLiteral::List(element_list), Some((len, inner_shape))) => {
+                if element_list.len() != *len {
+                    panic!(
+                        "Error: Ragged tensor literal, row {:?} has {} elements but row {:?} has {}",
+                        path,
+                        element_list.len(),
+                        vec![0; path.len()],
+                        len
+                    );
+                }
+
+                for (index, element) in element_list.into_iter().enumerate() {
+                    path.push(index);
+                    element.flatten_into(inner_shape, path, data);
+                    path.pop();
+                }
+            }
+            (TensorLiteral::Scalar(_), Some(_)) => panic!(
+                "Error: Ragged tensor literal, element {:?} is a scalar where {:?} is a list",
+                path,
+                vec![0; path.len()]
+            ),
+            (TensorLiteral::List(_), None) => panic!(
+                "Error: Ragged tensor literal, element {:?} is a list where {:?} is a scalar",
+                path,
+                vec![0; path.len()]
+            ),
+        }
+    }
+
+    pub fn into_data_and_shape(self) -> (Vec<T>, Vec<usize>) {
+        let shape = self.shape();
+        let mut data = Vec::with_capacity(shape.iter().product());
+
+        self.flatten_into(&shape, &mut Vec::new(), &mut data);
+
+        return (data, shape);
+    }
+
+    pub fn map<U, F>(self, f: &F) -> TensorLiteral<U>
+    where
+        F: Fn(T) -> U,
+    {
+        return match self {
+            TensorLiteral::Scalar(x) => TensorLiteral::Scalar(f(x)),
+            TensorLiteral::List(element_list) => TensorLiteral::List(
+                element_list
+                    .into_iter()
+                    .map(|element| element.map(f))
+                    .collect(),
+            ),
+        };
+    }
+}
+
+impl<T> TensorLiteral<T>
+where
+    T: DTComp + Debug,
+{
+    pub fn into_tensor(self, requires_grad: bool) -> Tensor<T> {
+        let (data, shape) = self.into_data_and_shape();
+        return Tensor::new(data, shape, requires_grad);
+    }
+
+    /// Casts every element to `U` first, so integer literals can build a float tensor without a
+    /// detaching `as_float_32`
+    pub fn into_tensor_as<U>(self, requires_grad: bool) -> Tensor<U>
+    where
+        T: AsPrimitive<U>,
+        U: DTComp + Debug + Copy,
+    {
+        return self.map(&|x: T| x.as_()).into_tensor(requires_grad);
+    }
 }
 
 impl<T> Tensor<T>
@@ -159,6 +301,8 @@ impl<T> Tensor<T>
 where
     T: DTComp + Debug + AsPrimitive<f32>,
 {
+    /// Copies the tensor into a new f32 tensor. The copy is a leaf of its own, use `Tensor::to`
+    /// for a cast that stays in the graph.
     pub fn as_float_32(&self) -> Tensor<f32> {
         let tensor;
 
@@ -193,6 +337,8 @@ impl<T> Tensor<T>
 where
     T: DTComp + Debug + AsPrimitive<f64>,
 {
+    /// Copies the tensor into a new f64 tensor. The copy is a leaf of its own, use `Tensor::to`
+    /// for a cast that stays in the graph.
     pub fn as_float_64(&self) -> Tensor<f64> {
         let tensor;
 
@@ -226,8 +372,7 @@ where
     T: DTComp + Debug + Zero + Clone,
 {
     pub fn zeros(shape: &Vec<usize>, requires_grad: Option<bool>) -> Self {
-        let dyn_shape = IxDyn(shape);
-        let data = ArrayD::<T>::zeros(dyn_shape);
+        let data = buffer_pool::filled(shape, T::zero());
 
         let tensor_impl =
             TensorImpl::generate_pointer_for_tensor(TensorImpl::from_raw_array_(data));
@@ -239,6 +384,11 @@ where
         return tensor;
     }
 
+    /// Safe Rust has no uninitialized buffers, so this is `zeros` under another name
+    pub fn empty(shape: &Vec<usize>, requires_grad: Option<bool>) -> Self {
+        return Tensor::zeros(shape, requires_grad);
+    }
+
     pub fn zeros_like(tensor: &Tensor<T>, requires_grad: Option<bool>) -> Self {
         let shape = tensor.get_shape();
         return Tensor::zeros(shape.deref(), requires_grad);
@@ -255,8 +405,7 @@ where
     }
 
     pub fn ones(shape: &Vec<usize>, requires_grad: Option<bool>) -> Self {
-        let dyn_shape = IxDyn(shape);
-        let data = ArrayD::<T>::ones(dyn_shape);
+        let data = buffer_pool::filled(shape, T::one());
 
         let tensor_impl =
             TensorImpl::generate_pointer_for_tensor(TensorImpl::from_raw_array_(data));
@@ -269,9 +418,435 @@ where
     }
 }
 
+impl<T> Tensor<T>
+where
+    T: DTComp + Debug + Clone,
+{
+    pub fn full(shape: &Vec<usize>, value: T, requires_grad: Option<bool>) -> Self {
+        return Tensor::from_array(buffer_pool::filled(shape, value), requires_grad);
+    }
+
+    pub fn full_like(tensor: &Tensor<T>, value: T, requires_grad: Option<bool>) -> Self {
+        let shape = tensor.get_shape();
+        return Tensor::full(shape.deref(), value, requires_grad);
+    }
+
+    /// 0-d tensor of shape `[]` holding `value`, what `tensor!(scalar: value)` builds
+    pub fn scalar(value: T, requires_grad: Option<bool>) -> Self {
+        return Tensor::full(&vec![], value, requires_grad);
+    }
+
+    /// Builds a tensor of `shape` by calling `f` with the index of every element
+    pub fn from_shape_fn<F>(shape: &Vec<usize>, mut f: F, requires_grad: Option<bool>) -> Self
+    where
+        F: FnMut(&[usize]) -> T,
+    {
+        let array = ArrayD::from_shape_fn(IxDyn(shape), |index| f(index.slice()));
+        return Tensor::from_array(array, requires_grad);
+    }
+
+    /// Coordinate grids of 1D tensors, with `ij` indexing. The `i`th grid repeats the `i`th tensor
+    /// along axis `i`.
+    pub fn meshgrid(tensor_list: &[&Tensor<T>], requires_grad: Option<bool>) -> Vec<Self> {
+        let axis_list: Vec<Vec<T>> = tensor_list
+            .iter()
+            .map(|tensor| {
+                if tensor.get_shape().len() != 1 {
+                    panic!(
+                        "Error: meshgrid needs 1D tensors, got shape {:?}",
+                        tensor.get_shape()
+                    );
+                }
+
+                return tensor.get_raw_data().iter().cloned().collect();
+            })
+            .collect();
+
+        let shape: Vec<usize> = axis_list.iter().map(|axis| axis.len()).collect();
+
+        return axis_list
+            .iter()
+            .enumerate()
+            .map(|(axis_index, axis)| {
+                return Tensor::from_shape_fn(
+                    &shape,
+                    |index| axis[index[axis_index]].clone(),
+                    requires_grad,
+                );
+            })
+            .collect();
+    }
+
+    fn from_array(array: ArrayD<T>, requires_grad: Option<bool>) -> Self {
+        let tensor_impl =
+            TensorImpl::generate_pointer_for_tensor(TensorImpl::from_raw_array_(array));
+
+        let tensor = Tensor { tensor_impl };
+
+        handle_requires_grad(&tensor, requires_grad);
+
+        return tensor;
+    }
+}
+
+impl<T> Tensor<T>
+where
+    T: DTComp + Debug + Clone + Zero + One,
+{
+    /// `n x m` matrix with ones on the main diagonal
+    pub fn eye(n: usize, m: usize, requires_grad: Option<bool>) -> Self {
+        return Tensor::from_shape_fn(
+            &vec![n, m],
+            |index| {
+                if index[0] == index[1] {
+                    return T::one();
+                }
+
+                return T::zero();
+            },
+            requires_grad,
+        );
+    }
+}
+
+impl<T> Tensor<T>
+where
+    T: DTComp + Debug + Clone + Zero,
+{
+    /// Places the last axis of `diagonal` on the diagonal of new square matrices, so a tensor of
+    /// shape `[..., n]` becomes one of shape `[..., n, n]`
+    pub fn diag_embed(diagonal: &Tensor<T>, requires_grad: Option<bool>) -> Self {
+        let diagonal_data = diagonal.get_raw_data();
+
+        let mut shape = diagonal.get_shape().clone();
+        let n = match shape.last() {
+            Some(n) => *n,
+            None => panic!("Error: diag_embed needs at least one dimension"),
+        };
+        shape.push(n);
+
+        return Tensor::from_shape_fn(
+            &shape,
+            |index| {
+                let (row, column) = (index[index.len() - 2], index[index.len() - 1]);
+                if row != column {
+                    return T::zero();
+                }
+
+                return diagonal_data[&index[..index.len() - 1]].clone();
+            },
+            requires_grad,
+        );
+    }
+}
+
+impl<T> Tensor<T>
+where
+    T: DTComp + Debug + NumCast,
+{
+    /// Values from `start` up to but excluding `end`, `step` apart
+    pub fn arange(start: T, end: T, step: T, requires_grad: Option<bool>) -> Self {
+        // integer ranges are computed exactly in i128, f64 would round values past 2^53
+        let data: Vec<T> = match T::dtype() {
+            DTypes::Int8
+            | DTypes::Int16
+            | DTypes::Int32
+            | DTypes::Int64
+            | DTypes::Int128
+            | DTypes::Isize
+            | DTypes::Uint8
+            | DTypes::Uint16
+            | DTypes::Uint32
+            | DTypes::Uint64
+            | DTypes::Uint128
+            | DTypes::Usize => {
+                let to_i128 = |value: T| {
+                    return value.to_i128().unwrap_or_else(|| {
+                        panic!("Error: arange cannot represent {:?} as an i128", value)
+                    });
+                };
+                let (start, end, step) = (to_i128(start), to_i128(end), to_i128(step));
+
+                if step == 0 {
+                    panic!("Error: arange needs a non zero step");
+                }
+
+                let span = end.checked_sub(start).unwrap_or_else(|| {
+                    panic!("Error: arange from {} to {} overflows i128", start, end)
+                });
+                let numel = if span == 0 || span.signum() != step.signum() {
+                    0
+                } else {
+                    span.unsigned_abs().div_ceil(step.unsigned_abs())
+                };
+                let mut data = arange_buffer::<T>(numel);
+
+                data.extend((0..numel).map(|i| {
+                    let value = start + i as i128 * step;
+                    return T::from(value).unwrap_or_else(|| {
+                        panic!(
+                            "Error: arange value {} does not fit in {}",
+                            value,
+                            T::dtype()
+                        )
+                    });
+                }));
+
+                data
+            }
+            _ => {
+                let start = start.to_f64().unwrap();
+                let end = end.to_f64().unwrap();
+                let step = step.to_f64().unwrap();
+
+                if step == 0.0 {
+                    panic!("Error: arange needs a non zero step");
+                }
+
+                let numel = ((end - start) / step).ceil().max(0.0);
+                if !numel.is_finite() {
+                    panic!(
+                        "Error: arange from {} to {} with step {} has no finite number of elements",
+                        start, end, step
+                    );
+                }
+
+                // the float to integer cast saturates, so a huge count still fails the size check
+                let numel = numel as u128;
+                let mut data = arange_buffer::<T>(numel);
+
+                data.extend((0..numel).map(|i| {
+                    let value = start + i as f64 * step;
+                    return T::from(value).unwrap_or_else(|| {
+                        panic!(
+                            "Error: arange value {} does not fit in {}",
+                            value,
+                            T::dtype()
+                        )
+                    });
+                }));
+
+                data
+            }
+        };
+
+        let numel = data.len();
+        let tensor = Tensor::new(data, vec![numel], false);
+
+        handle_requires_grad(&tensor, requires_grad);
+
+        return tensor;
+    }
+}
+
+/// Buffer for the elements of an arange. The number of elements is checked before anything is
+/// allocated, so a tiny step fails with a message instead of aborting on allocation.
+fn arange_buffer<T>(numel: u128) -> Vec<T> {
+    let max_numel = isize::MAX as usize / std::mem::size_of::<T>().max(1);
+
+    let mut data = Vec::new();
+    if numel > max_numel as u128 || data.try_reserve_exact(numel as usize).is_err() {
+        panic!(
+            "Error: arange would create {} elements, more than can be allocated",
+            numel
+        );
+    }
+
+    return data;
+}
+
+impl<T> Tensor<T>
+where
+    T: DTComp + Debug + Float,
+{
+    /// `steps` evenly spaced values from `start` to `end`, both included
+    pub fn linspace(start: T, end: T, steps: usize, requires_grad: Option<bool>) -> Self {
+        let start_f64 = start.to_f64().unwrap();
+        let end_f64 = end.to_f64().unwrap();
+
+        let data: Vec<T> = (0..steps)
+            .map(|i| {
+                if i + 1 == steps && steps > 1 {
+                    return end;
+                }
+
+                let fraction = if steps > 1 {
+                    i as f64 / (steps - 1) as f64
+                } else {
+                    0.0
+                };
+
+                return T::from(start_f64 + (end_f64 - start_f64) * fraction).unwrap();
+            })
+            .collect();
+
+        let tensor = Tensor::new(data, vec![steps], false);
+
+        handle_requires_grad(&tensor, requires_grad);
+
+        return tensor;
+    }
+
+    /// `steps` values from `base^start` to `base^end`, evenly spaced in log space
+    pub fn logspace(start: T, end: T, steps: usize, base: T, requires_grad: Option<bool>) -> Self {
+        let exponents = Tensor::linspace(start, end, steps, None);
+        let data: Vec<T> = exponents
+            .get_raw_data()
+            .iter()
+            .map(|exponent| base.powf(*exponent))
+            .collect();
+
+        let tensor = Tensor::new(data, vec![steps], false);
+
+        handle_requires_grad(&tensor, requires_grad);
+
+        return tensor;
+    }
+}
+
+impl<T> Tensor<T>
+where
+    T: DTComp + Debug,
+{
+    /// Fills a tensor of `shape` with draws from the global generator
+    fn from_generator<F>(shape: &Vec<usize>, requires_grad: Option<bool>, mut f: F) -> Self
+    where
+        F: FnMut(&mut crate::tensor_core::random::Generator) -> T,
+    {
+        let numel: usize = shape.iter().product();
+        let data: Vec<T> = with_generator(|generator| {
+            return (0..numel).map(|_| f(generator)).collect();
+        });
+
+        let array = ArrayD::from_shape_vec(IxDyn(shape), data)
+            .expect("Error: Could not build a random tensor of the given shape");
+        let tensor = Tensor::from_raw_array(array, false);
+
+        handle_requires_grad(&tensor, requires_grad);
+
+        return tensor;
+    }
+}
+
+impl<T> Tensor<T>
+where
+    T: DTComp + Debug + Float,
+{
+    /// Samples uniformly from `[0, 1)`
+    pub fn rand(shape: &Vec<usize>, requires_grad: Option<bool>) -> Self {
+        return Tensor::uniform(shape, T::zero(), T::one(), requires_grad);
+    }
+
+    /// Samples from the standard normal distribution
+    pub fn randn(shape: &Vec<usize>, requires_grad: Option<bool>) -> Self {
+        return Tensor::normal(shape, T::zero(), T::one(), requires_grad);
+    }
+
+    /// Samples uniformly from `[low, high)`
+    pub fn uniform(shape: &Vec<usize>, low: T, high: T, requires_grad: Option<bool>) -> Self {
+        if low >= high || low.is_nan() || high.is_nan() {
+            panic!(
+                "Error: uniform needs low < high, got {:?} and {:?}",
+                low, high
+            );
+        }
+
+        // Draw in f64 and cast, so half precision types get the same stream as f32 and f64
+        let low_f64 = low.to_f64().unwrap();
+        let high_f64 = high.to_f64().unwrap();
+
+        return Tensor::from_generator(shape, requires_grad, |generator| {
+            // samples just below `high` can round up to it in narrower types, those are drawn
+            // again to keep the range half open
+            loop {
+                let sample = T::from(generator.gen_range(low_f64..high_f64)).unwrap();
+                if sample < high {
+                    return sample;
+                }
+            }
+        });
+    }
+
+    /// Samples from a normal distribution with the given `mean` and standard deviation `std`
+    pub fn normal(shape: &Vec<usize>, mean: T, std: T, requires_grad: Option<bool>) -> Self {
+        if std < T::zero() {
+            panic!("Error: normal needs a non negative std, got {:?}", std);
+        }
+
+        let mean_f64 = mean.to_f64().unwrap();
+        let std_f64 = std.to_f64().unwrap();
+
+        return Tensor::from_generator(shape, requires_grad, |generator| {
+            let sample: f64 = StandardNormal.sample(generator);
+            return T::from(mean_f64 + std_f64 * sample).unwrap();
+        });
+    }
+}
+
+impl<T> Tensor<T>
+where
+    T: DTComp + Debug + NumCast,
+{
+    /// Samples integers uniformly from `[low, high)`
+    pub fn randint(shape: &Vec<usize>, low: i64, high: i64, requires_grad: Option<bool>) -> Self {
+        if low >= high {
+            panic!("Error: randint needs low < high, got {} and {}", low, high);
+        }
+
+        return Tensor::from_generator(shape, requires_grad, |generator| {
+            let sample = generator.gen_range(low..high);
+            return T::from(sample)
+                .unwrap_or_else(|| panic!("Error: {} does not fit in the tensor dtype", sample));
+        });
+    }
+
+    /// A random permutation of `0..n`
+    pub fn randperm(n: usize, requires_grad: Option<bool>) -> Self {
+        let mut indices: Vec<usize> = (0..n).collect();
+        with_generator(|generator| indices.shuffle(generator));
+
+        let data: Vec<T> = indices
+            .into_iter()
+            .map(|index| {
+                return T::from(index).unwrap_or_else(|| {
+                    panic!("Error: {} does not fit in the tensor dtype", index)
+                });
+            })
+            .collect();
+
+        let tensor = Tensor::new(data, vec![n], false);
+
+        handle_requires_grad(&tensor, requires_grad);
+
+        return tensor;
+    }
+}
+
+impl<T> Tensor<T>
+where
+    T: DTComp + Debug + Zero + One,
+{
+    /// Draws ones with probability `p` and zeros otherwise
+    pub fn bernoulli(shape: &Vec<usize>, p: f64, requires_grad: Option<bool>) -> Self {
+        if !(0.0..=1.0).contains(&p) {
+            panic!("Error: bernoulli needs p in [0, 1], got {}", p);
+        }
+
+        return Tensor::from_generator(shape, requires_grad, |generator| {
+            if generator.gen_bool(p) {
+                return T::one();
+            }
+
+            return T::zero();
+        });
+    }
+}
+
 #[cfg(test)]
 pub mod test {
+    use crate::tensor_core::{dtypes::DTypes, half::F16};
     use crate::utils::testing_utils;
+    use crate::utils::testing_utils::raw_data_to_vec;
 
     #[allow(unused)]
     use super::*;
@@ -353,4 +928,126 @@ pub mod test {
             1e-4,
         );
     }
+
+    #[test]
+    fn test_macro_options_and_ranks() {
+        let x = tensor!(1, -2, 3 * 2);
+        assert_eq!(x.get_type(), DTypes::Int32);
+        assert!(!x.does_require_grad());
+
+        let y = tensor!([1, 2], [3, 4]; dtype = f32, requires_grad = true);
+        assert!(y.does_require_grad());
+        assert_eq!(
+            y.get_raw_data().iter().copied().collect::<Vec<f32>>(),
+            vec![1.0, 2.0, 3.0, 4.0]
+        );
+
+        let z = tensor!(1.0; requires_grad = true, dtype = F16);
+        assert_eq!(*z.get_shape(), vec![1]);
+        assert_eq!(z.get_type(), DTypes::Float16);
+
+        let scalar = tensor!(scalar: 5.0; requires_grad = true);
+        assert_eq!(*scalar.get_shape(), Vec::<usize>::new());
+
+        let rank_8 = tensor!([[[[[[[1, 2]]]]]]], [[[[[[[3, 4]]]]]]]);
+        assert_eq!(*rank_8.get_shape(), vec![2, 1, 1, 1, 1, 1, 1, 2]);
+        assert_eq!(
+            rank_8.get_raw_data().iter().copied().collect::<Vec<_>>(),
+            vec![1, 2, 3, 4]
+        );
+
+        let empty: Tensor<f64> = tensor!([], []);
+        assert_eq!(*empty.get_shape(), vec![2, 0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "row [1, 1] has 1 elements but row [0, 0] has 2")]
+    fn test_macro_names_ragged_row() {
+        tensor!([[1, 2], [3, 4]], [[5, 6], [7]]);
+    }
+
+    #[test]
+    fn arange_keeps_large_integers_exact() {
+        let start = (1_i64 << 53) + 1;
+        assert_eq!(
+            raw_data_to_vec(&Tensor::<i64>::arange(start, start + 6, 2, None)),
+            vec![start, start + 2, start + 4]
+        );
+        assert_eq!(
+            raw_data_to_vec(&Tensor::<u64>::arange(u64::MAX - 2, u64::MAX, 1, None)),
+            vec![u64::MAX - 2, u64::MAX - 1]
+        );
+        assert_eq!(
+            raw_data_to_vec(&Tensor::<i32>::arange(5, -5, -4, None)),
+            vec![5, 1, -3]
+        );
+        assert!(raw_data_to_vec(&Tensor::<i32>::arange(0, 5, -1, None)).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "arange would create")]
+    fn arange_rejects_absurd_lengths() {
+        Tensor::<f64>::arange(0.0, 1.0, 1e-300, None);
+    }
+
+    #[test]
+    fn structured_constructors() {
+        assert_eq!(
+            raw_data_to_vec(&Tensor::<f64>::arange(0.0, 1.0, 0.25, None)),
+            vec![0.0, 0.25, 0.5, 0.75]
+        );
+        assert_eq!(
+            raw_data_to_vec(&Tensor::<f64>::arange(3.0, 0.0, -1.0, None)),
+            vec![3.0, 2.0, 1.0]
+        );
+        assert_eq!(
+            raw_data_to_vec(&Tensor::<i32>::arange(0, 5, 2, None)),
+            vec![0, 2, 4]
+        );
+        assert_eq!(
+            raw_data_to_vec(&Tensor::<f64>::linspace(-1.0, 1.0, 5, None)),
+            vec![-1.0, -0.5, 0.0, 0.5, 1.0]
+        );
+        assert_eq!(
+            raw_data_to_vec(&Tensor::<f64>::logspace(0.0, 2.0, 3, 10.0, None)),
+            vec![1.0, 10.0, 100.0]
+        );
+
+        let eye = Tensor::<f64>::eye(2, 3, Some(true));
+        assert!(eye.does_require_grad());
+        assert_eq!(*eye.get_shape(), vec![2, 3]);
+        assert_eq!(raw_data_to_vec(&eye), vec![1.0, 0.0, 0.0, 0.0, 1.0, 0.0]);
+
+        let full = Tensor::full_like(&eye, 7.0, None);
+        assert_eq!(raw_data_to_vec(&full), vec![7.0; 6]);
+        assert_eq!(
+            raw_data_to_vec(&Tensor::<f64>::empty(&vec![2], None)),
+            vec![0.0; 2]
+        );
+
+        let from_fn =
+            Tensor::from_shape_fn(&vec![2, 2], |index| (index[0] * 2 + index[1]) as f64, None);
+        assert_eq!(raw_data_to_vec(&from_fn), vec![0.0, 1.0, 2.0, 3.0]);
+
+        let diagonal = tensor!([1.0, 2.0], [3.0, 4.0]; requires_grad=false);
+        let embedded = Tensor::diag_embed(&diagonal, None);
+        assert_eq!(*embedded.get_shape(), vec![2, 2, 2]);
+        assert_eq!(
+            raw_data_to_vec(&embedded),
+            vec![1.0, 0.0, 0.0, 2.0, 3.0, 0.0, 0.0, 4.0]
+        );
+
+        let x = tensor!(1.0, 2.0, 3.0; requires_grad=false);
+        let y = tensor!(10.0, 20.0; requires_grad=false);
+        let grid = Tensor::meshgrid(&[&x, &y], None);
+        assert_eq!(*grid[0].get_shape(), vec![3, 2]);
+        assert_eq!(
+            raw_data_to_vec(&grid[0]),
+            vec![1.0, 1.0, 2.0, 2.0, 3.0, 3.0]
+        );
+        assert_eq!(
+            raw_data_to_vec(&grid[1]),
+            vec![10.0, 20.0, 10.0, 20.0, 10.0, 20.0]
+        );
+    }
 }
@@ -1,13 +1,15 @@
 use crate::graph::backward::Backward;
 use crate::graph::backward::grad_accum::GradAccum;
 
+use crate::profiler::memory_profiler;
+
 use super::super::config::CONFIG;
 use super::autograd_meta::AutogradMeta;
 use super::dtypes::{DTComp, DTypes};
 use super::storage::Storage;
 use super::tensor::Tensor;
 
-use ndarray::Ix2;
+use ndarray::{ArcArray, ArrayView, Ix1, Ix2};
 use ndarray::{Array, ArrayBase, IxDyn, OwnedRepr};
 use std::fmt::Debug;
 
@@ -21,11 +23,13 @@ where
     T: DTComp + Debug,
 {
     pub shape: Vec<usize>,
-    pub strides: Vec<usize>,
+    pub strides: Vec<isize>,
+    pub offset: usize,
     pub numel: usize,
     pub version: u64,
     pub storage: Storage<T>,
     pub autograd_meta: Option<AutogradMeta<T>>,
+    pub name: Option<String>,
 }
 
 impl<T> TensorImpl<T>
@@ -36,6 +40,10 @@ where
         return &self.storage;
     }
 
+    pub fn get_storage_mut_(&mut self) -> &mut Storage<T> {
+        return &mut self.storage;
+    }
+
     pub fn new(x: Vec<T>, shape: Vec<usize>) -> Self {
         let numel = x.len();
         let type_signature = T::dtype();
@@ -51,18 +59,124 @@ where
 
         let storage = Storage::new(data, nbytes, type_signature);
 
+        return TensorImpl::from_storage_(storage, shape);
+    }
+
+    /// A tensor impl over `storage`. The storage has the same number of dimensions as `shape`, and
+    /// any dimension of the storage with length 1 where `shape` is longer is broadcast, with a
+    /// stride of 0.
+    pub fn from_storage_(storage: Storage<T>, shape: Vec<usize>) -> Self {
+        let numel = shape.iter().product();
+
+        let strides = if storage.is_meta() {
+            let mut strides = vec![0_isize; shape.len()];
+            let mut stride = 1_isize;
+
+            for (i, dim) in shape.iter().enumerate().rev() {
+                strides[i] = stride;
+                stride *= *dim as isize;
+            }
+
+            strides
+        } else {
+            let storage_shape = storage.get_raw_shape();
+
+            storage
+                .get_strides()
+                .iter()
+                .zip(storage_shape.iter().zip(shape.iter()))
+                .map(|(stride, (storage_dim, dim))| if storage_dim == dim { *stride } else { 0 })
+                .collect()
+        };
+
         let tensor_impl = TensorImpl {
+            offset: storage.get_offset(),
             storage: storage,
             shape: shape,
-            strides: vec![1, numel],
+            strides: strides,
             numel: numel,
             version: CONFIG.version,
             autograd_meta: None,
+            name: None,
+        };
+
+        return tensor_impl;
+    }
+
+    /// A tensor impl sharing this one's buffer, laid out by `layout` and with the given shape. No
+    /// data is copied, see `Storage::share_as` for which layouts are allowed.
+    pub fn view_<F>(&self, shape: Vec<usize>, layout: F) -> Self
+    where
+        F: FnOnce(ArcArray<T, IxDyn>) -> ArcArray<T, IxDyn>,
+    {
+        return TensorImpl::from_storage_(self.storage.share_as(layout), shape);
+    }
+
+    /// Whether the tensor reads its storage in row major order without gaps or broadcasting
+    pub fn is_contiguous_(&self) -> bool {
+        if self.is_meta_() {
+            return true;
+        }
+
+        let data = self.storage.get_data();
+        return data.shape() == self.shape.as_slice() && data.is_standard_layout();
+    }
+
+    /// Whether some dimension of the tensor is broadcast from a dimension of length 1
+    pub fn is_broadcast_(&self) -> bool {
+        if self.is_meta_() {
+            return false;
+        }
+
+        return self.storage.get_raw_shape() != self.shape;
+    }
+
+    /// A tensor impl with a shape and dtype but no data
+    pub fn new_meta(shape: Vec<usize>) -> Self {
+        let numel: usize = shape.iter().product();
+        let nbytes = std::mem::size_of::<T>() * numel;
+
+        return TensorImpl::from_storage_(Storage::new_meta(nbytes, T::dtype()), shape);
+    }
+
+    pub fn is_meta_(&self) -> bool {
+        return self.storage.is_meta();
+    }
+
+    /// A tensor impl sharing this one's storage, without any autograd metadata
+    pub fn detach_(&self) -> Self {
+        let tensor_impl = TensorImpl {
+            storage: self.storage.share(),
+            shape: self.shape.clone(),
+            strides: self.strides.clone(),
+            offset: self.offset,
+            numel: self.numel,
+            version: self.version,
+            autograd_meta: None,
+            name: self.name.clone(),
         };
 
         return tensor_impl;
     }
 
+    /// Name given by the user, falling back to the default name of the autograd metadata, which
+    /// is derived from the op that created the tensor
+    pub fn get_name_(&self) -> Option<String> {
+        return self.name.clone().or_else(|| {
+            self.autograd_meta
+                .as_ref()
+                .map(|autograd_meta| autograd_meta.name.clone())
+        });
+    }
+
+    /// Name of the tensor formatted for error messages, empty if the tensor has no name
+    pub fn describe_name_(&self) -> String {
+        return match self.get_name_() {
+            Some(name) => format!(" '{}'", name),
+            None => String::new(),
+        };
+    }
+
     pub fn generate_pointer_for_tensor(tensor_impl: Self) -> Rc<RefCell<Self>> {
         return Rc::new(RefCell::new(tensor_impl));
     }
@@ -73,6 +187,10 @@ where
 
     /// Dangerously set grad_fn, will panic if grad_fn does not exist
     pub fn set_grad_fn_(&mut self, node: Rc<RefCell<dyn Backward<T>>>) {
+        // a view allocates nothing, so the buffer stays attributed to the op that created it
+        if !self.storage.is_view() {
+            memory_profiler::record_node_output(self.storage.get_allocation_id(), &*node.borrow());
+        }
         self.get_autograd_ref_as_mut_().set_grad_fn_to_node(node);
     }
 
@@ -86,9 +204,14 @@ where
     }
 
     pub fn get_autograd_ref_as_mut_(&mut self) -> &mut AutogradMeta<T> {
-        return self.autograd_meta.as_mut().expect(
-            "Attmepting to get AutogradMeta on a tensor that does not require gradient tracking.",
-        );
+        let name = self.describe_name_();
+
+        return self.autograd_meta.as_mut().unwrap_or_else(|| {
+            panic!(
+                "Attmepting to get AutogradMeta on tensor{} that does not require gradient tracking.",
+                name
+            )
+        });
     }
 
     pub fn get_autograd_and_expect_res(&self) -> &AutogradMeta<T> {
@@ -141,16 +264,7 @@ where
 
         let storage = Storage::new(x, nbytes, type_signature);
 
-        let tensor_impl = TensorImpl {
-            storage: storage,
-            shape: shape,
-            strides: vec![1, numel],
-            numel: numel,
-            version: CONFIG.version,
-            autograd_meta: None,
-        };
-
-        return tensor_impl;
+        return TensorImpl::from_storage_(storage, shape);
     }
 }
 
@@ -159,16 +273,50 @@ where
     T: DTComp + Debug + Clone,
 {
     // GETTERS AND SETTERS
+    /// Shape of the tensor. Meta tensors have no data, so this is read from the tensor itself
+    /// rather than from the storage.
     pub fn get_raw_shape(&self) -> Vec<usize> {
-        return self.get_storage_().get_raw_shape();
+        return self.shape.clone();
     }
 
-    pub fn get_raw_data_(&self) -> &ArrayBase<OwnedRepr<T>, IxDyn> {
-        return self.get_storage_().get_data();
+    /// Data of the tensor as a view into its storage. Nothing is copied, broadcast dimensions
+    /// read the same elements again through a stride of 0.
+    pub fn get_raw_data_(&self) -> ArrayView<'_, T, IxDyn> {
+        let data = self.get_storage_().get_data();
+
+        if !self.is_broadcast_() {
+            return data.view();
+        }
+
+        return data
+            .broadcast(self.shape.clone())
+            .expect("Internal error, broadcast tensor does not match its storage");
+    }
+
+    /// Mutable access to the data, only available while nothing else shares the buffer and the
+    /// tensor is not broadcast
+    pub fn get_raw_data_mut_(&mut self) -> Option<&mut ArcArray<T, IxDyn>> {
+        if self.is_broadcast_() {
+            return None;
+        }
+
+        return self.get_storage_mut_().get_data_mut();
     }
 
     pub fn get_raw_data_as_ix2_(&self) -> ArrayBase<OwnedRepr<T>, Ix2> {
-        return self.get_storage_().get_data_as_ix2();
+        return self
+            .get_raw_data_()
+            .to_owned()
+            .into_dimensionality::<Ix2>()
+            .expect("Error: Attempting to cast tensor as as 2D tensor failed");
+    }
+
+    pub fn get_raw_data_as_ix1_(&self) -> ArrayBase<OwnedRepr<T>, Ix1> {
+        return self
+            .get_raw_data_()
+            .to_owned()
+            .into_dimensionality::<Ix1>()
+            .expect("Error: Attempting to cast tensor as as 1D tensor failed");
     }
 
     pub fn get_nbytes_(&self) -> usize {
@@ -185,6 +333,13 @@ where
     T: DTComp + Debug + Clone + Add<Output = T>,
 {
     pub fn backward_(&self, starting_gradient: Tensor<T>, retain_graph: bool) {
+        if self.is_meta_() || starting_gradient.is_meta() {
+            panic!(
+                "Error: Cannot run backward on meta tensor{}. Meta tensors only carry shapes, so their graph can be inspected and planned but not executed.",
+                self.describe_name_()
+            );
+        }
+
         match self.get_autograd_ref_() {
             Some(autograd_meta_arc_ref) => {
                 autograd_meta_arc_ref
@@ -192,7 +347,8 @@ where
             }
             None => {
                 panic!(
-                    "Warning! Calling backward on a tensor that does not have gradient tracking enabled. Please call `requires_grad()` on the tensor and try again."
+                    "Warning! Calling backward on tensor{} that does not have gradient tracking enabled. Please call `requires_grad()` on the tensor and try again.",
+                    self.describe_name_()
                 );
             }
         }
@@ -0,0 +1,207 @@
+use crate::graph::visualize::serialize_graph_struct::TensorJSON;
+use crate::tensor_core::{dtypes::DTComp, tensor::Tensor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de, ser};
+
+use std::{fmt::Debug, fs};
+
+/// Serialized form of a tensor. Data is stored flat in C order, whatever the strides of the tensor.
+#[derive(Debug, Serialize, Deserialize)]
+struct TensorSerde<T> {
+    data: Vec<T>,
+    shape: Vec<usize>,
+    dtype: String,
+    requires_grad: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    grad: Option<Box<TensorSerde<T>>>,
+}
+
+impl<T> TensorSerde<T>
+where
+    T: DTComp + Debug + Clone,
+{
+    fn from_tensor(tensor: &Tensor<T>) -> Result<Self, String> {
+        if tensor.is_meta() {
+            return Err(String::from("meta tensors have no data to serialize"));
+        }
+
+        let grad = match tensor.grad() {
+            Some(grad) => Some(Box::new(TensorSerde::from_tensor(&grad)?)),
+            None => None,
+        };
+
+        return Ok(TensorSerde {
+            data: tensor.get_raw_data().iter().cloned().collect(),
+            shape: tensor.get_shape().clone(),
+            dtype: T::dtype().to_string(),
+            requires_grad: tensor.does_require_grad(),
+            name: tensor.__get_tensor_impl().borrow().name.clone(),
+            grad,
+        });
+    }
+
+    fn into_tensor(self) -> Result<Tensor<T>, String> {
+        if self.dtype != T::dtype().to_string() {
+            return Err(format!(
+                "tensor is stored as {} but was requested as {}",
+                self.dtype,
+                T::dtype()
+            ));
+        }
+
+        let numel: usize = self.shape.iter().product();
+        if self.data.len() != numel {
+            return Err(format!(
+                "shape {:?} needs {} elements but the data has {}",
+                self.shape,
+                numel,
+                self.data.len()
+            ));
+        }
+
+        let tensor = Tensor::new(self.data, self.shape, self.requires_grad);
+        if let Some(name) = self.name.as_ref() {
+            tensor.set_name(name);
+        }
+
+        if let Some(grad) = self.grad {
+            if !self.requires_grad {
+                return Err(String::from(
+                    "tensor has a gradient but does not require gradient tracking",
+                ));
+            }
+
+            let grad = grad.into_tensor()?;
+            if *grad.get_shape() != *tensor.get_shape() {
+                return Err(format!(
+                    "gradient of shape {:?} does not match the tensor of shape {:?}",
+                    grad.get_shape(),
+                    tensor.get_shape()
+                ));
+            }
+
+            tensor.set_grad(Some(grad));
+        }
+
+        return Ok(tensor);
+    }
+}
+
+impl<T> Serialize for Tensor<T>
+where
+    T: DTComp + Debug + Clone + Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let tensor_serde = TensorSerde::from_tensor(self).map_err(ser::Error::custom)?;
+        return tensor_serde.serialize(serializer);
+    }
+}
+
+/// Deserialized tensors are leaves. The graph that produced them is not serialized, so a tensor
+/// that required gradients comes back as a leaf that requires gradients.
+impl<'de, T> Deserialize<'de> for Tensor<T>
+where
+    T: DTComp + Debug + Clone + Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let tensor_serde = TensorSerde::<T>::deserialize(deserializer)?;
+        return tensor_serde.into_tensor().map_err(de::Error::custom);
+    }
+}
+
+impl<T> Tensor<T>
+where
+    T: DTComp + Debug + Clone,
+{
+    /// Rebuilds a tensor exported by the graph visualizer. Exports do not record gradient
+    /// tracking, so `requires_grad` decides it.
+    pub fn from_tensor_json(tensor_json: TensorJSON<T>, requires_grad: bool) -> Self {
+        let offset = tensor_json.offset.unwrap_or(0);
+        let numel: usize = tensor_json.shape.iter().product();
+
+        if tensor_json.data.len() < offset + numel {
+            panic!(
+                "Error: Exported tensor of shape {:?} needs {} elements after offset {} but has {}",
+                tensor_json.shape,
+                numel,
+                offset,
+                tensor_json.data.len()
+            );
+        }
+
+        let data: Vec<T> = tensor_json.data[offset..offset + numel].to_vec();
+        let tensor = Tensor::new(data, tensor_json.shape, requires_grad);
+
+        if let Some(name) = tensor_json.name.as_ref() {
+            tensor.set_name(name);
+        }
+
+        return tensor;
+    }
+
+    /// Loads a tensor exported by the graph visualizer, such as `output/tensors/t-0.json`
+    pub fn from_tensor_json_file(path: &str, requires_grad: bool) -> Self
+    where
+        T: for<'de> Deserialize<'de>,
+    {
+        let json_str = fs::read_to_string(path)
+            .unwrap_or_else(|e| panic!("Error: Could not read {}: {}", path, e));
+        let tensor_json: TensorJSON<T> = serde_json::from_str(&json_str)
+            .unwrap_or_else(|e| panic!("Error: {} is not an exported tensor: {}", path, e));
+
+        return Tensor::from_tensor_json(tensor_json, requires_grad);
+    }
+}
+
+#[cfg(test)]
+pub mod test {
+    #[allow(unused)]
+    use super::*;
+
+    use crate::graph::visualize::serialize_graph_fn::serialize_tensor;
+    use crate::utils::testing_utils::raw_data_to_vec;
+
+    #[test]
+    fn tensor_serde_round_trip() {
+        let x = Tensor::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], vec![2, 3], true).with_name("x");
+        let loss = (&x * &x).sum(ndarray::Axis(0)).sum(ndarray::Axis(0));
+        loss.backward(Tensor::new(vec![1.0], vec![], false), false);
+
+        let json = serde_json::to_string(&x).unwrap();
+        let loaded: Tensor<f64> = serde_json::from_str(&json).unwrap();
+        assert_eq!(raw_data_to_vec(&loaded), raw_data_to_vec(&x));
+        assert_eq!(loaded.get_name().as_deref(), Some("x"));
+        assert!(loaded.does_require_grad() && loaded.is_leaf());
+        assert_eq!(
+            raw_data_to_vec(&loaded.grad().unwrap()),
+            vec![2.0, 4.0, 6.0, 8.0, 10.0, 12.0]
+        );
+
+        // views are written in logical order
+        let transposed = Tensor::new(vec![1.0, 2.0, 3.0, 4.0], vec![2, 2], false).transpose(None);
+        let json = serde_json::to_string(&transposed).unwrap();
+        assert_eq!(
+            json,
+            r#"{"data":[1.0,3.0,2.0,4.0],"shape":[2,2],"dtype":"float64","requires_grad":false}"#
+        );
+
+        assert!(serde_json::from_str::<Tensor<f32>>(&json).is_err());
+        assert!(
+            serde_json::from_str::<Tensor<f64>>(
+                r#"{"data":[1.0],"shape":[2],"dtype":"float64","requires_grad":false}"#
+            )
+            .is_err()
+        );
+
+        let exported = serialize_tensor(transposed.__clone_ptr_to_tensor_impl());
+        let reloaded = Tensor::from_tensor_json(exported, false);
+        assert_eq!(raw_data_to_vec(&reloaded), vec![1.0, 3.0, 2.0, 4.0]);
+    }
+}
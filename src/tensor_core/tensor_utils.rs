@@ -8,9 +8,7 @@ where
 {
     match requires_grad {
         Some(does_require_grad) => {
-            if does_require_grad {
-                tensor.requires_grad();
-            }
+            tensor.set_requires_grad(does_require_grad);
         }
         None => {
             return;
@@ -11,3 +11,26 @@ pub fn get_last_2_dim(shape: &[usize]) -> (usize, usize) {
 
     return last_2_dim;
 }
+
+/// Compute the shape that a list of shapes broadcast to, following numpy's broadcasting rules.
+/// Returns `None` if the shapes are not broadcast compatible
+pub fn broadcast_shapes(shapes: &[Vec<usize>]) -> Option<Vec<usize>> {
+    let rank = shapes.iter().map(|shape| shape.len()).max().unwrap_or(0);
+    let mut result_shape = vec![1_usize; rank];
+
+    for shape in shapes {
+        let rank_diff = rank - shape.len();
+
+        for (i, dim) in shape.iter().enumerate() {
+            let result_dim = &mut result_shape[rank_diff + i];
+
+            if *result_dim == 1 {
+                *result_dim = *dim;
+            } else if *dim != 1 && *dim != *result_dim {
+                return None;
+            }
+        }
+    }
+
+    return Some(result_shape);
+}
@@ -1,12 +1,11 @@
-use ndarray::{ArrayBase, IxDyn, OwnedRepr};
 use num_traits::One;
 
-use crate::tensor_core::{dtypes::DTComp, tensor::Tensor};
-use std::{cell::Ref, fmt::Debug, ops::Add};
+use crate::tensor_core::{dtypes::DTComp, raw_data::RawData, tensor::Tensor};
+use std::{fmt::Debug, ops::Add};
 
 pub fn epsilon_test_for_tensor_similarity<T>(
-    y_raw: Ref<ArrayBase<OwnedRepr<T>, IxDyn>>,
-    target_raw: Ref<ArrayBase<OwnedRepr<T>, IxDyn>>,
+    y_raw: RawData<T>,
+    target_raw: RawData<T>,
     epsilon: f64,
 ) where
     T: DTComp + Debug + Clone + PartialEq + Add<Output = T> + Into<f64>,
@@ -68,6 +67,14 @@ pub fn test_for_correct_gradient<T>(
     }
 }
 
+/// Elements of a tensor in logical order, whatever its strides
+pub fn raw_data_to_vec<T>(tensor: &Tensor<T>) -> Vec<T>
+where
+    T: DTComp + Debug + Clone,
+{
+    return tensor.get_raw_data().iter().cloned().collect();
+}
+
 pub fn test_node_name<T>(output_tensor: &Tensor<T>, node_name: &str)
 where
     T: DTComp + Debug + Clone,